@@ -0,0 +1,154 @@
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+
+use crate::cards::properties::title::Title;
+use crate::cards::types::card_model::CardTrait;
+use crate::world::deck::{CardRc, DeckRepository};
+
+/// Cards of the repository a scenario must or must not be played with, identified by their title.
+#[derive(Serialize, Deserialize, JsonSchema, Debug, Clone, PartialEq, Default)]
+#[serde(rename_all = "camelCase", default)]
+pub struct CardSelection {
+    pub required: Vec<Title>,
+    pub forbidden: Vec<Title>,
+}
+
+impl CardSelection {
+    pub fn is_empty(&self) -> bool {
+        self.required.is_empty() && self.forbidden.is_empty()
+    }
+
+    fn is_forbidden(&self, card: &CardRc) -> bool {
+        self.forbidden
+            .iter()
+            .any(|title| title.value() == card.title().value())
+    }
+
+    fn is_required(&self, card: &CardRc) -> bool {
+        self.required
+            .iter()
+            .any(|title| title.value() == card.title().value())
+    }
+}
+
+/*
+Wraps a card repository and hides the forbidden cards of a selection from it. The required cards are not
+drawn by chance, they are added to the prepared deck with `PreparedDeck::include`.
+ */
+pub struct SelectedCards<T: DeckRepository> {
+    access: T,
+    selection: CardSelection,
+}
+
+impl<T: DeckRepository> SelectedCards<T> {
+    pub fn new(access: T, selection: CardSelection) -> Self {
+        SelectedCards { access, selection }
+    }
+
+    /// All cards of the repository which are required by the selection.
+    pub fn required_cards(&self) -> Vec<CardRc> {
+        self.access
+            .get_all_cards()
+            .into_iter()
+            .filter(|card| self.selection.is_required(card))
+            .collect()
+    }
+
+    fn allowed(&self, cards: Vec<CardRc>) -> Vec<CardRc> {
+        cards
+            .into_iter()
+            .filter(|card| !self.selection.is_forbidden(card))
+            .collect()
+    }
+}
+
+impl<T: DeckRepository> DeckRepository for SelectedCards<T> {
+    fn get_event_cards(&self) -> Vec<CardRc> {
+        self.allowed(self.access.get_event_cards())
+    }
+
+    fn get_lucky_cards(&self) -> Vec<CardRc> {
+        self.allowed(self.access.get_lucky_cards())
+    }
+
+    fn get_oopsie_cards(&self) -> Vec<CardRc> {
+        self.allowed(self.access.get_oopsie_cards())
+    }
+
+    fn get_attack_cards(&self) -> Vec<CardRc> {
+        self.allowed(self.access.get_attack_cards())
+    }
+
+    fn get_control_cards(&self) -> Vec<CardRc> {
+        self.allowed(self.access.get_control_cards())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::cards::types::event::tests::event_with_title;
+
+    use super::*;
+
+    struct FakeRepository {
+        events: Vec<CardRc>,
+    }
+
+    impl DeckRepository for FakeRepository {
+        fn get_event_cards(&self) -> Vec<CardRc> {
+            self.events.clone()
+        }
+
+        fn get_lucky_cards(&self) -> Vec<CardRc> {
+            vec![]
+        }
+
+        fn get_oopsie_cards(&self) -> Vec<CardRc> {
+            vec![]
+        }
+
+        fn get_attack_cards(&self) -> Vec<CardRc> {
+            vec![]
+        }
+
+        fn get_control_cards(&self) -> Vec<CardRc> {
+            vec![]
+        }
+    }
+
+
+    fn selected_cards() -> SelectedCards<FakeRepository> {
+        SelectedCards::new(
+            FakeRepository {
+                events: vec![
+                    event_with_title("Audit"),
+                    event_with_title("Budget cut"),
+                    event_with_title("Conference"),
+                ],
+            },
+            CardSelection {
+                required: vec![Title::new("Audit")],
+                forbidden: vec![Title::new("Budget cut")],
+            },
+        )
+    }
+
+    #[test]
+    fn forbidden_cards_are_hidden() {
+        let titles: Vec<String> = selected_cards()
+            .get_event_cards()
+            .iter()
+            .map(|card| card.title().value().to_string())
+            .collect();
+
+        assert_eq!(titles, vec!["Audit", "Conference"]);
+    }
+
+    #[test]
+    fn required_cards_are_found_by_title() {
+        let required = selected_cards().required_cards();
+
+        assert_eq!(required.len(), 1);
+        assert_eq!(required[0].title().value(), "Audit");
+    }
+}
@@ -1 +1,5 @@
-pub mod scenario;
\ No newline at end of file
+pub mod card_selection;
+pub mod rule_set;
+pub mod scenario;
+pub mod scenario_validation;
+pub mod schedule;
\ No newline at end of file
@@ -0,0 +1,84 @@
+use std::rc::Rc;
+
+use log::warn;
+use serde::{Deserialize, Serialize};
+
+/*
+Rules of the game which are up for experimentation. Rule sets are stored as JSON in the `rules` directory
+of the card repository and can be selected in the game setup or by a scenario. Every field falls back to
+the default rules if it is missing, so a rule set only needs to contain what it changes.
+ */
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+#[serde(rename_all = "camelCase", default)]
+pub struct RuleSet {
+    pub name: String,
+    /// Freshly drawn attacks keep their full duration in the round they are drawn.
+    pub attack_grace: bool,
+    /// Cards with an `OnNextFix` effect are removed by the next fix attempt. Otherwise they stay on the
+    /// board and modify every fix.
+    pub consume_on_next_fix: bool,
+    /// Rounds the duration of attacks decreases every round, 0 keeps attacks until they are closed.
+    pub duration_tick: usize,
+}
+
+impl RuleSet {
+    pub const DEFAULT_NAME: &'static str = "Default";
+
+    /// Picks the rule set with the given name, unknown names fall back to the default rules.
+    pub fn select(rule_sets: &[Rc<RuleSet>], name: &str) -> RuleSet {
+        if name == Self::DEFAULT_NAME {
+            return RuleSet::default();
+        }
+        match rule_sets.iter().find(|rules| rules.name == name) {
+            Some(rules) => (**rules).clone(),
+            None => {
+                warn!("Rule set '{}' not found, using default rules", name);
+                RuleSet::default()
+            }
+        }
+    }
+}
+
+impl Default for RuleSet {
+    fn default() -> Self {
+        RuleSet {
+            name: Self::DEFAULT_NAME.to_string(),
+            attack_grace: true,
+            consume_on_next_fix: true,
+            duration_tick: 1,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn missing_rules_fall_back_to_default() {
+        let rules: RuleSet =
+            serde_json::from_str(r#"{ "name": "Slow attacks", "durationTick": 0 }"#).unwrap();
+
+        assert_eq!(
+            rules,
+            RuleSet {
+                name: "Slow attacks".to_string(),
+                duration_tick: 0,
+                ..RuleSet::default()
+            }
+        );
+    }
+
+    #[test]
+    fn select_picks_rule_set_by_name_and_falls_back_to_default() {
+        let fast = RuleSet {
+            name: "Fast".to_string(),
+            duration_tick: 2,
+            ..RuleSet::default()
+        };
+        let rule_sets = vec![Rc::new(fast.clone())];
+
+        assert_eq!(RuleSet::select(&rule_sets, "Fast"), fast);
+        assert_eq!(RuleSet::select(&rule_sets, "Unknown"), RuleSet::default());
+    }
+}
@@ -1,33 +1,65 @@
+use schemars::JsonSchema;
 use serde::{Deserialize, Serialize};
+use crate::cards::game_variants::card_selection::CardSelection;
+use crate::cards::game_variants::schedule::{ScheduledIncome, ScriptedEvent};
 use crate::cards::properties::description::Description;
 use crate::cards::properties::title::Title;
+use crate::world::deck::DeckComposition;
 use crate::world::game::GameInitSettings;
+use crate::world::objective::Objective;
 use crate::world::reputation::Reputation;
+use crate::world::reputation_income::ReputationIncome;
 use crate::world::resource_fix_multiplier::ResourceFixMultiplier;
 use crate::world::resources::Resources;
 
-#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+#[derive(Serialize, Deserialize, JsonSchema, Debug, Clone, PartialEq)]
 #[serde(rename_all = "camelCase")]
 pub struct Scenario {
     pub title: Title,
     pub description: Description,
     pub preset: Preset,
     pub goal: Goal,
+    /// Optional bands which scale the resource gain by the reputation.
+    #[serde(default, skip_serializing_if = "ReputationIncome::is_disabled")]
+    pub reputation_income: ReputationIncome,
+    /// Name of the rule set the scenario is played with, the default rules if not given.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub rule_set: Option<String>,
+    /// Number of cards per type, the deck settings of the game setup are used if not given.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub deck: Option<DeckComposition>,
+    /// Number of cards drawn before the first attack can show up.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub grace_rounds: Option<u8>,
+    /// Cards which must or must not be part of the deck.
+    #[serde(default, skip_serializing_if = "CardSelection::is_empty")]
+    pub cards: CardSelection,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub income_schedule: Vec<ScheduledIncome>,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub scripted_events: Vec<ScriptedEvent>,
 }
-#[derive(Serialize, Deserialize, Debug, Clone, PartialEq, Copy)]
+#[derive(Serialize, Deserialize, JsonSchema, Debug, Clone, PartialEq, Copy)]
 #[serde(rename_all = "camelCase")]
 pub struct Preset {
     pub resources: Resources,
     pub reputation: Reputation,
     pub resource_gain: Resources,
-    pub multiplier: ResourceFixMultiplier
+    pub multiplier: ResourceFixMultiplier,
+    #[serde(default)]
+    pub people_hours: Resources,
+    #[serde(default)]
+    pub people_hours_gain: Resources,
 }
 
-#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+#[derive(Serialize, Deserialize, JsonSchema, Debug, Clone, PartialEq)]
 #[serde(rename_all = "camelCase")]
 pub struct Goal {
     pub minimum_resources: Resources,
-    pub minimum_reputation: Reputation
+    pub minimum_reputation: Reputation,
+    /// Additional objectives which are tracked during the game.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub objectives: Vec<Objective>,
 }
 
 impl Into<GameInitSettings> for Preset {
@@ -37,6 +69,76 @@ impl Into<GameInitSettings> for Preset {
             reputation: self.reputation,
             resource_gain: self.resource_gain,
             fix_multiplier: self.multiplier,
+            people_hours: self.people_hours,
+            people_hours_gain: self.people_hours_gain,
+            ..GameInitSettings::default()
         }
     }
-}
\ No newline at end of file
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn deserializes_scenario_with_preset_and_goal_only() {
+        let json = r#"
+        {
+            "title": "Startup",
+            "description": "Few resources, many oopsies",
+            "preset": { "resources": 10, "reputation": 50, "resourceGain": 5, "multiplier": 1 },
+            "goal": { "minimumResources": 0, "minimumReputation": 20 }
+        }"#;
+
+        let scenario: Scenario = serde_json::from_str(json).unwrap();
+
+        assert!(scenario.deck.is_none());
+        assert!(scenario.cards.is_empty());
+        assert!(scenario.scripted_events.is_empty());
+        assert!(scenario.goal.objectives.is_empty());
+    }
+
+    #[test]
+    fn deserializes_rich_scenario() {
+        let json = r#"
+        {
+            "title": "Audit year",
+            "description": "The auditors are coming",
+            "preset": { "resources": 10, "reputation": 50, "resourceGain": 5, "multiplier": 1 },
+            "goal": {
+                "minimumResources": 0,
+                "minimumReputation": 20,
+                "objectives": [{ "maxIncidents": 2 }, { "fixOopsies": 3 }]
+            },
+            "ruleSet": "Relentless attackers",
+            "deck": { "events": 4, "attacks": 2, "oopsies": 3 },
+            "graceRounds": 2,
+            "cards": { "required": ["Audit"], "forbidden": ["Budget cut"] },
+            "incomeSchedule": [{ "round": 5, "resourceGain": 2 }],
+            "scriptedEvents": [{
+                "round": 3,
+                "card": { "type": "event", "title": "Audit", "description": "", "effect": { "other": "Prepare" } }
+            }]
+        }"#;
+
+        let scenario: Scenario = serde_json::from_str(json).unwrap();
+
+        assert_eq!(
+            scenario.deck,
+            Some(DeckComposition {
+                events: 4,
+                attacks: 2,
+                oopsies: 3,
+                ..DeckComposition::default()
+            })
+        );
+        assert_eq!(scenario.grace_rounds, Some(2));
+        assert_eq!(scenario.cards.required.len(), 1);
+        assert_eq!(scenario.income_schedule[0].resource_gain, Resources::new(2));
+        assert_eq!(scenario.scripted_events[0].round, 3);
+        assert_eq!(
+            scenario.goal.objectives,
+            vec![Objective::MaxIncidents(2), Objective::FixOopsies(3)]
+        );
+    }
+}
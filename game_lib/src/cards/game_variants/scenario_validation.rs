@@ -0,0 +1,294 @@
+use std::collections::HashSet;
+use std::fmt::{Display, Formatter};
+use std::rc::Rc;
+
+use crate::cards::game_variants::rule_set::RuleSet;
+use crate::cards::game_variants::scenario::Scenario;
+use crate::cards::properties::target_taxonomy::TargetTaxonomy;
+use crate::cards::properties::title::Title;
+use crate::cards::types::card_model::CardTrait;
+use crate::world::deck::CardRc;
+use crate::world::objective::Objective;
+
+/// A problem of a scenario, `field` is the path of the offending value in the scenario file.
+#[derive(Debug, Clone, PartialEq)]
+pub struct ScenarioIssue {
+    pub field: String,
+    pub message: String,
+}
+
+impl ScenarioIssue {
+    fn new(field: String, message: String) -> Self {
+        ScenarioIssue { field, message }
+    }
+}
+
+impl Display for ScenarioIssue {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}: {}", self.field, self.message)
+    }
+}
+
+impl Scenario {
+    /// Checks the scenario against the cards, rule sets and targets of the card repository. A scenario
+    /// without issues can be played as it is.
+    pub fn validate(
+        &self,
+        cards: &[CardRc],
+        rule_sets: &[Rc<RuleSet>],
+        taxonomy: &TargetTaxonomy,
+    ) -> Vec<ScenarioIssue> {
+        let mut issues = vec![];
+        if self.title.value().trim().is_empty() {
+            issues.push(ScenarioIssue::new(
+                "title".to_string(),
+                "must not be empty".to_string(),
+            ));
+        }
+        if let Some(name) = &self.rule_set {
+            if name != RuleSet::DEFAULT_NAME && !rule_sets.iter().any(|rules| &rules.name == name) {
+                issues.push(ScenarioIssue::new(
+                    "ruleSet".to_string(),
+                    format!("unknown rule set '{}'", name),
+                ));
+            }
+        }
+        issues.extend(self.validate_deck());
+        issues.extend(self.validate_cards(cards));
+        issues.extend(self.validate_rounds());
+        issues.extend(self.validate_objectives(taxonomy));
+        issues
+    }
+
+    fn validate_deck(&self) -> Vec<ScenarioIssue> {
+        let mut issues = vec![];
+        if let Some(deck) = &self.deck {
+            let total = deck.events + deck.attacks + deck.oopsies + deck.lucky + deck.controls;
+            if total == 0 {
+                issues.push(ScenarioIssue::new(
+                    "deck".to_string(),
+                    "must contain at least one card".to_string(),
+                ));
+            }
+            if let Some(grace_rounds) = self.grace_rounds {
+                let other_cards = total - deck.attacks;
+                if grace_rounds as usize > other_cards {
+                    issues.push(ScenarioIssue::new(
+                        "graceRounds".to_string(),
+                        format!(
+                            "{} grace rounds exceed the {} cards of the deck which are no attacks",
+                            grace_rounds, other_cards
+                        ),
+                    ));
+                }
+            }
+        }
+        issues
+    }
+
+    fn validate_cards(&self, cards: &[CardRc]) -> Vec<ScenarioIssue> {
+        let known: HashSet<&str> = cards.iter().map(|card| card.title().value()).collect();
+        let unknown = |field: &str, titles: &[Title]| -> Vec<ScenarioIssue> {
+            titles
+                .iter()
+                .enumerate()
+                .filter(|(_, title)| !known.contains(title.value()))
+                .map(|(index, title)| {
+                    ScenarioIssue::new(
+                        format!("{}[{}]", field, index),
+                        format!("unknown card '{}'", title.value()),
+                    )
+                })
+                .collect()
+        };
+        let mut issues = unknown("cards.required", &self.cards.required);
+        issues.extend(unknown("cards.forbidden", &self.cards.forbidden));
+        issues.extend(
+            self.cards
+                .forbidden
+                .iter()
+                .enumerate()
+                .filter(|(_, title)| self.cards.required.contains(title))
+                .map(|(index, title)| {
+                    ScenarioIssue::new(
+                        format!("cards.forbidden[{}]", index),
+                        format!("'{}' is also required", title.value()),
+                    )
+                }),
+        );
+        issues
+    }
+
+    fn validate_rounds(&self) -> Vec<ScenarioIssue> {
+        let rounds = self.deck.as_ref().map(|deck| {
+            deck.events + deck.attacks + deck.oopsies + deck.lucky + deck.controls + deck.evaluation
+        });
+        let check_round = |field: String, round: usize| -> Option<ScenarioIssue> {
+            match rounds {
+                _ if round == 0 => Some(ScenarioIssue::new(field, "rounds start at 1".to_string())),
+                Some(total) if round > total => Some(ScenarioIssue::new(
+                    field,
+                    format!("round {} exceeds the {} rounds of the deck", round, total),
+                )),
+                _ => None,
+            }
+        };
+
+        let mut issues = vec![];
+        let mut scheduled = HashSet::new();
+        for (index, income) in self.income_schedule.iter().enumerate() {
+            let field = format!("incomeSchedule[{}].round", index);
+            if !scheduled.insert(income.round) {
+                issues.push(ScenarioIssue::new(
+                    field.clone(),
+                    format!("round {} is scheduled twice", income.round),
+                ));
+            }
+            issues.extend(check_round(field, income.round));
+        }
+        for (index, event) in self.scripted_events.iter().enumerate() {
+            issues.extend(check_round(
+                format!("scriptedEvents[{}].round", index),
+                event.round,
+            ));
+        }
+        issues
+    }
+
+    fn validate_objectives(&self, taxonomy: &TargetTaxonomy) -> Vec<ScenarioIssue> {
+        self.goal
+            .objectives
+            .iter()
+            .enumerate()
+            .filter_map(|(index, objective)| {
+                let field = format!("goal.objectives[{}]", index);
+                match objective {
+                    Objective::FixAllOopsiesWithTarget(target) if !taxonomy.contains(target) => Some(
+                        ScenarioIssue::new(field, format!("unknown target '{}'", target.value())),
+                    ),
+                    Objective::ReputationAbove { rounds: 0, .. } => Some(ScenarioIssue::new(
+                        field,
+                        "rounds must be at least 1".to_string(),
+                    )),
+                    _ => None,
+                }
+            })
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use fake::Fake;
+
+    use crate::cards::game_variants::card_selection::CardSelection;
+    use crate::cards::game_variants::schedule::ScheduledIncome;
+    use crate::cards::properties::target::Target;
+    use crate::cards::types::card_model::Card;
+    use crate::cards::types::event::tests::FakeEventCard;
+    use crate::cards::types::event::EventCard;
+    use crate::world::deck::DeckComposition;
+    use crate::world::resources::Resources;
+
+    use super::*;
+
+    fn scenario() -> Scenario {
+        serde_json::from_str(
+            r#"
+            {
+                "title": "Startup",
+                "description": "Few resources, many oopsies",
+                "preset": { "resources": 10, "reputation": 50, "resourceGain": 5, "multiplier": 1 },
+                "goal": { "minimumResources": 0, "minimumReputation": 20 }
+            }"#,
+        )
+        .unwrap()
+    }
+
+    fn cards() -> Vec<CardRc> {
+        vec![Rc::new(Card::from(EventCard {
+            title: Title::new("Audit"),
+            ..FakeEventCard.fake()
+        }))]
+    }
+
+    fn validate(scenario: &Scenario) -> Vec<String> {
+        scenario
+            .validate(&cards(), &[], &TargetTaxonomy::default())
+            .iter()
+            .map(|issue| issue.to_string())
+            .collect()
+    }
+
+    #[test]
+    fn minimal_scenario_is_valid() {
+        assert!(validate(&scenario()).is_empty());
+    }
+
+    #[test]
+    fn unknown_rule_set_and_cards_are_reported_with_field() {
+        let invalid = Scenario {
+            rule_set: Some("Chaos".to_string()),
+            cards: CardSelection {
+                required: vec![Title::new("Audit"), Title::new("Budget cut")],
+                forbidden: vec![Title::new("Audit")],
+            },
+            ..scenario()
+        };
+
+        assert_eq!(
+            validate(&invalid),
+            vec![
+                "ruleSet: unknown rule set 'Chaos'",
+                "cards.required[1]: unknown card 'Budget cut'",
+                "cards.forbidden[0]: 'Audit' is also required",
+            ]
+        );
+    }
+
+    #[test]
+    fn rounds_outside_of_deck_are_reported() {
+        let invalid = Scenario {
+            deck: Some(DeckComposition {
+                events: 2,
+                attacks: 1,
+                ..DeckComposition::default()
+            }),
+            grace_rounds: Some(3),
+            income_schedule: vec![
+                ScheduledIncome {
+                    round: 0,
+                    resource_gain: Resources::new(1),
+                },
+                ScheduledIncome {
+                    round: 4,
+                    resource_gain: Resources::new(1),
+                },
+            ],
+            ..scenario()
+        };
+
+        assert_eq!(
+            validate(&invalid),
+            vec![
+                "graceRounds: 3 grace rounds exceed the 2 cards of the deck which are no attacks",
+                "incomeSchedule[0].round: rounds start at 1",
+                "incomeSchedule[1].round: round 4 exceeds the 3 rounds of the deck",
+            ]
+        );
+    }
+
+    #[test]
+    fn objectives_with_unknown_targets_are_reported() {
+        let mut invalid = scenario();
+        invalid.goal.objectives = vec![
+            Objective::MaxIncidents(2),
+            Objective::FixAllOopsiesWithTarget(Target::new("mainframe")),
+        ];
+
+        assert_eq!(
+            validate(&invalid),
+            vec!["goal.objectives[1]: unknown target 'mainframe'"]
+        );
+    }
+}
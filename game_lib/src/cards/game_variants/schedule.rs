@@ -0,0 +1,72 @@
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+
+use crate::cards::types::card_model::Card;
+use crate::world::resources::Resources;
+
+/// Changes the resource gain from the given round on.
+#[derive(Serialize, Deserialize, JsonSchema, Debug, Clone, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub struct ScheduledIncome {
+    pub round: usize,
+    pub resource_gain: Resources,
+}
+
+/// Places a card on the board in the given round in addition to the drawn card. The card is written like
+/// a card file of the repository.
+#[derive(Serialize, Deserialize, JsonSchema, Debug, Clone, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub struct ScriptedEvent {
+    pub round: usize,
+    pub card: Card,
+}
+
+/// The resource gain scheduled for the given round, `None` if it does not change in this round.
+pub fn scheduled_gain(schedule: &[ScheduledIncome], round: usize) -> Option<Resources> {
+    schedule
+        .iter()
+        .find(|income| income.round == round)
+        .map(|income| income.resource_gain)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn scheduled_gain_only_changes_in_scheduled_round() {
+        let schedule = vec![
+            ScheduledIncome {
+                round: 3,
+                resource_gain: Resources::new(2),
+            },
+            ScheduledIncome {
+                round: 6,
+                resource_gain: Resources::new(8),
+            },
+        ];
+
+        assert_eq!(scheduled_gain(&schedule, 3), Some(Resources::new(2)));
+        assert_eq!(scheduled_gain(&schedule, 4), None);
+        assert_eq!(scheduled_gain(&schedule, 6), Some(Resources::new(8)));
+    }
+
+    #[test]
+    fn deserializes_scripted_event_with_card() {
+        let json = r#"
+        {
+            "round": 4,
+            "card": {
+                "type": "event",
+                "title": "Audit",
+                "description": "The auditors are coming",
+                "effect": { "other": "Prepare the documentation" }
+            }
+        }"#;
+
+        let event: ScriptedEvent = serde_json::from_str(json).unwrap();
+
+        assert_eq!(event.round, 4);
+        assert!(matches!(event.card, Card::Event(_)));
+    }
+}
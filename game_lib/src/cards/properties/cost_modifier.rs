@@ -1,11 +1,12 @@
 use std::ops::{Add, Mul};
 
+use schemars::JsonSchema;
 use serde::{Deserialize, Serialize};
 
 use crate::world::resource_fix_multiplier::ResourceFixMultiplier;
 use crate::world::resources::Resources;
 
-#[derive(Serialize, Deserialize, Clone, Debug, PartialEq)]
+#[derive(Serialize, Deserialize, JsonSchema, Clone, Debug, PartialEq)]
 #[serde(rename_all = "camelCase")]
 pub enum CostModifier {
     Increase(Resources),
@@ -0,0 +1,116 @@
+use std::fmt::{Display, Formatter};
+
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+
+use crate::world::reputation::Reputation;
+use crate::world::resources::Resources;
+
+/// One option of a decision event. Choosing it applies all of its consequences.
+#[derive(Serialize, Deserialize, JsonSchema, Debug, Clone, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub struct DecisionOption {
+    pub label: String,
+    #[serde(default)]
+    pub consequences: Vec<Consequence>,
+}
+
+#[derive(Serialize, Deserialize, JsonSchema, Debug, Clone, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub enum Consequence {
+    PayResources(Resources),
+    GainResources(Resources),
+    LoseReputation(Reputation),
+    GainReputation(Reputation),
+}
+
+impl DecisionOption {
+    pub fn new(label: &str, consequences: Vec<Consequence>) -> Self {
+        DecisionOption {
+            label: label.to_string(),
+            consequences,
+        }
+    }
+
+    /// Resources which have to be paid when this option is chosen.
+    pub fn costs(&self) -> Resources {
+        self.consequences
+            .iter()
+            .fold(Resources::new(0), |sum, consequence| match consequence {
+                Consequence::PayResources(r) => sum + *r,
+                _ => sum,
+            })
+    }
+}
+
+impl Display for Consequence {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Consequence::PayResources(r) => write!(f, "pay {} resources", r.value()),
+            Consequence::GainResources(r) => write!(f, "gain {} resources", r.value()),
+            Consequence::LoseReputation(r) => write!(f, "lose {} reputation", r.value()),
+            Consequence::GainReputation(r) => write!(f, "gain {} reputation", r.value()),
+        }
+    }
+}
+
+impl Display for DecisionOption {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        if self.consequences.is_empty() {
+            return write!(f, "{}", self.label);
+        }
+        let consequences: Vec<String> = self.consequences.iter().map(|c| c.to_string()).collect();
+        write!(f, "{} ({})", self.label, consequences.join(", "))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn deserializes_decision_option() {
+        let json = r#"
+        {
+            "label": "Patch now",
+            "consequences": [{ "payResources": 10 }, { "gainReputation": 2 }]
+        }"#;
+
+        let option: DecisionOption = serde_json::from_str(json).unwrap();
+
+        assert_eq!(
+            option,
+            DecisionOption::new(
+                "Patch now",
+                vec![
+                    Consequence::PayResources(Resources::new(10)),
+                    Consequence::GainReputation(Reputation::new(2)),
+                ]
+            )
+        );
+    }
+
+    #[test]
+    fn costs_sum_up_all_payments() {
+        let option = DecisionOption::new(
+            "Buy",
+            vec![
+                Consequence::PayResources(Resources::new(3)),
+                Consequence::LoseReputation(Reputation::new(1)),
+                Consequence::PayResources(Resources::new(4)),
+            ],
+        );
+
+        assert_eq!(option.costs(), Resources::new(7));
+    }
+
+    #[test]
+    fn displays_label_with_consequences() {
+        let option = DecisionOption::new(
+            "Accept the risk",
+            vec![Consequence::LoseReputation(Reputation::new(5))],
+        );
+
+        assert_eq!(option.to_string(), "Accept the risk (lose 5 reputation)");
+    }
+}
@@ -1,16 +1,19 @@
 use crate::cards::properties::incident_impact::IncidentImpact;
 use crate::cards::properties::effect_description::EffectDescription;
 use crate::cards::properties::cost_modifier::CostModifier;
+use crate::cards::properties::mitigation::Mitigation;
 use crate::cards::properties::target::Target;
+use schemars::JsonSchema;
 use serde::{Deserialize, Serialize};
 
-#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+#[derive(Serialize, Deserialize, JsonSchema, Debug, Clone, PartialEq)]
 #[serde(rename_all = "camelCase")]
 #[derive(Default)]
 pub enum Effect {
     Immediate(EffectDescription),
     AttackSurface(EffectDescription, Vec<Target>),
     Incident(EffectDescription, Vec<Target>, IncidentImpact),
+    Protection(EffectDescription, Vec<Target>, Mitigation),
     OnNextFix(EffectDescription, CostModifier),
     OnUsingForFix(EffectDescription, CostModifier),
     Other(EffectDescription),
@@ -18,6 +21,18 @@ pub enum Effect {
     NOP,
 }
 
+impl Effect {
+    /// Targets of an attack surface, an incident or a protection, all other effects have no targets.
+    pub fn targets(&self) -> &[Target] {
+        match self {
+            Effect::AttackSurface(_, targets)
+            | Effect::Incident(_, targets, _)
+            | Effect::Protection(_, targets, _) => targets,
+            _ => &[],
+        }
+    }
+}
+
 
 #[cfg(test)]
 pub(crate) mod tests {
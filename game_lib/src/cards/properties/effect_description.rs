@@ -1,3 +1,6 @@
+use std::borrow::Cow;
+
+use schemars::{json_schema, JsonSchema, Schema, SchemaGenerator};
 use serde::{Deserialize, Deserializer, Serialize, Serializer};
 
 use crate::cards::serialization::helper::StrVisitor;
@@ -42,6 +45,16 @@ impl<'de> Deserialize<'de> for EffectDescription {
     }
 }
 
+impl JsonSchema for EffectDescription {
+    fn schema_name() -> Cow<'static, str> {
+        "EffectDescription".into()
+    }
+
+    fn json_schema(_: &mut SchemaGenerator) -> Schema {
+        json_schema!({ "type": "string" })
+    }
+}
+
 #[cfg(test)]
 pub(crate) mod tests {
     use fake::Dummy;
@@ -0,0 +1,76 @@
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+
+/// Describes how the impact of an attack grows while its incidents stay unresolved. The increase is
+/// given in resources for fixed impacts and in percentage points for impacts relative to the revenue.
+#[derive(Serialize, Deserialize, JsonSchema, Debug, Clone, PartialEq, Default)]
+#[serde(rename_all = "camelCase")]
+pub enum Escalation {
+    /// The impact stays the same for the whole duration of the attack.
+    #[default]
+    None,
+    /// The impact grows by the given increase in every round.
+    Linear(usize),
+    /// The impact grows by the given increase every given number of rounds.
+    Stepped { every: usize, increase: usize },
+}
+
+impl Escalation {
+    pub fn is_none(&self) -> bool {
+        matches!(self, Escalation::None)
+    }
+
+    /// Returns by how much the impact grows once the incident has been unresolved for the given
+    /// number of rounds.
+    pub fn increase_after(&self, rounds_unresolved: usize) -> usize {
+        if rounds_unresolved == 0 {
+            return 0;
+        }
+        match self {
+            Escalation::None => 0,
+            Escalation::Linear(increase) => *increase,
+            Escalation::Stepped { every, increase } => {
+                if rounds_unresolved.is_multiple_of(*every) {
+                    *increase
+                } else {
+                    0
+                }
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use rstest::rstest;
+
+    use super::*;
+
+    #[rstest]
+    #[case(Escalation::None, 1, 0)]
+    #[case(Escalation::Linear(5), 0, 0)]
+    #[case(Escalation::Linear(5), 1, 5)]
+    #[case(Escalation::Linear(5), 4, 5)]
+    #[case(Escalation::Stepped { every: 2, increase: 10 }, 1, 0)]
+    #[case(Escalation::Stepped { every: 2, increase: 10 }, 2, 10)]
+    #[case(Escalation::Stepped { every: 2, increase: 10 }, 3, 0)]
+    #[case(Escalation::Stepped { every: 2, increase: 10 }, 4, 10)]
+    #[case(Escalation::Stepped { every: 0, increase: 10 }, 4, 0)]
+    fn increase_after_rounds(
+        #[case] escalation: Escalation,
+        #[case] rounds: usize,
+        #[case] expected: usize,
+    ) {
+        assert_eq!(escalation.increase_after(rounds), expected);
+    }
+
+    #[test]
+    fn deserializes_escalations() {
+        let linear: Escalation = serde_json::from_str(r#"{ "linear": 5 }"#).unwrap();
+        let stepped: Escalation =
+            serde_json::from_str(r#"{ "stepped": { "every": 2, "increase": 10 } }"#).unwrap();
+
+        assert_eq!(linear, Escalation::Linear(5));
+        assert_eq!(stepped, Escalation::Stepped { every: 2, increase: 10 });
+    }
+}
@@ -1,17 +1,22 @@
 use std::ops::Mul;
 
+use schemars::JsonSchema;
 use serde::{Deserialize, Serialize};
 
 use crate::cards::errors::{ErrorKind, ModelError};
 use crate::world::resource_fix_multiplier::ResourceFixMultiplier;
 use crate::world::resources::Resources;
 
-#[derive(Serialize, Deserialize, Clone, Debug, PartialEq)]
+#[derive(Serialize, Deserialize, JsonSchema, Clone, Debug, PartialEq)]
 #[serde(rename_all = "camelCase")]
 #[derive(Default)]
 pub struct FixCost {
+    /// Budget range, the actual costs are rolled when the fix is paid.
     pub min: Resources,
     pub max: Resources,
+    /// People-hours needed for the fix in addition to the budget.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub people_hours: Option<Resources>,
 }
 
 impl FixCost {
@@ -25,6 +30,7 @@ impl FixCost {
             Ok(FixCost {
                 min: Resources::new(min),
                 max: Resources::new(max),
+                people_hours: None,
             })
         }
     }
@@ -36,7 +42,11 @@ impl FixCost {
                 message: format!("min {:?} grater then max {:?}", min, max),
             })
         } else {
-            Ok(FixCost { min, max })
+            Ok(FixCost {
+                min,
+                max,
+                people_hours: None,
+            })
         }
     }
 
@@ -47,6 +57,18 @@ impl FixCost {
     pub fn max_value(&self) -> &usize {
         self.max.value()
     }
+
+    pub fn with_people_hours(self, people_hours: Resources) -> Self {
+        FixCost {
+            people_hours: Some(people_hours),
+            ..self
+        }
+    }
+
+    /// People-hours needed for the fix, fixes without them need none.
+    pub fn people_hours_value(&self) -> Resources {
+        self.people_hours.unwrap_or_default()
+    }
 }
 
 
@@ -57,6 +79,7 @@ impl Mul<&ResourceFixMultiplier> for FixCost {
         FixCost {
             min: self.min * rhs,
             max: self.max * rhs,
+            people_hours: self.people_hours.map(|hours| hours * rhs),
         }
     }
 }
@@ -79,4 +102,34 @@ pub(crate) mod tests {
             .unwrap()
         }
     }
+
+    #[test]
+    fn single_resource_fix_costs_load_as_budget() {
+        let fix_cost: FixCost = serde_json::from_str(r#"{ "min": 2, "max": 4 }"#).unwrap();
+
+        assert_eq!(fix_cost, FixCost::new(2, 4).unwrap());
+        assert_eq!(fix_cost.people_hours_value(), Resources::new(0));
+    }
+
+    #[test]
+    fn deserializes_people_hours() {
+        let fix_cost: FixCost =
+            serde_json::from_str(r#"{ "min": 2, "max": 4, "peopleHours": 3 }"#).unwrap();
+
+        assert_eq!(fix_cost.people_hours, Some(Resources::new(3)));
+    }
+
+    #[test]
+    fn multiplier_applies_to_all_resource_types() {
+        let fix_cost = FixCost::new(2, 4)
+            .unwrap()
+            .with_people_hours(Resources::new(3));
+
+        let multiplied = fix_cost * &ResourceFixMultiplier::new(2);
+
+        assert_eq!(
+            multiplied,
+            FixCost::new(4, 8).unwrap().with_people_hours(Resources::new(6))
+        );
+    }
 }
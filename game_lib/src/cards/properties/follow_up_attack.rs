@@ -0,0 +1,64 @@
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+
+use crate::cards::types::attack::AttackCard;
+
+/// An attack which follows another attack once that attack caused an incident. This is used to
+/// model kill chains, e.g. phishing -> credential theft -> ransomware. As the follow-up is an attack
+/// card itself, it can declare follow-ups on its own.
+#[derive(Serialize, Deserialize, JsonSchema, Debug, Clone, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub struct FollowUpAttack {
+    pub attack: AttackCard,
+    pub timing: FollowUpTiming,
+}
+
+#[derive(Serialize, Deserialize, JsonSchema, Debug, Clone, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub enum FollowUpTiming {
+    /// The attack is placed on the board right away.
+    Immediately,
+    /// The attack is inserted into the remaining deck and drawn in the given number of rounds. If the
+    /// deck has fewer cards left, it becomes the last card.
+    InRounds(usize),
+}
+
+impl FollowUpAttack {
+    pub fn new(attack: AttackCard, timing: FollowUpTiming) -> Self {
+        FollowUpAttack { attack, timing }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn deserializes_follow_up_attacks() {
+        let json = r#"
+        {
+            "attack": {
+                "title": "Credential theft",
+                "description": "Stolen credentials",
+                "effect": {
+                    "incident": ["Attacker logs in", ["backend"], { "fixed": 5 }]
+                },
+                "duration": { "rounds": 2 }
+            },
+            "timing": { "inRounds": 2 }
+        }"#;
+
+        let follow_up: FollowUpAttack = serde_json::from_str(json).unwrap();
+
+        assert_eq!(follow_up.timing, FollowUpTiming::InRounds(2));
+        assert_eq!(follow_up.attack.title.value(), "Credential theft");
+        assert!(follow_up.attack.follow_ups.is_empty());
+    }
+
+    #[test]
+    fn deserializes_immediate_timing() {
+        let timing: FollowUpTiming = serde_json::from_str(r#""immediately""#).unwrap();
+
+        assert_eq!(timing, FollowUpTiming::Immediately);
+    }
+}
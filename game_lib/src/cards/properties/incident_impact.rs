@@ -1,9 +1,10 @@
 use crate::cards::properties::incident_impact::IncidentImpact::Fixed;
 use crate::world::part_of_hundred::PartOfHundred;
 use crate::world::resources::Resources;
+use schemars::JsonSchema;
 use serde::{Deserialize, Serialize};
 
-#[derive(Serialize, Deserialize, Clone, Debug, PartialEq)]
+#[derive(Serialize, Deserialize, JsonSchema, Clone, Debug, PartialEq)]
 #[serde(rename_all = "camelCase")]
 pub enum IncidentImpact {
     PartOfRevenue(PartOfHundred),
@@ -14,6 +15,18 @@ impl IncidentImpact {
     pub fn none() -> Self {
         Fixed(Resources::new(0))
     }
+
+    /// Increases the impact by resources for fixed impacts or by percentage points, capped at 100%,
+    /// for impacts relative to the revenue.
+    pub fn escalate(&self, increase: usize) -> Self {
+        match self {
+            IncidentImpact::PartOfRevenue(part) => {
+                let value = (part.value as usize + increase).min(100);
+                IncidentImpact::PartOfRevenue(PartOfHundred::new(value as u8))
+            }
+            Fixed(resources) => Fixed(resources + &Resources::new(increase)),
+        }
+    }
 }
 
 #[cfg(test)]
@@ -83,6 +96,22 @@ pub(crate) mod tests {
         }
     }
 
+    #[test]
+    fn escalate_fixed_impact_adds_resources() {
+        assert_eq!(
+            Fixed(Resources::new(10)).escalate(5),
+            Fixed(Resources::new(15))
+        );
+    }
+
+    #[test]
+    fn escalate_relative_impact_is_capped_at_100() {
+        assert_eq!(
+            PartOfRevenue(PartOfHundred::new(90)).escalate(20),
+            PartOfRevenue(PartOfHundred::new(100))
+        );
+    }
+
     #[test]
     #[should_panic]
     fn create_relative_incident_impact_of_101_panics() {
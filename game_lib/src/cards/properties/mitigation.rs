@@ -0,0 +1,87 @@
+use std::fmt::{Display, Formatter};
+
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+
+use crate::world::part_of_hundred::PartOfHundred;
+
+/// How a control protects its targets against attacks.
+#[derive(Serialize, Deserialize, JsonSchema, Debug, Clone, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub enum Mitigation {
+    /// Attacks on the targets do not cause incidents at all.
+    Block,
+    /// Incidents on the targets cost only the remaining part of their impact.
+    Reduce(PartOfHundred),
+}
+
+impl Mitigation {
+    /// Returns the mitigation which protects more. Blocking protects more than any reduction.
+    pub fn strongest(self, other: Mitigation) -> Mitigation {
+        match (&self, &other) {
+            (Mitigation::Block, _) => self,
+            (_, Mitigation::Block) => other,
+            (Mitigation::Reduce(a), Mitigation::Reduce(b)) => {
+                if a.value >= b.value {
+                    self
+                } else {
+                    other
+                }
+            }
+        }
+    }
+}
+
+impl Display for Mitigation {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Mitigation::Block => write!(f, "Blocks incidents"),
+            Mitigation::Reduce(part) => write!(f, "Reduces incident impact by {}", part),
+        }
+    }
+}
+
+#[cfg(test)]
+pub(crate) mod tests {
+    use fake::Dummy;
+    use rand::Rng;
+
+    use super::*;
+
+    pub struct FakeMitigation;
+
+    impl Dummy<FakeMitigation> for Mitigation {
+        fn dummy_with_rng<R: Rng + ?Sized>(_: &FakeMitigation, rng: &mut R) -> Self {
+            if rng.gen_bool(0.5) {
+                Mitigation::Block
+            } else {
+                Mitigation::Reduce(PartOfHundred::new(rng.gen_range(0..=100)))
+            }
+        }
+    }
+
+    #[test]
+    fn block_is_the_strongest_mitigation() {
+        let reduce = Mitigation::Reduce(PartOfHundred::new(90));
+
+        assert_eq!(reduce.clone().strongest(Mitigation::Block), Mitigation::Block);
+        assert_eq!(Mitigation::Block.strongest(reduce), Mitigation::Block);
+    }
+
+    #[test]
+    fn higher_reduction_is_stronger() {
+        let low = Mitigation::Reduce(PartOfHundred::new(10));
+        let high = Mitigation::Reduce(PartOfHundred::new(50));
+
+        assert_eq!(low.strongest(high.clone()), high);
+    }
+
+    #[test]
+    fn deserializes_mitigations() {
+        let block: Mitigation = serde_json::from_str(r#""block""#).unwrap();
+        let reduce: Mitigation = serde_json::from_str(r#"{ "reduce": 50 }"#).unwrap();
+
+        assert_eq!(block, Mitigation::Block);
+        assert_eq!(reduce, Mitigation::Reduce(PartOfHundred::new(50)));
+    }
+}
@@ -2,8 +2,13 @@ pub mod description;
 pub mod duration;
 pub mod effect;
 pub mod effect_description;
+pub mod escalation;
 pub mod fix_cost;
+pub mod follow_up_attack;
 pub mod cost_modifier;
+pub mod decision;
 pub mod target;
+pub mod target_taxonomy;
 pub mod title;
 pub mod incident_impact;
+pub mod mitigation;
@@ -0,0 +1,209 @@
+use std::collections::{BTreeMap, BTreeSet, VecDeque};
+
+use serde::{Deserialize, Serialize};
+
+use crate::cards::properties::target::Target;
+
+/*
+Targets are organized in a hierarchy, e.g. `infrastructure` contains `network` and `backup`. The
+taxonomy is stored in the card repository as a map from a target to the targets it contains. Targets
+on the first level can have no children. Children can contain further targets themselves.
+
+An attack on `infrastructure` hits an oopsie which opened `network` and vice versa. Two siblings
+(e.g. `network` and `backup`) do not match.
+ */
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+#[serde(transparent)]
+pub struct TargetTaxonomy {
+    children: BTreeMap<Target, Vec<Target>>,
+}
+
+impl TargetTaxonomy {
+    pub const FILE_NAME: &'static str = "targets.json";
+
+    pub fn new(children: BTreeMap<Target, Vec<Target>>) -> Self {
+        TargetTaxonomy { children }
+    }
+
+    /// All targets known to the taxonomy, sorted by name.
+    pub fn all_targets(&self) -> Vec<Target> {
+        let mut targets: Vec<Target> = self
+            .children
+            .iter()
+            .flat_map(|(parent, children)| std::iter::once(parent).chain(children.iter()))
+            .cloned()
+            .collect();
+        targets.sort();
+        targets.dedup();
+        targets
+    }
+
+    pub fn contains(&self, target: &Target) -> bool {
+        self.children.contains_key(target)
+            || self.children.values().any(|children| children.contains(target))
+    }
+
+    /// Returns all targets of the given list which are not part of the taxonomy.
+    pub fn unknown_targets(&self, targets: &[Target]) -> Vec<Target> {
+        targets
+            .iter()
+            .filter(|target| !self.contains(target))
+            .cloned()
+            .collect()
+    }
+
+    /// Returns true if the target is the group itself or is contained in it on any level. A target can
+    /// be listed under several parents, all of them are searched.
+    pub fn is_part_of(&self, target: &Target, group: &Target) -> bool {
+        // the visited targets protect against cycles in the file
+        let mut visited: BTreeSet<&Target> = BTreeSet::new();
+        let mut queue: VecDeque<&Target> = VecDeque::from([target]);
+        while let Some(current) = queue.pop_front() {
+            if current == group {
+                return true;
+            }
+            if visited.insert(current) {
+                queue.extend(self.parents_of(current));
+            }
+        }
+        false
+    }
+
+    /// Two targets match if one of them is part of the other.
+    pub fn matches(&self, first: &Target, second: &Target) -> bool {
+        self.is_part_of(first, second) || self.is_part_of(second, first)
+    }
+
+    /// Returns true if any of the first targets matches any of the second targets.
+    pub fn any_matches(&self, first: &[Target], second: &[Target]) -> bool {
+        first
+            .iter()
+            .any(|a| second.iter().any(|b| self.matches(a, b)))
+    }
+
+    fn parents_of<'a>(&'a self, target: &'a Target) -> impl Iterator<Item = &'a Target> + 'a {
+        self.children
+            .iter()
+            .filter(move |(_, children)| children.contains(target))
+            .map(|(parent, _)| parent)
+    }
+}
+
+/// Flat taxonomy of the targets known before the taxonomy file was introduced. Without hierarchy
+/// targets only match if they are equal.
+impl Default for TargetTaxonomy {
+    fn default() -> Self {
+        let targets = [
+            "backend",
+            "backup",
+            "database",
+            "frontend",
+            "hardware",
+            "infrastructure",
+            "mobile",
+            "network",
+            "social",
+            "supply chain",
+        ];
+        TargetTaxonomy {
+            children: targets
+                .iter()
+                .map(|target| (Target::new(target), vec![]))
+                .collect(),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn create_taxonomy() -> TargetTaxonomy {
+        serde_json::from_str(
+            r#"{
+                "infrastructure": ["network", "backup"],
+                "network": ["firewall"],
+                "frontend": []
+            }"#,
+        )
+        .unwrap()
+    }
+
+    #[test]
+    fn all_targets_contains_parents_and_children() {
+        let taxonomy = create_taxonomy();
+
+        assert_eq!(
+            taxonomy.all_targets(),
+            vec![
+                Target::new("backup"),
+                Target::new("firewall"),
+                Target::new("frontend"),
+                Target::new("infrastructure"),
+                Target::new("network"),
+            ]
+        );
+    }
+
+    #[test]
+    fn child_is_part_of_parents_on_all_levels() {
+        let taxonomy = create_taxonomy();
+
+        assert!(taxonomy.is_part_of(&Target::new("network"), &Target::new("infrastructure")));
+        assert!(taxonomy.is_part_of(&Target::new("firewall"), &Target::new("infrastructure")));
+        assert!(!taxonomy.is_part_of(&Target::new("infrastructure"), &Target::new("network")));
+    }
+
+    #[test]
+    fn matches_in_both_directions_but_not_siblings() {
+        let taxonomy = create_taxonomy();
+
+        assert!(taxonomy.matches(&Target::new("infrastructure"), &Target::new("backup")));
+        assert!(taxonomy.matches(&Target::new("backup"), &Target::new("infrastructure")));
+        assert!(taxonomy.matches(&Target::new("frontend"), &Target::new("frontend")));
+        assert!(!taxonomy.matches(&Target::new("network"), &Target::new("backup")));
+        assert!(!taxonomy.matches(&Target::new("frontend"), &Target::new("network")));
+    }
+
+    #[test]
+    fn target_with_several_parents_is_part_of_all_of_them() {
+        let taxonomy: TargetTaxonomy = serde_json::from_str(
+            r#"{
+                "infrastructure": ["network"],
+                "data": ["backup"],
+                "network": ["backup"]
+            }"#,
+        )
+        .unwrap();
+
+        assert!(taxonomy.is_part_of(&Target::new("backup"), &Target::new("data")));
+        assert!(taxonomy.is_part_of(&Target::new("backup"), &Target::new("infrastructure")));
+        assert!(taxonomy.matches(&Target::new("network"), &Target::new("backup")));
+        assert!(!taxonomy.matches(&Target::new("data"), &Target::new("network")));
+    }
+
+    #[test]
+    fn unknown_targets_are_reported() {
+        let taxonomy = create_taxonomy();
+
+        let result = taxonomy.unknown_targets(&[Target::new("network"), Target::new("cloud")]);
+
+        assert_eq!(result, vec![Target::new("cloud")]);
+    }
+
+    #[test]
+    fn cycles_do_not_loop_forever() {
+        let taxonomy: TargetTaxonomy =
+            serde_json::from_str(r#"{ "a": ["b"], "b": ["a"] }"#).unwrap();
+
+        assert!(!taxonomy.is_part_of(&Target::new("a"), &Target::new("c")));
+    }
+
+    #[test]
+    fn default_taxonomy_only_matches_equal_targets() {
+        let taxonomy = TargetTaxonomy::default();
+
+        assert!(taxonomy.matches(&Target::new("network"), &Target::new("network")));
+        assert!(!taxonomy.matches(&Target::new("network"), &Target::new("infrastructure")));
+    }
+}
@@ -1,5 +1,7 @@
+use std::borrow::Cow;
 use std::marker::PhantomData;
 
+use schemars::{json_schema, JsonSchema, Schema, SchemaGenerator};
 use serde::{Deserialize, Deserializer, Serialize, Serializer};
 
 #[derive(Debug, Clone, PartialEq)]
@@ -44,6 +46,19 @@ impl<'de> Deserialize<'de> for Title {
     }
 }
 
+impl JsonSchema for Title {
+    fn schema_name() -> Cow<'static, str> {
+        "Title".into()
+    }
+
+    fn json_schema(_: &mut SchemaGenerator) -> Schema {
+        json_schema!({
+            "type": "string",
+            "minLength": 1
+        })
+    }
+}
+
 #[cfg(test)]
 pub(crate) mod tests {
     use fake::Dummy;
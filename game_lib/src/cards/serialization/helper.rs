@@ -1,8 +1,7 @@
-use std::error::Error;
 use std::fmt;
 use std::marker::PhantomData;
 
-use serde::de::Visitor;
+use serde::de::{Error, Visitor};
 
 pub(crate) struct StrVisitor<T>(pub(crate) PhantomData<T>);
 
@@ -21,28 +20,30 @@ where
     }
 }
 
+/// Numbers of the card model. Values the model can not represent are rejected with a message which
+/// becomes a serde error, so broken card files do not crash the game.
 pub trait Number: Sized {
-    fn from_i64(value: i64) -> Self;
-    fn from_u64(value: u64) -> Self;
+    fn from_i64(value: i64) -> Result<Self, String>;
+    fn from_u64(value: u64) -> Result<Self, String>;
 }
 
 impl Number for i64 {
-    fn from_i64(value: i64) -> Self {
-        value
+    fn from_i64(value: i64) -> Result<Self, String> {
+        Ok(value)
     }
 
-    fn from_u64(value: u64) -> Self {
-        value as Self
+    fn from_u64(value: u64) -> Result<Self, String> {
+        i64::try_from(value).map_err(|e| e.to_string())
     }
 }
 
 impl Number for u64 {
-    fn from_i64(value: i64) -> Self {
-        value as Self
+    fn from_i64(value: i64) -> Result<Self, String> {
+        u64::try_from(value).map_err(|e| e.to_string())
     }
 
-    fn from_u64(value: u64) -> Self {
-        value
+    fn from_u64(value: u64) -> Result<Self, String> {
+        Ok(value)
     }
 }
 
@@ -62,13 +63,13 @@ where
     where
         E: Error,
     {
-        Ok(T::from_i64(value))
+        T::from_i64(value).map_err(E::custom)
     }
 
     fn visit_u64<E>(self, value: u64) -> Result<Self::Value, E>
     where
         E: Error,
     {
-        Ok(T::from_u64(value))
+        T::from_u64(value).map_err(E::custom)
     }
 }
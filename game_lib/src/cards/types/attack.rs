@@ -1,20 +1,29 @@
+use schemars::JsonSchema;
 use serde::{Deserialize, Serialize};
 use crate::cards::properties::incident_impact::IncidentImpact;
 use crate::cards::properties::description::Description;
 use crate::cards::properties::duration::Duration;
 use crate::cards::properties::effect::Effect;
 use crate::cards::properties::effect_description::EffectDescription;
+use crate::cards::properties::escalation::Escalation;
+use crate::cards::properties::follow_up_attack::FollowUpAttack;
 use crate::cards::properties::target::Target;
 use crate::cards::properties::title::Title;
 use crate::cards::types::card_model::Card;
 
-#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+#[derive(Serialize, Deserialize, JsonSchema, Debug, Clone, PartialEq)]
 #[serde(rename_all = "camelCase")]
 pub struct AttackCard {
     pub title: Title,
     pub description: Description,
     pub effect: Effect,
     pub duration: Duration,
+    /// Attacks which follow this attack once it caused an incident.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub follow_ups: Vec<FollowUpAttack>,
+    /// How the impact grows while the incidents of this attack are not resolved.
+    #[serde(default, skip_serializing_if = "Escalation::is_none")]
+    pub escalation: Escalation,
 }
 
 impl AttackCard {
@@ -31,6 +40,8 @@ impl AttackCard {
             description,
             effect: Effect::Incident(effect, targets, cost),
             duration,
+            follow_ups: vec![],
+            escalation: Escalation::None,
         }
     }
 
@@ -40,12 +51,16 @@ impl AttackCard {
             description: Description::empty(),
             effect: Effect::Incident(EffectDescription::empty(), vec![], IncidentImpact::none()),
             duration: Duration::default(),
+            follow_ups: vec![],
+            escalation: Escalation::None,
         })
     }
 }
 
 #[cfg(test)]
 pub(crate) mod tests {
+    use std::rc::Rc;
+
     use fake::{Dummy, Fake};
     use rand::Rng;
     use crate::cards::properties::incident_impact::tests::FakeFixedIncidentImpact;
@@ -55,6 +70,7 @@ pub(crate) mod tests {
     use crate::cards::properties::effect_description::tests::FakeEffectDescription;
     use crate::cards::properties::target::tests::FakeTarget;
     use crate::cards::properties::title::tests::FakeTitle;
+    use crate::world::deck::CardRc;
 
     use super::*;
 
@@ -67,7 +83,22 @@ pub(crate) mod tests {
                 description: FakeDescription.fake(),
                 effect: Incident(FakeEffectDescription.fake(), vec![FakeTarget.fake()], FakeFixedIncidentImpact.fake()),
                 duration: FakeDuration.fake(),
+                follow_ups: vec![],
+                escalation: Escalation::None,
             }
         }
     }
+
+    /// An attack card which hits the given target.
+    pub fn attack_targeting(target: &str) -> CardRc {
+        let attack: AttackCard = FakeAttackCard.fake();
+        Rc::new(Card::from(AttackCard {
+            effect: Incident(
+                EffectDescription::new("Hit"),
+                vec![Target::new(target)],
+                FakeFixedIncidentImpact.fake(),
+            ),
+            ..attack
+        }))
+    }
 }
@@ -1,16 +1,18 @@
 use std::fmt::{Display, Formatter};
+use schemars::JsonSchema;
 use serde::{Deserialize, Serialize};
 
 use crate::cards::properties::description::Description;
 use crate::cards::properties::effect::Effect;
 use crate::cards::properties::title::Title;
 use crate::cards::types::attack::AttackCard;
+use crate::cards::types::control::ControlCard;
 use crate::cards::types::evaluation::EvaluationCard;
 use crate::cards::types::event::EventCard;
 use crate::cards::types::lucky::LuckyCard;
 use crate::cards::types::oopsie::OopsieCard;
 
-#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+#[derive(Serialize, Deserialize, JsonSchema, Debug, Clone, PartialEq)]
 #[serde(rename_all = "camelCase")]
 #[serde(tag = "type")]
 pub enum Card {
@@ -19,6 +21,7 @@ pub enum Card {
     Oopsie(OopsieCard),
     Lucky(LuckyCard),
     Evaluation(EvaluationCard),
+    Control(ControlCard),
 }
 
 #[derive(Copy, Clone, Debug, Eq, PartialEq, Hash)]
@@ -28,11 +31,20 @@ pub enum CardCategory {
     Oopsie(&'static str),
     Lucky(&'static str),
     Evaluation(&'static str),
+    Control(&'static str),
 }
 
 impl Display for CardCategory {
     fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
-        write!(f, "{} card", self)
+        let name = match self {
+            CardCategory::Event(name)
+            | CardCategory::Attack(name)
+            | CardCategory::Oopsie(name)
+            | CardCategory::Lucky(name)
+            | CardCategory::Evaluation(name)
+            | CardCategory::Control(name) => name,
+        };
+        write!(f, "{} card", name)
     }
 }
 
@@ -43,13 +55,15 @@ impl Card {
     pub const OOPSIE_CARD: CardCategory = CardCategory::Oopsie("Oopise");
     pub const LUCKY_CARD: CardCategory = CardCategory::Lucky("Lucky");
     pub const EVALUATION: CardCategory = CardCategory::Evaluation("Evaluation");
+    pub const CONTROL_CARD: CardCategory = CardCategory::Control("Control");
 
-    pub const CARD_TYPES: [&'static CardCategory; 5] = [
+    pub const CARD_TYPES: [&'static CardCategory; 6] = [
         &Self::ATTACK_CARD,
         &Self::EVENT_CARD,
         &Self::LUCKY_CARD,
         &Self::OOPSIE_CARD,
         &Self::EVALUATION,
+        &Self::CONTROL_CARD,
     ];
 }
 
@@ -70,6 +84,7 @@ impl CardTrait for Card {
             Card::Oopsie(card) => &card.title,
             Card::Lucky(card) => &card.title,
             Card::Evaluation(card) => &card.title,
+            Card::Control(card) => &card.title,
         }
     }
 
@@ -80,6 +95,7 @@ impl CardTrait for Card {
             Card::Oopsie(card) => &card.description,
             Card::Lucky(card) => &card.description,
             Card::Evaluation(card) => &card.description,
+            Card::Control(card) => &card.description,
         }
     }
 
@@ -90,6 +106,7 @@ impl CardTrait for Card {
             Card::Oopsie(card) => &card.effect,
             Card::Lucky(card) => &card.effect,
             Card::Evaluation(card) => &card.effect,
+            Card::Control(card) => &card.effect,
         }
     }
 
@@ -100,6 +117,7 @@ impl CardTrait for Card {
             Card::Oopsie(_) => &Card::OOPSIE_CARD,
             Card::Lucky(_) => &Card::LUCKY_CARD,
             Card::Evaluation(_) => &Card::EVALUATION,
+            Card::Control(_) => &Card::CONTROL_CARD,
         }
     }
 
@@ -110,6 +128,7 @@ impl CardTrait for Card {
             Card::Oopsie(_) => OopsieCard::empty(),
             Card::Lucky(_) => LuckyCard::empty(),
             Card::Evaluation(_) => EvaluationCard::empty(),
+            Card::Control(_) => ControlCard::empty(),
         }
     }
 }
@@ -143,3 +162,20 @@ impl From<EvaluationCard> for Card {
         Card::Evaluation(value)
     }
 }
+
+impl From<ControlCard> for Card {
+    fn from(value: ControlCard) -> Self {
+        Card::Control(value)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn card_category_is_displayed_with_its_name() {
+        assert_eq!(Card::CONTROL_CARD.to_string(), "Control card");
+        assert_eq!(Card::EVENT_CARD.to_string(), "Event card");
+    }
+}
@@ -0,0 +1,108 @@
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+
+use crate::cards::properties::description::Description;
+use crate::cards::properties::effect::Effect;
+use crate::cards::properties::effect_description::EffectDescription;
+use crate::cards::properties::mitigation::Mitigation;
+use crate::cards::properties::target::Target;
+use crate::cards::properties::title::Title;
+use crate::cards::types::card_model::Card;
+use crate::world::resources::Resources;
+
+/// A security control protects targets against attacks once it is bought. The effect is always a
+/// protection of the targets, the cost is multiplied by the resource fix multiplier of the game.
+#[derive(Serialize, Deserialize, JsonSchema, Debug, Clone, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub struct ControlCard {
+    pub title: Title,
+    pub description: Description,
+    pub effect: Effect,
+    pub cost: Resources,
+}
+
+impl ControlCard {
+    pub fn new(
+        title: Title,
+        description: Description,
+        targets: Vec<Target>,
+        effect: EffectDescription,
+        mitigation: Mitigation,
+        cost: Resources,
+    ) -> Self {
+        ControlCard {
+            title,
+            description,
+            effect: Effect::Protection(effect, targets, mitigation),
+            cost,
+        }
+    }
+
+    pub fn empty() -> Card {
+        Card::Control(ControlCard {
+            title: Title::empty(),
+            description: Description::empty(),
+            effect: Effect::default(),
+            cost: Resources::default(),
+        })
+    }
+
+    pub fn is_closeable(&self) -> bool {
+        true
+    }
+}
+
+#[cfg(test)]
+pub(crate) mod tests {
+    use fake::{Dummy, Fake};
+    use rand::Rng;
+
+    use crate::cards::properties::description::tests::FakeDescription;
+    use crate::cards::properties::effect_description::tests::FakeEffectDescription;
+    use crate::cards::properties::mitigation::tests::FakeMitigation;
+    use crate::cards::properties::target::tests::FakeTarget;
+    use crate::cards::properties::title::tests::FakeTitle;
+
+    use super::*;
+
+    pub struct FakeControlCard;
+
+    impl Dummy<FakeControlCard> for ControlCard {
+        fn dummy_with_rng<R: Rng + ?Sized>(_: &FakeControlCard, rng: &mut R) -> Self {
+            ControlCard {
+                title: FakeTitle.fake(),
+                description: FakeDescription.fake(),
+                effect: Effect::Protection(
+                    FakeEffectDescription.fake(),
+                    vec![FakeTarget.fake()],
+                    FakeMitigation.fake(),
+                ),
+                cost: Resources::new(rng.gen_range(1..20)),
+            }
+        }
+    }
+
+    #[test]
+    fn deserializes_control_card() {
+        let json = r#"
+        {
+            "type": "control",
+            "title": "Firewall",
+            "description": "Filters traffic",
+            "effect": {
+                "protection": ["Blocks attacks on the network", ["network"], "block"]
+            },
+            "cost": 10
+        }"#;
+
+        let card: Card = serde_json::from_str(json).unwrap();
+
+        match card {
+            Card::Control(control) => {
+                assert_eq!(control.cost, Resources::new(10));
+                assert_eq!(control.effect.targets(), &[Target::new("network")]);
+            }
+            _ => panic!("Should be a control card"),
+        }
+    }
+}
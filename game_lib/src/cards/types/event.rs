@@ -1,16 +1,21 @@
+use schemars::JsonSchema;
 use serde::{Deserialize, Serialize};
 
+use crate::cards::properties::decision::DecisionOption;
 use crate::cards::properties::description::Description;
 use crate::cards::properties::effect::Effect;
 use crate::cards::properties::title::Title;
 use crate::cards::types::card_model::Card;
 
-#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+#[derive(Serialize, Deserialize, JsonSchema, Debug, Clone, PartialEq)]
 #[serde(rename_all = "camelCase")]
 pub struct EventCard {
     pub title: Title,
     pub description: Description,
     pub effect: Effect,
+    /// Options of a decision event. The event stays on the board until one of them is chosen.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub options: Vec<DecisionOption>,
 }
 
 impl EventCard {
@@ -19,17 +24,26 @@ impl EventCard {
             title: Title::empty(),
             description: Description::empty(),
             effect: Effect::NOP,
+            options: vec![],
         })
     }
 
+    pub fn is_decision(&self) -> bool {
+        !self.options.is_empty()
+    }
+
     pub fn is_closeable(&self) -> bool {
+        if self.is_decision() {
+            return false;
+        }
         match &self.effect {
             | Effect::OnUsingForFix(_, _)
             | Effect::Other(_)
             | Effect::NOP
             | Effect::Immediate(_)
             | Effect::AttackSurface(_, _)
-            | Effect::Incident(_, _, _) => true,
+            | Effect::Incident(_, _, _)
+            | Effect::Protection(_, _, _) => true,
             | Effect::OnNextFix(_, _) => false
         }
     }
@@ -37,12 +51,15 @@ impl EventCard {
 
 #[cfg(test)]
 pub(crate) mod tests {
+    use std::rc::Rc;
+
     use fake::{Dummy, Fake};
     use rand::Rng;
 
     use crate::cards::properties::description::tests::FakeDescription;
     use crate::cards::properties::effect::tests::FakeEffect;
     use crate::cards::properties::title::tests::FakeTitle;
+    use crate::world::deck::CardRc;
 
     use super::*;
 
@@ -55,6 +72,7 @@ pub(crate) mod tests {
                 title: FakeTitle.fake(),
                 description: FakeDescription.fake(),
                 effect: FakeEffect.fake(),
+                options: vec![],
             }
         }
     }
@@ -64,9 +82,17 @@ pub(crate) mod tests {
             EventCard {
                 title: FakeTitle.fake(),
                 description: FakeDescription.fake(),
-                effect: Effect::NOP
+                effect: Effect::NOP,
+                options: vec![],
             }
         }
     }
 
+    /// An event card with the given title, e.g. to select cards by title.
+    pub fn event_with_title(title: &str) -> CardRc {
+        Rc::new(Card::from(EventCard {
+            title: Title::new(title),
+            ..FakeEventCard.fake()
+        }))
+    }
 }
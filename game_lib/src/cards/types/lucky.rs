@@ -1,16 +1,21 @@
+use schemars::JsonSchema;
 use serde::{Deserialize, Serialize};
 
 use crate::cards::properties::description::Description;
 use crate::cards::properties::effect::Effect;
 use crate::cards::properties::title::Title;
 use crate::cards::types::card_model::Card;
+use crate::world::resources::Resources;
 
-#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+#[derive(Serialize, Deserialize, JsonSchema, Debug, Clone, PartialEq)]
 #[serde(rename_all = "camelCase")]
 pub struct LuckyCard {
     pub title: Title,
     pub description: Description,
     pub effect: Effect,
+    /// Price in the market, lucky cards without a price can not be bought.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub price: Option<Resources>,
 }
 
 impl LuckyCard {
@@ -19,6 +24,7 @@ impl LuckyCard {
             title: Title::empty(),
             description: Description::empty(),
             effect: Effect::default(),
+            price: None,
         })
     }
 
@@ -29,7 +35,8 @@ impl LuckyCard {
             | Effect::NOP
             | Effect::Immediate(_)
             | Effect::AttackSurface(_, _)
-            | Effect::Incident(_, _, _) => true,
+            | Effect::Incident(_, _, _)
+            | Effect::Protection(_, _, _) => true,
             | Effect::OnNextFix(_, _) => false
         }
     }
@@ -54,6 +61,7 @@ pub(crate) mod tests {
                 title: FakeTitle.fake(),
                 description: FakeDescription.fake(),
                 effect: FakeEffect.fake(),
+                price: None,
             }
         }
     }
@@ -1,5 +1,6 @@
 pub mod attack;
 pub mod card_model;
+pub mod control;
 pub mod event;
 pub mod lucky;
 pub mod oopsie;
@@ -1,3 +1,4 @@
+use schemars::JsonSchema;
 use serde::{Deserialize, Serialize};
 
 use crate::cards::properties::description::Description;
@@ -8,13 +9,16 @@ use crate::cards::properties::target::Target;
 use crate::cards::properties::title::Title;
 use crate::cards::types::card_model::Card;
 
-#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+#[derive(Serialize, Deserialize, JsonSchema, Debug, Clone, PartialEq)]
 #[serde(rename_all = "camelCase")]
 pub struct OopsieCard {
     pub title: Title,
     pub description: Description,
     pub effect: Effect,
     pub fix_cost: FixCost,
+    /// Number of rounds it takes to fix the oopsie. Without it the oopsie is fixed instantly.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub fix_rounds: Option<usize>,
 }
 
 impl OopsieCard {
@@ -30,6 +34,7 @@ impl OopsieCard {
             description,
             effect: Effect::AttackSurface(effect, targets),
             fix_cost,
+            fix_rounds: None,
         }
     }
 
@@ -39,12 +44,15 @@ impl OopsieCard {
             description: Description::empty(),
             effect: Effect::default(),
             fix_cost: FixCost::default(),
+            fix_rounds: None,
         })
     }
 }
 
 #[cfg(test)]
 pub(crate) mod tests {
+    use std::rc::Rc;
+
     use fake::{Dummy, Fake};
     use rand::Rng;
     use crate::cards::properties::description::tests::FakeDescription;
@@ -52,6 +60,7 @@ pub(crate) mod tests {
     use crate::cards::properties::fix_cost::tests::FakeFixCost;
     use crate::cards::properties::target::tests::FakeTarget;
     use crate::cards::properties::title::tests::FakeTitle;
+    use crate::world::deck::CardRc;
 
     use super::*;
 
@@ -64,7 +73,17 @@ pub(crate) mod tests {
                 description: FakeDescription.fake(),
                 effect: Effect::AttackSurface(FakeEffectDescription.fake(), vec![FakeTarget.fake(), FakeTarget.fake()]),
                 fix_cost: FakeFixCost.fake(),
+                fix_rounds: None,
             }
         }
     }
+
+    /// An oopsie card whose attack surface is the given target.
+    pub fn oopsie_targeting(target: &str) -> CardRc {
+        let oopsie: OopsieCard = FakeOopsieCard.fake();
+        Rc::new(Card::from(OopsieCard {
+            effect: Effect::AttackSurface(EffectDescription::new("Open"), vec![Target::new(target)]),
+            ..oopsie
+        }))
+    }
 }
@@ -1,11 +1,16 @@
 use std::error::Error;
 use std::fmt::{Display, Formatter};
+use std::path::Path;
 
 pub type GameLibResult<T> = Result<T, GameLibError>;
 
 #[derive(Clone, Debug, PartialEq)]
 pub enum ErrorKind {
     IO,
+    /// The file is no valid JSON or does not match the model.
+    Parse,
+    /// The file could be read but contains values the game can not be played with.
+    Validation,
 }
 
 #[derive(Clone, Debug, PartialEq)]
@@ -13,17 +18,22 @@ pub struct GameLibError {
     kind: ErrorKind,
     message: String,
     original_message: Option<String>,
+    path: Option<String>,
+    field: Option<String>,
 }
 
 impl Display for GameLibError {
     fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "GameLibError: {:?}: {}", self.kind, self.message)?;
+        if let Some(path) = &self.path {
+            write!(f, " in {}", path)?;
+        }
+        if let Some(field) = &self.field {
+            write!(f, " at field {}", field)?;
+        }
         match &self.original_message {
-            None => write!(f, "GameLibError: {:?}: {}", self.kind, self.message),
-            Some(orig) => write!(
-                f,
-                "GameLibError: {:?}: {} (was {})",
-                self.kind, self.message, orig
-            ),
+            None => Ok(()),
+            Some(orig) => write!(f, " (was {})", orig),
         }
     }
 }
@@ -36,14 +46,78 @@ impl GameLibError {
             kind,
             message: message.to_string(),
             original_message: None,
+            path: None,
+            field: None,
         }
     }
 
     pub fn create_with_original(kind: ErrorKind, message: &str, original_message: String) -> Self {
         GameLibError {
-            kind,
-            message: message.to_string(),
             original_message: Some(original_message),
+            ..Self::create(kind, message)
+        }
+    }
+
+    /// The file could not be deserialized, `original_message` carries line and column of the problem.
+    pub fn parse(path: &Path, original_message: String) -> Self {
+        GameLibError {
+            path: Some(path.display().to_string()),
+            ..Self::create_with_original(ErrorKind::Parse, "Failed to deserialize", original_message)
         }
     }
+
+    /// A field of the file has a value the game can not be played with.
+    pub fn validation(path: &Path, field: &str, message: &str) -> Self {
+        GameLibError {
+            path: Some(path.display().to_string()),
+            field: Some(field.to_string()),
+            ..Self::create(ErrorKind::Validation, message)
+        }
+    }
+
+    pub fn kind(&self) -> &ErrorKind {
+        &self.kind
+    }
+
+    pub fn message(&self) -> &str {
+        &self.message
+    }
+
+    pub fn path(&self) -> Option<&str> {
+        self.path.as_deref()
+    }
+
+    pub fn field(&self) -> Option<&str> {
+        self.field.as_deref()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn displays_path_and_field() {
+        let error = GameLibError::validation(
+            Path::new("oopsies/0001-open_port.json"),
+            "fixCost",
+            "min is greater than max",
+        );
+
+        assert_eq!(
+            error.to_string(),
+            "GameLibError: Validation: min is greater than max in oopsies/0001-open_port.json at field fixCost"
+        );
+    }
+
+    #[test]
+    fn displays_original_message_of_parse_errors() {
+        let error = GameLibError::parse(Path::new("events/0001.json"), "expected `:` at line 3".to_string());
+
+        assert_eq!(error.kind(), &ErrorKind::Parse);
+        assert_eq!(
+            error.to_string(),
+            "GameLibError: Parse: Failed to deserialize in events/0001.json (was expected `:` at line 3)"
+        );
+    }
 }
@@ -0,0 +1,393 @@
+use std::io;
+
+use serde::{Deserialize, Serialize};
+
+use crate::cards::properties::cost_modifier::CostModifier;
+use crate::cards::properties::description::Description;
+use crate::cards::properties::duration::Duration;
+use crate::cards::properties::effect::Effect;
+use crate::cards::properties::effect_description::EffectDescription;
+use crate::cards::properties::escalation::Escalation;
+use crate::cards::properties::fix_cost::FixCost;
+use crate::cards::properties::incident_impact::IncidentImpact;
+use crate::cards::properties::mitigation::Mitigation;
+use crate::cards::properties::target::Target;
+use crate::cards::properties::title::Title;
+use crate::cards::types::attack::AttackCard;
+use crate::cards::types::card_model::{Card, CardTrait};
+use crate::cards::types::control::ControlCard;
+use crate::cards::types::event::EventCard;
+use crate::cards::types::lucky::LuckyCard;
+use crate::cards::types::oopsie::OopsieCard;
+use crate::world::card_query::EffectKind;
+use crate::world::part_of_hundred::PartOfHundred;
+use crate::world::resources::Resources;
+
+/*
+Cards as rows of a CSV file, so they can be drafted in a spreadsheet. Every card type uses the columns
+it needs and leaves the others empty. Follow-up attacks, escalations and decision options do not fit
+into columns and are not part of the rows.
+ */
+
+/// Separates the targets in the targets column.
+const TARGET_SEPARATOR: char = ';';
+
+/// A card as row of a CSV file.
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct CardRow {
+    #[serde(rename = "type")]
+    pub card_type: String,
+    pub title: String,
+    pub description: String,
+    /// Kind of the effect as named in the card files, e.g. `attackSurface`.
+    pub effect: String,
+    pub effect_description: String,
+    /// Targets separated by `;`.
+    pub targets: String,
+    pub fix_cost_min: Option<usize>,
+    pub fix_cost_max: Option<usize>,
+    pub fix_rounds: Option<usize>,
+    pub people_hours: Option<usize>,
+    /// Incident impact as percentage of the revenue.
+    pub revenue_share: Option<u8>,
+    /// Incident impact as fixed amount of resources.
+    pub damage: Option<usize>,
+    /// Rounds an attack lasts, empty if it lasts until it is closed.
+    pub duration: Option<usize>,
+    /// Change of the fix cost by `onNextFix` and `onUsingForFix` effects, negative values decrease it.
+    pub modifier: Option<isize>,
+    /// Market price of a lucky card or costs of a control.
+    pub price: Option<usize>,
+    /// Reduction of the incident impact by a control, controls without reduction block incidents.
+    pub reduce: Option<u8>,
+}
+
+/// A row which could be read from the CSV file, `line` starts at 1 with the header.
+#[derive(Debug, Clone, PartialEq)]
+pub struct ImportedRow {
+    pub line: usize,
+    pub card: Result<Card, String>,
+}
+
+impl CardRow {
+    /// Returns true if the card has properties which can not be written into a row.
+    pub fn loses_details(card: &Card) -> bool {
+        match card {
+            Card::Attack(attack) => {
+                !attack.follow_ups.is_empty() || attack.escalation != Escalation::None
+            }
+            Card::Event(event) => !event.options.is_empty(),
+            _ => false,
+        }
+    }
+
+    pub fn to_card(&self) -> Result<Card, String> {
+        let title = Title::from(self.title.clone());
+        let description = Description::from(self.description.clone());
+        let effect = self.to_effect()?;
+        let card = match self.card_type.to_lowercase().as_str() {
+            "event" => Card::Event(EventCard {
+                title,
+                description,
+                effect,
+                options: vec![],
+            }),
+            "lucky" => Card::Lucky(LuckyCard {
+                title,
+                description,
+                effect,
+                price: self.price.map(Resources::new),
+            }),
+            "attack" => Card::Attack(AttackCard {
+                title,
+                description,
+                effect,
+                duration: Duration::new(self.duration),
+                follow_ups: vec![],
+                escalation: Escalation::None,
+            }),
+            "oopsie" => {
+                let (min, max) = match (self.fix_cost_min, self.fix_cost_max) {
+                    (Some(min), Some(max)) => (min, max),
+                    _ => return Err("oopsie cards need fix_cost_min and fix_cost_max".to_string()),
+                };
+                Card::Oopsie(OopsieCard {
+                    title,
+                    description,
+                    effect,
+                    fix_cost: FixCost {
+                        min: Resources::new(min),
+                        max: Resources::new(max),
+                        people_hours: self.people_hours.filter(|h| *h > 0).map(Resources::new),
+                    },
+                    fix_rounds: self.fix_rounds.filter(|rounds| *rounds > 1),
+                })
+            }
+            "control" => Card::Control(ControlCard {
+                title,
+                description,
+                effect,
+                cost: Resources::new(
+                    self.price.ok_or("control cards need a price".to_string())?,
+                ),
+            }),
+            other => {
+                return Err(format!(
+                    "unknown card type '{}', use event, attack, oopsie, lucky or control",
+                    other
+                ))
+            }
+        };
+        Ok(card)
+    }
+
+    fn to_effect(&self) -> Result<Effect, String> {
+        let description = EffectDescription::from(self.effect_description.clone());
+        let kind: EffectKind = self.effect.parse()?;
+        let effect = match kind {
+            EffectKind::Immediate => Effect::Immediate(description),
+            EffectKind::AttackSurface => Effect::AttackSurface(description, self.to_targets()),
+            EffectKind::Incident => {
+                Effect::Incident(description, self.to_targets(), self.to_impact()?)
+            }
+            EffectKind::Protection => {
+                let mitigation = match self.reduce {
+                    Some(reduce) => Mitigation::Reduce(percentage(reduce, "reduce")?),
+                    None => Mitigation::Block,
+                };
+                Effect::Protection(description, self.to_targets(), mitigation)
+            }
+            EffectKind::OnNextFix => Effect::OnNextFix(description, self.to_modifier()?),
+            EffectKind::OnUsingForFix => Effect::OnUsingForFix(description, self.to_modifier()?),
+            EffectKind::Other => Effect::Other(description),
+            EffectKind::Nop => Effect::NOP,
+        };
+        Ok(effect)
+    }
+
+    fn to_targets(&self) -> Vec<Target> {
+        self.targets
+            .split(TARGET_SEPARATOR)
+            .map(|target| target.trim())
+            .filter(|target| !target.is_empty())
+            .map(Target::new)
+            .collect()
+    }
+
+    fn to_impact(&self) -> Result<IncidentImpact, String> {
+        match (self.revenue_share, self.damage) {
+            (Some(share), None) => Ok(IncidentImpact::PartOfRevenue(percentage(
+                share,
+                "revenue_share",
+            )?)),
+            (None, Some(damage)) => Ok(IncidentImpact::Fixed(Resources::new(damage))),
+            _ => Err("incidents need either revenue_share or damage".to_string()),
+        }
+    }
+
+    fn to_modifier(&self) -> Result<CostModifier, String> {
+        match self.modifier {
+            Some(amount) if amount >= 0 => Ok(CostModifier::Increase(Resources::new(
+                amount.unsigned_abs(),
+            ))),
+            Some(amount) => Ok(CostModifier::Decrease(Resources::new(amount.unsigned_abs()))),
+            None => Err("fix cost effects need a modifier".to_string()),
+        }
+    }
+}
+
+fn percentage(value: u8, column: &str) -> Result<PartOfHundred, String> {
+    match value {
+        0..=100 => Ok(PartOfHundred::new(value)),
+        _ => Err(format!("{} must be between 0 and 100, was {}", column, value)),
+    }
+}
+
+impl From<&Card> for CardRow {
+    fn from(card: &Card) -> Self {
+        let effect = card.effect();
+        let mut row = CardRow {
+            card_type: card_type(card).to_string(),
+            title: card.title().value().to_string(),
+            description: card.description().value().to_string(),
+            effect: EffectKind::of(effect).to_string(),
+            targets: effect
+                .targets()
+                .iter()
+                .map(|target| target.value())
+                .collect::<Vec<&str>>()
+                .join(&TARGET_SEPARATOR.to_string()),
+            ..CardRow::default()
+        };
+        match effect {
+            Effect::Immediate(d)
+            | Effect::AttackSurface(d, _)
+            | Effect::Other(d) => row.effect_description = d.value().to_string(),
+            Effect::Incident(d, _, impact) => {
+                row.effect_description = d.value().to_string();
+                match impact {
+                    IncidentImpact::PartOfRevenue(share) => row.revenue_share = Some(share.value),
+                    IncidentImpact::Fixed(damage) => row.damage = Some(*damage.value()),
+                }
+            }
+            Effect::Protection(d, _, mitigation) => {
+                row.effect_description = d.value().to_string();
+                if let Mitigation::Reduce(reduce) = mitigation {
+                    row.reduce = Some(reduce.value);
+                }
+            }
+            Effect::OnNextFix(d, modifier) | Effect::OnUsingForFix(d, modifier) => {
+                row.effect_description = d.value().to_string();
+                row.modifier = Some(modifier.value());
+            }
+            Effect::NOP => {}
+        }
+        match card {
+            Card::Attack(attack) => {
+                // an empty duration means until closed, see `Duration::new`
+                row.duration = match attack.duration {
+                    Duration::Rounds(rounds) => Some(rounds),
+                    Duration::None => Some(0),
+                    Duration::UntilClosed => None,
+                }
+            }
+            Card::Oopsie(oopsie) => {
+                row.fix_cost_min = Some(*oopsie.fix_cost.min_value());
+                row.fix_cost_max = Some(*oopsie.fix_cost.max_value());
+                row.fix_rounds = oopsie.fix_rounds;
+                row.people_hours = oopsie.fix_cost.people_hours.map(|h| *h.value());
+            }
+            Card::Lucky(lucky) => row.price = lucky.price.map(|p| *p.value()),
+            Card::Control(control) => row.price = Some(*control.cost.value()),
+            Card::Event(_) | Card::Evaluation(_) => {}
+        }
+        row
+    }
+}
+
+/// Type of the card as it is written in the card files.
+fn card_type(card: &Card) -> &'static str {
+    match card {
+        Card::Event(_) => "event",
+        Card::Attack(_) => "attack",
+        Card::Oopsie(_) => "oopsie",
+        Card::Lucky(_) => "lucky",
+        Card::Evaluation(_) => "evaluation",
+        Card::Control(_) => "control",
+    }
+}
+
+/// Writes the cards as CSV with a header row.
+pub fn write_cards_csv<W: io::Write>(cards: &[&Card], writer: W) -> io::Result<()> {
+    let mut csv_writer = csv::Writer::from_writer(writer);
+    for card in cards {
+        csv_writer
+            .serialize(CardRow::from(*card))
+            .map_err(|e| io::Error::other(e.to_string()))?;
+    }
+    csv_writer.flush()
+}
+
+/// Reads all rows of the CSV file. A row which can not be read or does not describe a card is
+/// returned with the reason, so the other rows can still be imported.
+pub fn read_cards_csv<R: io::Read>(reader: R) -> io::Result<Vec<ImportedRow>> {
+    let mut csv_reader = csv::Reader::from_reader(reader);
+    let headers = csv_reader
+        .headers()
+        .map_err(|e| io::Error::new(io::ErrorKind::InvalidData, e.to_string()))?
+        .clone();
+    let mut rows = vec![];
+    for (index, record) in csv_reader.records().enumerate() {
+        let (line, card) = match record {
+            Ok(record) => (
+                record.position().map(|p| p.line() as usize).unwrap_or(index + 2),
+                record
+                    .deserialize::<CardRow>(Some(&headers))
+                    .map_err(|e| e.to_string())
+                    .and_then(|row| row.to_card()),
+            ),
+            Err(e) => (
+                e.position().map(|p| p.line() as usize).unwrap_or(index + 2),
+                Err(e.to_string()),
+            ),
+        };
+        rows.push(ImportedRow { line, card });
+    }
+    Ok(rows)
+}
+
+#[cfg(test)]
+mod tests {
+    use fake::Fake;
+
+    use crate::cards::types::attack::tests::FakeAttackCard;
+    use crate::cards::types::control::tests::FakeControlCard;
+    use crate::cards::types::lucky::tests::FakeLuckyCard;
+    use crate::cards::types::oopsie::tests::FakeOopsieCard;
+
+    use super::*;
+
+    fn round_trip(card: Card) {
+        let mut buffer = vec![];
+        write_cards_csv(&[&card], &mut buffer).unwrap();
+
+        let rows = read_cards_csv(buffer.as_slice()).unwrap();
+
+        assert_eq!(rows.len(), 1);
+        assert_eq!(rows[0].card, Ok(card));
+    }
+
+    #[test]
+    fn cards_survive_export_and_import() {
+        round_trip(Card::Attack(FakeAttackCard.fake()));
+        round_trip(Card::Oopsie(FakeOopsieCard.fake()));
+        round_trip(Card::Lucky(FakeLuckyCard.fake()));
+        round_trip(Card::Control(FakeControlCard.fake()));
+    }
+
+    #[test]
+    fn invalid_rows_are_reported_with_line() {
+        let content = "type,title,description,effect,effect_description,targets,fix_cost_min,fix_cost_max,fix_rounds,people_hours,revenue_share,damage,duration,modifier,price,reduce
+event,Audit,An auditor,other,Nothing happens,,,,,,,,,,,
+oopsie,Open port,SSH,attackSurface,Port 22,database;frontend,2,,,,,,,,,
+attack,DDoS,Requests,incident,Down,frontend,,,,,120,,2,,,
+evaluation,Review,x,other,x,,,,,,,,,,,
+";
+
+        let rows = read_cards_csv(content.as_bytes()).unwrap();
+
+        assert_eq!(rows.len(), 4);
+        assert_eq!(rows[0].line, 2);
+        assert_eq!(rows[0].card.as_ref().unwrap().title().value(), "Audit");
+        assert_eq!(rows[1].line, 3);
+        assert_eq!(
+            rows[1].card,
+            Err("oopsie cards need fix_cost_min and fix_cost_max".to_string())
+        );
+        assert_eq!(
+            rows[2].card,
+            Err("revenue_share must be between 0 and 100, was 120".to_string())
+        );
+        assert!(rows[3].card.as_ref().unwrap_err().starts_with("unknown card type"));
+    }
+
+    #[test]
+    fn targets_are_separated_by_semicolon() {
+        let row = CardRow {
+            card_type: "oopsie".to_string(),
+            effect: "attackSurface".to_string(),
+            targets: "database; frontend".to_string(),
+            fix_cost_min: Some(1),
+            fix_cost_max: Some(2),
+            ..CardRow::default()
+        };
+
+        let card = row.to_card().unwrap();
+
+        assert_eq!(
+            card.effect().targets(),
+            &[Target::new("database"), Target::new("frontend")]
+        );
+        assert_eq!(CardRow::from(&card).targets, "database;frontend");
+    }
+}
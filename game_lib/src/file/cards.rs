@@ -1,12 +1,14 @@
+use std::fs;
 use std::fs::File;
 use std::io;
 use std::io::Write;
 use std::path::{Path, PathBuf};
 
-use serde_json;
-
 use crate::cards::types::card_model::{Card, CardTrait};
-use crate::file::general::{count_cards_in_directory, ensure_directory_exists};
+use crate::file::format::{to_versioned_json, FileKind};
+use crate::file::general::{
+    count_cards_in_directory, ensure_directory_exists, get_files_in_directory_with_filter,
+};
 
 pub fn write_card_to_file(card: &Card, base_path: Option<&str>) -> std::io::Result<()> {
     let card_directory = get_card_directory(card);
@@ -47,15 +49,105 @@ pub fn write_card_to_file(card: &Card, base_path: Option<&str>) -> std::io::Resu
 }
 
 fn generate_filename(title: &str, current_cards_count: u32) -> String {
-    let new_card_number = current_cards_count + 1;
-    let padded_prefix = format!("{:0>4}", new_card_number);
+    numbered_filename(title, current_cards_count + 1)
+}
+
+fn numbered_filename(title: &str, card_number: u32) -> String {
+    let padded_prefix = format!("{:0>4}", card_number);
     let sanitized_title = sanitize_filename(title);
     let shortened_title: String = sanitized_title.chars().take(60).collect();
     format!("{}-{}.json", padded_prefix, shortened_title)
 }
 
+/// Number and title part of a card file name, e.g. `(3, "open_port.json")` for `0003-open_port.json`.
+fn split_filename(path: &Path) -> Option<(u32, String)> {
+    let file_name = path.file_name()?.to_str()?;
+    let (number, rest) = file_name.split_once('-')?;
+    Some((number.parse().ok()?, rest.to_string()))
+}
+
+/// Number of a card file, e.g. 3 for `0003-open_port.json`.
+pub fn card_number(path: &Path) -> Option<u32> {
+    split_filename(path).map(|(number, _)| number)
+}
+
+/// Name of the card file with another number, e.g. `0007-open_port.json` for `0003-open_port.json`.
+pub fn renumbered_filename(path: &Path, card_number: u32) -> Option<String> {
+    split_filename(path).map(|(_, rest)| format!("{:0>4}-{}", card_number, rest))
+}
+
+/// Overwrites a stored card. If the title changed the file is renamed, it keeps its number. Returns
+/// the path the card is stored in afterwards.
+pub fn update_card_file(card: &Card, path: &Path) -> io::Result<PathBuf> {
+    let new_path = match split_filename(path) {
+        Some((number, _)) => path.with_file_name(numbered_filename(card.title().value(), number)),
+        None => path.to_path_buf(),
+    };
+    if new_path != path && new_path.exists() {
+        return Err(io::Error::new(
+            io::ErrorKind::AlreadyExists,
+            format!("Card file {} already exists", new_path.display()),
+        ));
+    }
+    write_data_to_file(card, path)?;
+    if new_path != path {
+        fs::rename(path, &new_path)?;
+    }
+    Ok(new_path)
+}
+
+/// Deletes a stored card. The cards with a higher number in the same directory move down by one, so
+/// the numbers stay without gaps and new cards get the next free number. Returns the renamed files.
+/// If a card can not be renamed, the renamed cards and the deleted card are restored.
+pub fn delete_card_file(path: &Path) -> io::Result<Vec<(PathBuf, PathBuf)>> {
+    let deleted_number = match split_filename(path) {
+        Some((number, _)) => number,
+        None => return fs::remove_file(path).map(|_| vec![]),
+    };
+    let directory = path.parent().unwrap_or(Path::new("."));
+    let mut files: Vec<PathBuf> =
+        get_files_in_directory_with_filter(directory.to_str().unwrap(), ".json")?
+            .into_iter()
+            .map(PathBuf::from)
+            .collect();
+    files.sort();
+    let renames: Vec<(PathBuf, PathBuf)> = files
+        .into_iter()
+        .filter_map(|file| {
+            let (number, rest) = split_filename(&file)?;
+            (number > deleted_number).then(|| {
+                let new_path = file.with_file_name(format!("{:0>4}-{}", number - 1, rest));
+                (file, new_path)
+            })
+        })
+        .collect();
+
+    // the card is only moved aside until all following cards are renumbered
+    let parked = path.with_extension("json.deleted");
+    fs::rename(path, &parked)?;
+    for (index, (from, to)) in renames.iter().enumerate() {
+        if let Err(err) = fs::rename(from, to) {
+            for (from, to) in renames[..index].iter().rev() {
+                let _ = fs::rename(to, from);
+            }
+            let _ = fs::rename(&parked, path);
+            return Err(io::Error::new(
+                err.kind(),
+                format!(
+                    "Could not rename {} to {}, the card was not deleted ({})",
+                    from.display(),
+                    to.display(),
+                    err
+                ),
+            ));
+        }
+    }
+    fs::remove_file(&parked)?;
+    Ok(renames)
+}
+
 pub fn write_data_to_file(card: &Card, path: &Path) -> std::io::Result<()> {
-    match serde_json::to_string_pretty(&card) {
+    match to_versioned_json(FileKind::Card, card) {
         Ok(serialized_card) => {
             let mut file = File::create(path)?;
             file.write_all(serialized_card.as_bytes())
@@ -67,12 +159,25 @@ pub fn write_data_to_file(card: &Card, path: &Path) -> std::io::Result<()> {
     }
 }
 
+/// Directories of the card repository which contain cards, one per card type.
+pub const CARD_DIRECTORIES: [&str; 5] = ["events", "attacks", "oopsies", "lucky", "controls"];
+
+/// Id of a card file: its directory and the number in front of the title, e.g. `oopsies/0003` for
+/// `oopsies/0003-open_port.json`.
+pub fn card_id(path: &Path) -> Option<String> {
+    let directory = path.parent()?.file_name()?.to_str()?;
+    let stem = path.file_stem()?.to_str()?;
+    let number = stem.split('-').next().unwrap_or(stem);
+    Some(format!("{}/{}", directory, number))
+}
+
 pub fn get_card_directory(card: &Card) -> &'static str {
     match card {
         Card::Event(_) => "events",
         Card::Attack(_) => "attacks",
         Card::Oopsie(_) => "oopsies",
         Card::Lucky(_) => "lucky",
+        Card::Control(_) => "controls",
         Card::Evaluation(_) => panic!("Evaluation cards should not be written to file"),
     }
 }
@@ -84,6 +189,83 @@ fn replace_invalid_character(c: char) -> char {
     }
 }
 
-fn sanitize_filename(filename: &str) -> String {
+pub(crate) fn sanitize_filename(filename: &str) -> String {
     filename.chars().map(replace_invalid_character).collect()
 }
+
+#[cfg(test)]
+mod tests {
+    use tempfile::{tempdir, TempDir};
+
+    use crate::cards::properties::description::Description;
+    use crate::cards::properties::effect::Effect;
+    use crate::cards::properties::title::Title;
+    use crate::cards::types::event::EventCard;
+
+    use super::*;
+
+    fn event(title: &str) -> Card {
+        Card::Event(EventCard {
+            title: Title::from(title.to_string()),
+            description: Description::from("Something happens".to_string()),
+            effect: Effect::NOP,
+            options: vec![],
+        })
+    }
+
+    fn repository_with_events(titles: &[&str]) -> TempDir {
+        let base = tempdir().unwrap();
+        for title in titles {
+            write_card_to_file(&event(title), base.path().to_str()).unwrap();
+        }
+        base
+    }
+
+    fn file_names(directory: &Path) -> Vec<String> {
+        let mut names: Vec<String> = fs::read_dir(directory)
+            .unwrap()
+            .map(|entry| entry.unwrap().file_name().to_str().unwrap().to_string())
+            .collect();
+        names.sort();
+        names
+    }
+
+    #[test]
+    fn update_renames_the_file_but_keeps_the_number() {
+        let base = repository_with_events(&["Audit", "Party"]);
+        let directory = base.path().join("events");
+
+        let path = update_card_file(&event("Big Party"), &directory.join("0002-Party.json")).unwrap();
+
+        assert_eq!(path, directory.join("0002-Big_Party.json"));
+        assert_eq!(file_names(&directory), vec!["0001-Audit.json", "0002-Big_Party.json"]);
+    }
+
+    #[test]
+    fn delete_moves_following_cards_down() {
+        let base = repository_with_events(&["Audit", "Party", "Outage"]);
+        let directory = base.path().join("events");
+
+        let renamed = delete_card_file(&directory.join("0001-Audit.json")).unwrap();
+
+        assert_eq!(renamed.len(), 2);
+        assert_eq!(file_names(&directory), vec!["0001-Party.json", "0002-Outage.json"]);
+        write_card_to_file(&event("Hack"), base.path().to_str()).unwrap();
+        assert!(directory.join("0003-Hack.json").exists());
+    }
+
+    #[test]
+    fn delete_restores_all_cards_if_renumbering_fails() {
+        let base = repository_with_events(&["Audit", "Party", "Outage"]);
+        let directory = base.path().join("events");
+        fs::create_dir_all(directory.join("0002-Outage.json").join("blocker")).unwrap();
+
+        let result = delete_card_file(&directory.join("0001-Audit.json"));
+
+        assert!(result.is_err());
+        assert!(directory.join("0001-Audit.json").exists());
+        assert!(directory.join("0002-Party.json").exists());
+        assert!(directory.join("0003-Outage.json").exists());
+        assert!(!directory.join("0001-Party.json").exists());
+    }
+}
@@ -0,0 +1,391 @@
+use serde::de::DeserializeOwned;
+use serde::Serialize;
+use serde_json::{json, Map, Value};
+
+use crate::file::scenarios::SCENARIO_DIRECTORY;
+
+/*
+Card and scenario files carry the version of their format in the field `version`. Files written before
+the field existed are recognized by their structure. Older files are upgraded by a chain of migrations,
+each one upgrades a file by one version, so a file of any version reaches the current one. The loader
+upgrades old files in memory, `migration run` writes the upgraded files.
+
+Versions of the card format:
+1. cards are tagged with their `type`, before the type followed from the directory
+2. incidents have a `duration` in rounds
+3. `action` and `targets` become the `effect`, incidents become attacks in the `attacks` directory
+4. the effect of an attack has an incident impact
+ */
+
+pub const VERSION_FIELD: &str = "version";
+pub const CARD_FORMAT_VERSION: u64 = 4;
+pub const SCENARIO_FORMAT_VERSION: u64 = 1;
+
+/// Kinds of files of the card repository which have a versioned format.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum FileKind {
+    Card,
+    Scenario,
+}
+
+impl FileKind {
+    /// The kind of the files in a directory of the card repository, e.g. `oopsies`. The directories
+    /// cards were stored in before version 3 are included.
+    pub fn of_directory(directory: &str) -> Option<FileKind> {
+        match directory {
+            "events" | "attacks" | "incidents" | "oopsies" | "lucky" | "controls" => Some(FileKind::Card),
+            SCENARIO_DIRECTORY => Some(FileKind::Scenario),
+            _ => None,
+        }
+    }
+
+    pub fn current_version(&self) -> u64 {
+        match self {
+            FileKind::Card => CARD_FORMAT_VERSION,
+            FileKind::Scenario => SCENARIO_FORMAT_VERSION,
+        }
+    }
+
+    /// The version in the file or, for files without it, the version their structure belongs to.
+    pub fn detect_version(&self, content: &Value) -> u64 {
+        if let Some(version) = content.get(VERSION_FIELD).and_then(Value::as_u64) {
+            return version;
+        }
+        match self {
+            FileKind::Card => detect_card_version(content),
+            FileKind::Scenario => SCENARIO_FORMAT_VERSION,
+        }
+    }
+
+    fn migrations(&self) -> &'static [Migration] {
+        match self {
+            FileKind::Card => &CARD_MIGRATIONS,
+            FileKind::Scenario => &[],
+        }
+    }
+}
+
+fn detect_card_version(content: &Value) -> u64 {
+    let card_type = match content.get("type").and_then(Value::as_str) {
+        Some(card_type) => card_type,
+        None => return 0,
+    };
+    if content.get("action").is_some() {
+        return match card_type == "incident" && content.get("duration").is_none() {
+            true => 1,
+            false => 2,
+        };
+    }
+    match content.pointer("/effect/incident").and_then(Value::as_array) {
+        Some(incident) if incident.len() < 3 => 3,
+        _ => CARD_FORMAT_VERSION,
+    }
+}
+
+/// The content of a file of the card repository together with the name of the directory it is stored
+/// in. Migrations may move a file into another directory.
+#[derive(Debug, Clone, PartialEq)]
+pub struct VersionedFile {
+    pub directory: String,
+    pub content: Value,
+}
+
+/// Result of upgrading a file. Notes tell about values a migration had to make up.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Upgrade {
+    pub from: u64,
+    pub to: u64,
+    pub file: VersionedFile,
+    pub notes: Vec<String>,
+}
+
+struct Migration {
+    /// The version the migration upgrades from, it upgrades to the next version.
+    from: u64,
+    migrate: fn(&mut VersionedFile) -> Result<Vec<String>, String>,
+}
+
+const CARD_MIGRATIONS: [Migration; 4] = [
+    Migration {
+        from: 0,
+        migrate: add_type_tag,
+    },
+    Migration {
+        from: 1,
+        migrate: add_incident_duration,
+    },
+    Migration {
+        from: 2,
+        migrate: move_action_into_effect,
+    },
+    Migration {
+        from: 3,
+        migrate: add_incident_impact,
+    },
+];
+
+/// Upgrades the file to the current version of its format and sets the version field.
+pub fn upgrade(kind: FileKind, file: VersionedFile) -> Result<Upgrade, String> {
+    let from = kind.detect_version(&file.content);
+    let to = kind.current_version();
+    if from > to {
+        return Err(format!(
+            "format version {} is newer than version {} this game supports",
+            from, to
+        ));
+    }
+    let mut file = file;
+    let mut notes = vec![];
+    for migration in kind.migrations().iter().filter(|m| m.from >= from) {
+        notes.extend((migration.migrate)(&mut file)?);
+    }
+    object(&mut file)?.insert(VERSION_FIELD.to_string(), json!(to));
+    Ok(Upgrade {
+        from,
+        to,
+        file,
+        notes,
+    })
+}
+
+/// Reads a card or a scenario from the content of its file. Files of an older version are upgraded
+/// first. Errors of files in the current version keep line and column.
+pub fn read_versioned<T: DeserializeOwned>(
+    kind: FileKind,
+    directory: &str,
+    content: &str,
+) -> Result<T, String> {
+    let value: Value = serde_json::from_str(content).map_err(|e| e.to_string())?;
+    let version = kind.detect_version(&value);
+    if version == kind.current_version() {
+        return serde_json::from_str(content).map_err(|e| e.to_string());
+    }
+    let file = VersionedFile {
+        directory: directory.to_string(),
+        content: value,
+    };
+    let upgrade = upgrade(kind, file)?;
+    serde_json::from_value(upgrade.file.content)
+        .map_err(|e| format!("{} (upgraded from format version {})", e, version))
+}
+
+#[derive(Serialize)]
+struct VersionedContent<'a, T: Serialize> {
+    version: u64,
+    #[serde(flatten)]
+    content: &'a T,
+}
+
+/// Serializes a card or a scenario for its file, starting with the current version of the format.
+pub fn to_versioned_json<T: Serialize>(kind: FileKind, content: &T) -> serde_json::Result<String> {
+    serde_json::to_string_pretty(&VersionedContent {
+        version: kind.current_version(),
+        content,
+    })
+}
+
+fn object(file: &mut VersionedFile) -> Result<&mut Map<String, Value>, String> {
+    file.content
+        .as_object_mut()
+        .ok_or_else(|| "the file contains no JSON object".to_string())
+}
+
+fn add_type_tag(file: &mut VersionedFile) -> Result<Vec<String>, String> {
+    let card_type = match file.directory.as_str() {
+        "events" => "event",
+        "incidents" => "incident",
+        "oopsies" => "oopsie",
+        "lucky" => "lucky",
+        directory => {
+            return Err(format!(
+                "cards without type can not be upgraded in directory {}",
+                directory
+            ))
+        }
+    };
+    object(file)?.insert("type".to_string(), json!(card_type));
+    Ok(vec![])
+}
+
+fn add_incident_duration(file: &mut VersionedFile) -> Result<Vec<String>, String> {
+    let card = object(file)?;
+    if card.get("type") == Some(&json!("incident")) && !card.contains_key("duration") {
+        card.insert("duration".to_string(), json!(3));
+        return Ok(vec!["the incident had no duration, it lasts 3 rounds".to_string()]);
+    }
+    Ok(vec![])
+}
+
+fn move_action_into_effect(file: &mut VersionedFile) -> Result<Vec<String>, String> {
+    let is_incident = object(file)?.get("type") == Some(&json!("incident"));
+    if is_incident {
+        file.directory = "attacks".to_string();
+    }
+    let card = object(file)?;
+    let targets = card.remove("targets").unwrap_or(json!([]));
+    if let Some(action) = card.remove("action") {
+        let effect = match card.get("type").and_then(Value::as_str) {
+            Some("incident") => json!({ "incident": [action, targets] }),
+            Some("oopsie") => json!({ "attackSurface": [action, targets] }),
+            _ => json!({ "other": action }),
+        };
+        card.insert("effect".to_string(), effect);
+    }
+    if is_incident {
+        card.insert("type".to_string(), json!("attack"));
+        let rounds = card.remove("duration").unwrap_or(json!(3));
+        card.insert("duration".to_string(), json!({ "rounds": rounds }));
+    }
+    if let Some(fix_cost) = card.remove("fix_cost") {
+        card.insert(
+            "fixCost".to_string(),
+            json!({ "min": fix_cost["min"], "max": fix_cost["max"] }),
+        );
+    }
+    Ok(vec![])
+}
+
+fn add_incident_impact(file: &mut VersionedFile) -> Result<Vec<String>, String> {
+    match file.content.pointer_mut("/effect/incident").and_then(Value::as_array_mut) {
+        Some(incident) if incident.len() < 3 => {
+            incident.push(json!({ "fixed": 0 }));
+            Ok(vec![
+                "the attack had no incident impact, it costs 0 resources".to_string(),
+            ])
+        }
+        _ => Ok(vec![]),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use rstest::rstest;
+
+    use crate::cards::game_variants::scenario::Scenario;
+    use crate::cards::properties::duration::Duration;
+    use crate::cards::properties::effect::Effect;
+    use crate::cards::properties::incident_impact::IncidentImpact;
+    use crate::cards::types::card_model::{Card, CardTrait};
+    use crate::world::resources::Resources;
+
+    use super::*;
+
+    fn incident_v0() -> Value {
+        json!({
+            "title": "DDoS",
+            "description": "Too many requests",
+            "action": "The shop is down",
+            "targets": ["frontend"]
+        })
+    }
+
+    #[rstest]
+    #[case(incident_v0(), 0)]
+    #[case(json!({ "type": "incident", "title": "DDoS", "action": "Down" }), 1)]
+    #[case(json!({ "type": "incident", "title": "DDoS", "action": "Down", "duration": 2 }), 2)]
+    #[case(json!({ "type": "event", "title": "Audit", "action": "Prepare" }), 2)]
+    #[case(json!({ "type": "attack", "effect": { "incident": ["Down", ["frontend"]] } }), 3)]
+    #[case(json!({ "type": "attack", "effect": { "incident": ["Down", ["frontend"], { "fixed": 2 }] } }), 4)]
+    #[case(json!({ "type": "event", "effect": { "other": "Prepare" } }), 4)]
+    #[case(json!({ "version": 2, "type": "event", "effect": { "other": "Prepare" } }), 2)]
+    fn detects_card_versions(#[case] content: Value, #[case] expected: u64) {
+        assert_eq!(FileKind::Card.detect_version(&content), expected);
+    }
+
+    #[test]
+    fn upgrades_cards_of_the_first_format_through_all_versions() {
+        let file = VersionedFile {
+            directory: "incidents".to_string(),
+            content: incident_v0(),
+        };
+
+        let upgrade = upgrade(FileKind::Card, file).unwrap();
+
+        assert_eq!((upgrade.from, upgrade.to), (0, CARD_FORMAT_VERSION));
+        assert_eq!(upgrade.file.directory, "attacks");
+        assert_eq!(upgrade.file.content[VERSION_FIELD], json!(CARD_FORMAT_VERSION));
+        assert_eq!(upgrade.notes.len(), 2);
+        let card: Card = serde_json::from_value(upgrade.file.content).unwrap();
+        match card {
+            Card::Attack(attack) => {
+                assert_eq!(attack.duration, Duration::Rounds(3));
+                assert!(matches!(
+                    attack.effect,
+                    Effect::Incident(_, ref targets, IncidentImpact::Fixed(ref r))
+                        if targets.len() == 1 && r == &Resources::new(0)
+                ));
+            }
+            card => panic!("expected an attack, got {:?}", card),
+        }
+    }
+
+    #[test]
+    fn upgrades_oopsies_with_fix_costs() {
+        let file = VersionedFile {
+            directory: "oopsies".to_string(),
+            content: json!({
+                "type": "oopsie",
+                "title": "Open port",
+                "description": "SSH is open",
+                "action": "Port 22",
+                "targets": ["database"],
+                "fix_cost": { "min": 2, "max": 4 }
+            }),
+        };
+
+        let upgrade = upgrade(FileKind::Card, file).unwrap();
+
+        assert_eq!(upgrade.from, 2);
+        assert!(upgrade.notes.is_empty());
+        let card: Card = serde_json::from_value(upgrade.file.content).unwrap();
+        assert_eq!(card.effect().targets().len(), 1);
+    }
+
+    #[test]
+    fn current_cards_only_get_the_version() {
+        let content = json!({ "type": "event", "title": "Audit", "description": "", "effect": { "other": "Prepare" } });
+        let file = VersionedFile {
+            directory: "events".to_string(),
+            content: content.clone(),
+        };
+
+        let upgrade = upgrade(FileKind::Card, file).unwrap();
+
+        let mut expected = content;
+        expected[VERSION_FIELD] = json!(CARD_FORMAT_VERSION);
+        assert_eq!(upgrade.file.content, expected);
+    }
+
+    #[test]
+    fn newer_versions_are_rejected() {
+        let file = VersionedFile {
+            directory: SCENARIO_DIRECTORY.to_string(),
+            content: json!({ "version": 99 }),
+        };
+
+        assert!(upgrade(FileKind::Scenario, file)
+            .unwrap_err()
+            .contains("newer than version 1"));
+    }
+
+    #[test]
+    fn reads_old_files_and_writes_them_with_version() {
+        let content = r#"{ "type": "event", "title": "Audit", "description": "Prepare", "action": "Nothing" }"#;
+
+        let card: Card = read_versioned(FileKind::Card, "events", content).unwrap();
+        let written = to_versioned_json(FileKind::Card, &card).unwrap();
+
+        assert!(written.starts_with("{\n  \"version\": 4,\n  \"type\": \"event\""));
+        assert_eq!(read_versioned::<Card>(FileKind::Card, "events", &written).unwrap(), card);
+    }
+
+    #[test]
+    fn errors_of_current_files_keep_the_line() {
+        let content = "{\n  \"title\": \"Audit\",\n  \"description\": 3\n}";
+
+        let error = read_versioned::<Scenario>(FileKind::Scenario, SCENARIO_DIRECTORY, content)
+            .unwrap_err();
+
+        assert!(error.contains("line 3"), "{}", error);
+    }
+}
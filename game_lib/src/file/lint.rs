@@ -0,0 +1,443 @@
+use std::collections::HashMap;
+use std::fmt::{Display, Formatter};
+use std::fs;
+use std::io;
+use std::path::{Path, PathBuf};
+
+use serde_json::Value;
+
+use crate::cards::properties::target_taxonomy::TargetTaxonomy;
+use crate::cards::types::card_model::{Card, CardTrait};
+use crate::file::cards::{get_card_directory, CARD_DIRECTORIES};
+use crate::file::format::{FileKind, CARD_FORMAT_VERSION, VERSION_FIELD};
+use crate::file::general::get_files_in_directory_with_filter;
+
+/*
+Lint for the card repository. Every card file is checked on its own first: it must be valid JSON, must
+not contain values the card model can not represent and must deserialize into a card which fits its
+directory. Cards of an older format version are only reported as outdated. Afterwards the titles of
+all cards are compared. Each finding points to the line of the file the problem was found in, so it
+can be fixed without guessing.
+ */
+
+/// A problem found in a card file.
+#[derive(Debug, Clone, PartialEq)]
+pub struct CardDiagnostic {
+    pub file: PathBuf,
+    pub line: usize,
+    pub message: String,
+}
+
+impl Display for CardDiagnostic {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}:{}: {}", self.file.display(), self.line, self.message)
+    }
+}
+
+/// A problem found in the content of a single card, `line` starts at 1.
+#[derive(Debug, Clone, PartialEq)]
+pub struct LintIssue {
+    pub line: usize,
+    pub message: String,
+}
+
+impl LintIssue {
+    fn new(line: usize, message: String) -> Self {
+        LintIssue { line, message }
+    }
+}
+
+/// Keys whose values are percentages and must be between 0 and 100.
+const PERCENTAGE_KEYS: [&str; 2] = ["partOfRevenue", "reduce"];
+
+/// Checks all card files of the repository, directory by directory and file by file.
+pub fn lint_card_repository(
+    base_path: &str,
+    taxonomy: &TargetTaxonomy,
+) -> io::Result<Vec<CardDiagnostic>> {
+    let mut diagnostics = vec![];
+    let mut titles: HashMap<String, PathBuf> = HashMap::new();
+    for directory in CARD_DIRECTORIES {
+        let path = PathBuf::from(base_path).join(directory);
+        if !path.exists() {
+            continue;
+        }
+        let mut files = get_files_in_directory_with_filter(path.to_str().unwrap(), ".json")?;
+        files.sort();
+        for file in files.into_iter().map(PathBuf::from) {
+            let content = fs::read_to_string(&file)?;
+            let (card, issues) = lint_card(content.as_str(), directory, taxonomy);
+            diagnostics.extend(issues.into_iter().map(|issue| diagnostic(&file, issue)));
+            if let Some(card) = card {
+                let title = card.title().value().trim().to_lowercase();
+                if title.is_empty() {
+                    continue;
+                }
+                match titles.get(&title) {
+                    Some(first) => diagnostics.push(diagnostic(
+                        &file,
+                        LintIssue::new(
+                            line_of_key(content.as_str(), "title"),
+                            format!(
+                                "duplicate title '{}', already used in {}",
+                                card.title().value(),
+                                first.display()
+                            ),
+                        ),
+                    )),
+                    None => {
+                        titles.insert(title, file.clone());
+                    }
+                }
+            }
+        }
+    }
+    Ok(diagnostics)
+}
+
+fn diagnostic(file: &Path, issue: LintIssue) -> CardDiagnostic {
+    CardDiagnostic {
+        file: file.to_path_buf(),
+        line: issue.line,
+        message: issue.message,
+    }
+}
+
+/// Checks the content of a card file stored in the given directory. Returns the card if it could be
+/// read together with all issues found.
+pub fn lint_card(
+    content: &str,
+    directory: &str,
+    taxonomy: &TargetTaxonomy,
+) -> (Option<Card>, Vec<LintIssue>) {
+    lint_card_in(content, Some(directory), taxonomy)
+}
+
+/// Checks the content of a card which is not stored yet. It is checked like a stored card, only the
+/// directory is left out as it follows from the type of the card when it is written.
+pub fn lint_new_card(content: &str, taxonomy: &TargetTaxonomy) -> (Option<Card>, Vec<LintIssue>) {
+    lint_card_in(content, None, taxonomy)
+}
+
+fn lint_card_in(
+    content: &str,
+    directory: Option<&str>,
+    taxonomy: &TargetTaxonomy,
+) -> (Option<Card>, Vec<LintIssue>) {
+    let value: Value = match serde_json::from_str(content) {
+        Ok(value) => value,
+        Err(e) => return (None, vec![LintIssue::new(e.line(), e.to_string())]),
+    };
+    let version = FileKind::Card.detect_version(&value);
+    if version != CARD_FORMAT_VERSION {
+        let message = match version < CARD_FORMAT_VERSION {
+            true => format!(
+                "format version {} is outdated, run `seccardgame migration run` to upgrade it to version {}",
+                version, CARD_FORMAT_VERSION
+            ),
+            false => format!(
+                "format version {} is newer than version {} this game supports",
+                version, CARD_FORMAT_VERSION
+            ),
+        };
+        return (None, vec![LintIssue::new(line_of_key(content, VERSION_FIELD), message)]);
+    }
+    let range_issues = check_ranges(content, &value, &[]);
+    if !range_issues.is_empty() {
+        return (None, range_issues);
+    }
+    let card: Card = match serde_json::from_str(content) {
+        Ok(card) => card,
+        Err(e) => return (None, vec![LintIssue::new(e.line(), e.to_string())]),
+    };
+    let issues = check_card(content, &card, directory, taxonomy);
+    (Some(card), issues)
+}
+
+/// Finds values the card model rejects while reading: negative numbers and percentages above 100. Cards
+/// are internally tagged, so serde can not tell the line of these errors, this check can.
+fn check_ranges<'a>(content: &str, value: &'a Value, path: &[&'a str]) -> Vec<LintIssue> {
+    match value {
+        Value::Object(map) => map
+            .iter()
+            .flat_map(|(k, v)| check_ranges(content, v, &[path, &[k.as_str()]].concat()))
+            .collect(),
+        Value::Array(values) => values
+            .iter()
+            .flat_map(|v| check_ranges(content, v, path))
+            .collect(),
+        Value::Number(number) => {
+            let line = line_of_path(content, path);
+            let key = path.last().copied();
+            let name = key.unwrap_or("value");
+            match (number.as_i64(), number.as_u64()) {
+                (Some(n), _) if n < 0 => {
+                    vec![LintIssue::new(line, format!("{} must not be negative, was {}", name, n))]
+                }
+                (_, Some(n)) if n > 100 && key.is_some_and(|k| PERCENTAGE_KEYS.contains(&k)) => {
+                    vec![LintIssue::new(line, format!("{} must be between 0 and 100, was {}", name, n))]
+                }
+                _ => vec![],
+            }
+        }
+        _ => vec![],
+    }
+}
+
+fn check_card(
+    content: &str,
+    card: &Card,
+    directory: Option<&str>,
+    taxonomy: &TargetTaxonomy,
+) -> Vec<LintIssue> {
+    let mut issues = vec![];
+    if card.title().value().trim().is_empty() {
+        issues.push(LintIssue::new(
+            line_of_key(content, "title"),
+            "title must not be empty".to_string(),
+        ));
+    }
+    if card.description().value().trim().is_empty() {
+        issues.push(LintIssue::new(
+            line_of_key(content, "description"),
+            "description must not be empty".to_string(),
+        ));
+    }
+    match card {
+        Card::Evaluation(_) => issues.push(LintIssue::new(
+            line_of_key(content, "type"),
+            "evaluation cards are created with the deck and must not be stored".to_string(),
+        )),
+        _ if directory.is_some_and(|d| d != get_card_directory(card)) => issues.push(LintIssue::new(
+            line_of_key(content, "type"),
+            format!(
+                "{} belongs into directory '{}', not '{}'",
+                card.category(),
+                get_card_directory(card),
+                directory.unwrap_or_default()
+            ),
+        )),
+        _ => {}
+    }
+    if let Card::Oopsie(oopsie) = card {
+        if oopsie.fix_cost.min > oopsie.fix_cost.max {
+            issues.push(LintIssue::new(
+                line_of_key(content, "fixCost"),
+                format!(
+                    "fix cost min {} is greater than max {}",
+                    oopsie.fix_cost.min_value(),
+                    oopsie.fix_cost.max_value()
+                ),
+            ));
+        }
+    }
+    let unknown_targets = taxonomy.unknown_targets(card.effect().targets());
+    if !unknown_targets.is_empty() {
+        let names: Vec<&str> = unknown_targets.iter().map(|t| t.value()).collect();
+        issues.push(LintIssue::new(
+            line_of_key(content, "effect"),
+            format!("unknown targets: {}", names.join(", ")),
+        ));
+    }
+    issues
+}
+
+/// Line of a key of the card object, the first line if it is not found.
+fn line_of_key(content: &str, key: &str) -> usize {
+    line_of_path(content, &[key])
+}
+
+/// Line of the value at the path of object keys, arrays on the way are passed through. Every key is
+/// only searched inside the value of the key before it, so keys with the same name elsewhere in the
+/// file do not match. Falls back to the line of the deepest key found and the first line.
+fn line_of_path(content: &str, path: &[&str]) -> usize {
+    let mut line = 1;
+    let mut found_line = 1;
+    if path.is_empty() {
+        return found_line;
+    }
+    let mut depth: usize = 0;
+    // depth of the object each matched key was found in
+    let mut matched: Vec<usize> = vec![];
+    let mut chars = content.chars().peekable();
+    while let Some(c) = chars.next() {
+        match c {
+            '\n' => line += 1,
+            '{' | '[' => depth += 1,
+            '}' | ']' => {
+                depth = depth.saturating_sub(1);
+                if matched.last().is_some_and(|&d| depth < d) {
+                    break;
+                }
+            }
+            '"' => {
+                let mut string = String::new();
+                while let Some(c) = chars.next() {
+                    match c {
+                        '"' => break,
+                        '\\' => {
+                            chars.next();
+                        }
+                        _ => string.push(c),
+                    }
+                }
+                while chars.next_if(|c| c.is_whitespace() && *c != '\n').is_some() {}
+                if chars.peek() != Some(&':') {
+                    continue;
+                }
+                if matched.last().is_some_and(|&d| depth <= d) {
+                    // a sibling of the last key, the rest of the path is not inside its value
+                    break;
+                }
+                let inside_parent = !matched.is_empty() || depth == 1;
+                if inside_parent && string == path[matched.len()] {
+                    matched.push(depth);
+                    found_line = line;
+                    if matched.len() == path.len() {
+                        break;
+                    }
+                }
+            }
+            _ => {}
+        }
+    }
+    found_line
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn oopsie(fix_cost: &str, targets: &str) -> String {
+        format!(
+            r#"{{
+  "type": "oopsie",
+  "title": "Open port",
+  "description": "A port is open",
+  "effect": {{ "attackSurface": ["Port 22 is open", {}] }},
+  "fixCost": {}
+}}"#,
+            targets, fix_cost
+        )
+    }
+
+    fn lint(content: &str, directory: &str) -> Vec<String> {
+        lint_card(content, directory, &TargetTaxonomy::default())
+            .1
+            .iter()
+            .map(|issue| format!("{}: {}", issue.line, issue.message))
+            .collect()
+    }
+
+    #[test]
+    fn valid_card_has_no_issues() {
+        let content = oopsie(r#"{ "min": 1, "max": 3 }"#, r#"["database"]"#);
+
+        let (card, issues) = lint_card(content.as_str(), "oopsies", &TargetTaxonomy::default());
+
+        assert!(card.is_some());
+        assert!(issues.is_empty());
+    }
+
+    #[test]
+    fn syntax_errors_are_reported_with_line() {
+        let issues = lint("{\n  \"type\": \"event\",\n  \"title\" \"x\"\n}", "events");
+
+        assert_eq!(issues.len(), 1);
+        assert!(issues[0].starts_with("3: expected `:`"));
+    }
+
+    #[test]
+    fn out_of_range_values_are_reported_instead_of_panicking() {
+        let content = r#"{
+  "type": "attack",
+  "title": "DDoS",
+  "description": "Many requests",
+  "effect": {
+    "incident": ["Down", ["frontend"], { "partOfRevenue": 120 }]
+  },
+  "duration": { "rounds": 2 }
+}"#;
+
+        assert_eq!(
+            lint(content, "attacks"),
+            vec!["6: partOfRevenue must be between 0 and 100, was 120"]
+        );
+    }
+
+    #[test]
+    fn fix_costs_and_unknown_targets_are_reported() {
+        let content = oopsie(r#"{ "min": 5, "max": 3 }"#, r#"["mainframe"]"#);
+
+        assert_eq!(
+            lint(content.as_str(), "oopsies"),
+            vec![
+                "6: fix cost min 5 is greater than max 3",
+                "5: unknown targets: mainframe"
+            ]
+        );
+    }
+
+    #[test]
+    fn cards_in_wrong_directory_are_reported() {
+        let content = oopsie(r#"{ "min": 1, "max": 3 }"#, r#"["database"]"#);
+
+        assert_eq!(
+            lint(content.as_str(), "events"),
+            vec!["2: Oopise card belongs into directory 'oopsies', not 'events'"]
+        );
+    }
+
+    #[test]
+    fn outdated_format_versions_are_reported() {
+        let content = "{\n  \"type\": \"event\",\n  \"title\": \"Audit\",\n  \"action\": \"Prepare\"\n}";
+
+        assert_eq!(
+            lint(content, "events"),
+            vec!["1: format version 2 is outdated, run `seccardgame migration run` to upgrade it to version 4"]
+        );
+    }
+
+    #[test]
+    fn new_cards_are_checked_without_directory() {
+        let content = oopsie(r#"{ "min": 5, "max": 3 }"#, r#"["database"]"#);
+
+        let (card, issues) = lint_new_card(content.as_str(), &TargetTaxonomy::default());
+
+        assert!(card.is_some());
+        assert_eq!(issues, vec![LintIssue::new(6, "fix cost min 5 is greater than max 3".to_string())]);
+    }
+
+    #[test]
+    fn empty_title_and_description_are_reported() {
+        let content = r#"{
+  "type": "event",
+  "title": " ",
+  "description": "",
+  "effect": { "other": "Nothing happens" }
+}"#;
+
+        assert_eq!(
+            lint(content, "events"),
+            vec!["3: title must not be empty", "4: description must not be empty"]
+        );
+    }
+
+    #[test]
+    fn keys_are_searched_inside_their_parent() {
+        let content = r#"{
+  "effect": { "title": "nested", "fixCost": { "min": 1 } },
+  "title": "Top level",
+  "fixCost": {
+    "max": 3,
+    "min": 5
+  }
+}"#;
+
+        assert_eq!(line_of_key(content, "title"), 3);
+        assert_eq!(line_of_path(content, &["fixCost", "min"]), 6);
+        assert_eq!(line_of_path(content, &["effect", "fixCost", "min"]), 2);
+        assert_eq!(line_of_path(content, &["fixCost", "unknown"]), 4);
+        assert_eq!(line_of_key(content, "unknown"), 1);
+    }
+}
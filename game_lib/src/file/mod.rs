@@ -1,3 +1,8 @@
+pub mod card_csv;
 pub mod cards;
+pub mod format;
 pub mod general;
+pub mod lint;
 pub mod repository;
+pub mod scenarios;
+pub mod schema;
\ No newline at end of file
@@ -1,109 +1,502 @@
+use std::cell::{OnceCell, RefCell};
+use std::collections::HashMap;
 use std::ffi::OsString;
 use std::fs;
-use std::path::PathBuf;
+use std::path::{Path, PathBuf};
 use std::rc::Rc;
 
-use log::error;
+use log::{error, info, warn};
 
+use crate::cards::game_variants::rule_set::RuleSet;
+use crate::cards::game_variants::scenario::Scenario;
+use crate::cards::properties::target_taxonomy::TargetTaxonomy;
 use crate::cards::types::attack::AttackCard;
 use crate::cards::types::card_model::{Card, CardTrait};
+use crate::cards::types::control::ControlCard;
 use crate::cards::types::event::EventCard;
 use crate::cards::types::lucky::LuckyCard;
 use crate::cards::types::oopsie::OopsieCard;
 use crate::errors::{ErrorKind, GameLibError, GameLibResult};
-use crate::file::cards::get_card_directory;
+use crate::file::cards::{card_id, get_card_directory, CARD_DIRECTORIES};
+use crate::file::format::{read_versioned, FileKind};
 use crate::file::general::get_files_in_directory_with_filter;
-use crate::world::deck::{CardRc, DeckRepository, GameVariantsRepository};
+use crate::file::scenarios::SCENARIO_DIRECTORY;
+use crate::world::deck::{
+    CardRc, DeckRepository, GameVariantsRepository, TargetTaxonomyRepository,
+};
+
+const RULES_DIRECTORY: &str = "rules";
 
 pub struct DeckLoader {
     base_path: String,
+    /// Read on first use, the cards of every type are checked against it.
+    taxonomy: OnceCell<TargetTaxonomy>,
+    /// Files skipped by the last read of each directory, the load report is built from them.
+    skipped: RefCell<HashMap<&'static str, Vec<GameLibError>>>,
 }
 
 impl DeckRepository for DeckLoader {
     fn get_event_cards(&self) -> Vec<CardRc> {
-        self.read_all_cards(&EventCard::empty())
+        self.read_all_cards(get_card_directory(&EventCard::empty()))
     }
 
     fn get_lucky_cards(&self) -> Vec<CardRc> {
-        self.read_all_cards(&LuckyCard::empty())
+        self.read_all_cards(get_card_directory(&LuckyCard::empty()))
     }
 
     fn get_oopsie_cards(&self) -> Vec<CardRc> {
-        self.read_all_cards(&OopsieCard::empty())
+        self.read_all_cards(get_card_directory(&OopsieCard::empty()))
     }
 
     fn get_attack_cards(&self) -> Vec<CardRc> {
-        self.read_all_cards(&AttackCard::empty())
+        self.read_all_cards(get_card_directory(&AttackCard::empty()))
+    }
+
+    fn get_control_cards(&self) -> Vec<CardRc> {
+        self.read_all_cards(get_card_directory(&ControlCard::empty()))
     }
 }
 
 impl GameVariantsRepository for DeckLoader {
-    fn get_scenarios(&self) -> Vec<Rc<crate::cards::game_variants::scenario::Scenario>> {
-        let path = PathBuf::from(&self.base_path).join("scenarios");
-        let scenarios_path = path.to_str().expect("Scenarios path");
-        Self::load_cards(scenarios_path).unwrap_or_default()
+    /// Scenario files which can not be read are skipped, issues of the readable scenarios are logged.
+    fn get_scenarios(&self) -> Vec<Rc<Scenario>> {
+        let (scenarios, errors) = self.load_scenarios();
+        for err in errors {
+            error!("Skipped scenario: {}", err);
+        }
+        let cards = self.get_all_cards();
+        let rule_sets = self.get_rule_sets();
+        let taxonomy = self.get_target_taxonomy();
+        for scenario in &scenarios {
+            for issue in scenario.validate(&cards, &rule_sets, &taxonomy) {
+                warn!("Scenario '{}': {}", scenario.title.value(), issue);
+            }
+        }
+        scenarios
+    }
+
+    fn get_rule_sets(&self) -> Vec<Rc<RuleSet>> {
+        let (rule_sets, errors) = self.load_rule_sets();
+        for err in errors {
+            error!("Skipped rule set: {}", err);
+        }
+        rule_sets
     }
 }
 
+impl TargetTaxonomyRepository for DeckLoader {
+    /// Reads the taxonomy from the root of the card repository. Repositories without a taxonomy file
+    /// fall back to the flat default taxonomy. The file is only read once per loader.
+    fn get_target_taxonomy(&self) -> TargetTaxonomy {
+        self.taxonomy.get_or_init(|| self.read_target_taxonomy()).clone()
+    }
+}
+
+/// Files of the card repository which could not be loaded and were skipped.
+#[derive(Debug, Clone, PartialEq, Default)]
+pub struct LoadReport {
+    pub skipped: Vec<GameLibError>,
+}
+
+impl LoadReport {
+    pub fn is_empty(&self) -> bool {
+        self.skipped.is_empty()
+    }
+}
+
+/// A card of the repository together with the file it is stored in.
+#[derive(Debug, Clone)]
+pub struct StoredCard {
+    /// Directory and number of the file, see [`card_id`].
+    pub id: String,
+    pub path: PathBuf,
+    pub card: CardRc,
+}
+
+/// A card file of the repository, the card is the error if the file can not be loaded.
+#[derive(Debug, Clone)]
+pub struct CardFile {
+    /// Directory and number of the file, see [`card_id`].
+    pub id: String,
+    pub path: PathBuf,
+    pub card: GameLibResult<CardRc>,
+}
+
 impl DeckLoader {
     pub fn create(base_path: &str) -> Self {
         DeckLoader {
             base_path: base_path.to_string(),
+            taxonomy: OnceCell::new(),
+            skipped: RefCell::new(HashMap::new()),
         }
     }
 
-    fn read_all_cards(&self, card_type: &Card) -> Vec<Rc<Card>> {
-        let path = PathBuf::from(&self.base_path).join(get_card_directory(card_type));
+    /// Reports every file of the cards, scenarios and rule sets which was skipped while loading them.
+    /// Directories this loader has already read are not read again. Missing directories are not
+    /// reported.
+    pub fn load_report(&self) -> LoadReport {
+        for directory in CARD_DIRECTORIES {
+            if !self.was_read(directory) {
+                self.read_all_cards(directory);
+            }
+        }
+        if !self.was_read(SCENARIO_DIRECTORY) {
+            self.load_scenarios();
+        }
+        if !self.was_read(RULES_DIRECTORY) {
+            self.load_rule_sets();
+        }
+        let skipped = self.skipped.borrow();
+        LoadReport {
+            skipped: CARD_DIRECTORIES
+                .iter()
+                .chain([SCENARIO_DIRECTORY, RULES_DIRECTORY].iter())
+                .flat_map(|directory| skipped[directory].clone())
+                .collect(),
+        }
+    }
+
+    /// All scenarios of the repository together with the files which could not be read as scenario.
+    pub fn load_scenarios(&self) -> (Vec<Rc<Scenario>>, Vec<GameLibError>) {
+        self.load_variants(SCENARIO_DIRECTORY)
+    }
+
+    /// All rule sets of the repository together with the files which could not be read as rule set.
+    pub fn load_rule_sets(&self) -> (Vec<Rc<RuleSet>>, Vec<GameLibError>) {
+        self.load_variants(RULES_DIRECTORY)
+    }
+
+    /// All cards of the repository which can be loaded, ordered by directory and file name.
+    pub fn get_stored_cards(&self) -> Vec<StoredCard> {
+        self.get_card_files()
+            .into_iter()
+            .filter_map(|file| match file.card {
+                Ok(card) => Some(StoredCard {
+                    id: file.id,
+                    path: file.path,
+                    card,
+                }),
+                Err(err) => {
+                    Self::log_skipped(&[err]);
+                    None
+                }
+            })
+            .collect()
+    }
+
+    /// All card files of the repository, also the ones which can not be loaded, ordered by directory
+    /// and file name.
+    pub fn get_card_files(&self) -> Vec<CardFile> {
+        let mut card_files = vec![];
+        for directory in CARD_DIRECTORIES {
+            let path = PathBuf::from(&self.base_path).join(directory);
+            if !path.exists() {
+                continue;
+            }
+            let mut files = match get_files_in_directory_with_filter(path.to_str().expect("Path"), ".json") {
+                Ok(files) => files,
+                Err(err) => {
+                    error!("Could not read cards in {} (caused by {})", path.display(), err);
+                    continue;
+                }
+            };
+            files.sort();
+            for file in files {
+                let path = PathBuf::from(&file);
+                let card = Self::deserialize_card::<Card>(file)
+                    .and_then(|card| Self::check_card(&path, &card).map(|_| Rc::new(card)));
+                match card_id(&path) {
+                    Some(id) => card_files.push(CardFile { id, path, card }),
+                    None => warn!("Skipped card without id: {}", path.display()),
+                }
+            }
+        }
+        card_files
+    }
+
+    /// The stored card with the given id, e.g. `oopsies/0003`.
+    pub fn find_stored_card(&self, id: &str) -> Option<StoredCard> {
+        self.get_stored_cards().into_iter().find(|stored| stored.id == id)
+    }
+
+    fn read_all_cards(&self, directory: &'static str) -> Vec<Rc<Card>> {
+        let path = PathBuf::from(&self.base_path).join(directory);
         let cards_path = path.to_str().expect("Card path");
-        match Self::load_cards(cards_path) {
-            Ok(cards) => cards,
+        match Self::load_checked(cards_path, Self::check_card) {
+            Ok((cards, errors)) => {
+                Self::log_skipped(&errors);
+                self.record_skipped(directory, errors);
+                self.warn_about_unknown_targets(&cards);
+                cards
+            }
             Err(err) => {
-                error!(
-                    "Could not read cards of type: {} (caused by {})",
-                    card_type.category(),
-                    err.to_string()
-                );
+                error!("Could not read cards in {} (caused by {})", directory, err);
+                self.record_skipped(directory, vec![]);
                 vec![]
             }
         }
     }
 
-    fn deserialize_card<T>(file: OsString) -> GameLibResult<T> 
+    fn record_skipped(&self, directory: &'static str, errors: Vec<GameLibError>) {
+        self.skipped.borrow_mut().insert(directory, errors);
+    }
+
+    fn was_read(&self, directory: &str) -> bool {
+        self.skipped.borrow().contains_key(directory)
+    }
+
+    /// Cards which can be read but would break the game, e.g. a fix cost range which can not be rolled.
+    fn check_card(file: &Path, card: &Card) -> GameLibResult<()> {
+        match card {
+            Card::Oopsie(oopsie) if oopsie.fix_cost.min > oopsie.fix_cost.max => {
+                Err(GameLibError::validation(
+                    file,
+                    "fixCost",
+                    format!(
+                        "min {} is greater than max {}",
+                        oopsie.fix_cost.min_value(),
+                        oopsie.fix_cost.max_value()
+                    )
+                    .as_str(),
+                ))
+            }
+            _ => Ok(()),
+        }
+    }
+
+    fn read_target_taxonomy(&self) -> TargetTaxonomy {
+        let path = PathBuf::from(&self.base_path).join(TargetTaxonomy::FILE_NAME);
+        if !path.exists() {
+            info!("No target taxonomy found at {:?}, using default", path);
+            return TargetTaxonomy::default();
+        }
+        match Self::deserialize_card::<TargetTaxonomy>(path.into_os_string()) {
+            Ok(taxonomy) => taxonomy,
+            Err(err) => {
+                error!("Could not read target taxonomy, using default (caused by {})", err);
+                TargetTaxonomy::default()
+            }
+        }
+    }
+
+    fn warn_about_unknown_targets(&self, cards: &[Rc<Card>]) {
+        let taxonomy = self.get_target_taxonomy();
+        for card in cards {
+            let unknown_targets = taxonomy.unknown_targets(card.effect().targets());
+            if !unknown_targets.is_empty() {
+                let names: Vec<&str> = unknown_targets.iter().map(|t| t.value()).collect();
+                warn!(
+                    "Card '{}' uses targets which are not part of the taxonomy: {}",
+                    card.title().value(),
+                    names.join(", ")
+                );
+            }
+        }
+    }
+
+    fn log_skipped(errors: &[GameLibError]) {
+        for err in errors {
+            error!("Skipped file: {}", err);
+        }
+    }
+
+    /// Card and scenario files of an older format version are upgraded while they are read.
+    fn deserialize_card<T>(file: OsString) -> GameLibResult<T>
     where T: serde::de::DeserializeOwned
     {
-        match fs::read_to_string(file.clone()) {
-            Ok(content) => {
-                let card = serde_json::from_str::<T>(content.as_str()).map_err(|err| {
-                    let msg = format!("Failed to deserialize {}", file.to_string_lossy());
-                    GameLibError::create_with_original(ErrorKind::IO, msg.as_str(), err.to_string())
-                });
-                match card {
-                    Ok(card) => Ok(card),
-                    Err(e) => Err(e)
-                }
-            }
+        let path = PathBuf::from(file);
+        let directory = path
+            .parent()
+            .and_then(|parent| parent.file_name())
+            .and_then(|name| name.to_str())
+            .unwrap_or_default()
+            .to_string();
+        match fs::read_to_string(&path) {
+            Ok(content) => match FileKind::of_directory(directory.as_str()) {
+                Some(kind) => read_versioned::<T>(kind, directory.as_str(), content.as_str())
+                    .map_err(|err| GameLibError::parse(&path, err)),
+                None => serde_json::from_str::<T>(content.as_str())
+                    .map_err(|err| GameLibError::parse(&path, err.to_string())),
+            },
             Err(err) => Err(GameLibError::create_with_original(
                 ErrorKind::IO,
-                "Could not deserialize card",
+                format!("Could not read {}", path.display()).as_str(),
                 err.to_string(),
             )),
         }
     }
 
-    fn load_cards<T>(cards_path: &str) -> GameLibResult<Vec<Rc<T>>> 
+    /// Loads the files of a directory of game variants. A missing directory has no variants, a directory
+    /// which can not be read is reported like a broken file.
+    fn load_variants<T>(&self, directory: &'static str) -> (Vec<Rc<T>>, Vec<GameLibError>)
+    where T: serde::de::DeserializeOwned {
+        let path = PathBuf::from(&self.base_path).join(directory);
+        let (variants, errors) = if !path.exists() {
+            (vec![], vec![])
+        } else {
+            match Self::load_checked(path.to_str().expect("Path"), |_, _| Ok(())) {
+                Ok(loaded) => loaded,
+                Err(err) => (vec![], vec![err]),
+            }
+        };
+        self.record_skipped(directory, errors.clone());
+        (variants, errors)
+    }
+
+    /// Loads all JSON files of the directory. Files which can not be deserialized or fail the check are
+    /// returned as errors next to the loaded values.
+    fn load_checked<T>(
+        cards_path: &str,
+        check: fn(&Path, &T) -> GameLibResult<()>,
+    ) -> GameLibResult<(Vec<Rc<T>>, Vec<GameLibError>)>
     where T: serde::de::DeserializeOwned {
         let files = get_files_in_directory_with_filter(cards_path, ".json").map_err(|e| {
             GameLibError::create_with_original(ErrorKind::IO, "Could not read cards", e.to_string())
         })?;
 
         let mut cards : Vec<Rc<T>> = vec![];
+        let mut errors = vec![];
         for file in files {
-            let card: Option<T> = Self::deserialize_card(file.clone()).ok();
-            match card {
-                None => error!("Could not deserialize card: {:?}", file),
-                Some(c) => cards.push(Rc::new(c))
+            let path = PathBuf::from(&file);
+            match Self::deserialize_card::<T>(file).and_then(|c| check(&path, &c).map(|_| c)) {
+                Ok(c) => cards.push(Rc::new(c)),
+                Err(err) => errors.push(err),
             };
         }
-        Ok(cards)
+        Ok((cards, errors))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use tempfile::{tempdir, TempDir};
+
+    use crate::cards::properties::target::Target;
+
+    use super::*;
+
+    fn repository_with_files(files: &[(&str, &str)]) -> TempDir {
+        let base = tempdir().unwrap();
+        for (name, content) in files {
+            let path = base.path().join(name);
+            fs::create_dir_all(path.parent().unwrap()).unwrap();
+            fs::write(path, content).unwrap();
+        }
+        base
+    }
+
+    #[test]
+    fn load_report_lists_skipped_files_with_kind_path_and_field() {
+        let base = repository_with_files(&[
+            (
+                "oopsies/0001-open_port.json",
+                r#"{ "type": "oopsie", "title": "Open port", "description": "",
+                     "effect": { "attackSurface": ["open", ["database"]] },
+                     "fixCost": { "min": 5, "max": 3 } }"#,
+            ),
+            ("events/0001-broken.json", r#"{ "type": "event", "title": 3 }"#),
+            (
+                "events/0002-ok.json",
+                r#"{ "type": "event", "title": "Audit", "description": "", "effect": { "other": "x" } }"#,
+            ),
+        ]);
+        let loader = DeckLoader::create(base.path().to_str().unwrap());
+
+        let report = loader.load_report();
+
+        assert_eq!(loader.get_event_cards().len(), 1);
+        assert!(loader.get_oopsie_cards().is_empty());
+        assert_eq!(report.skipped.len(), 2);
+        assert_eq!(report.skipped[0].kind(), &ErrorKind::Parse);
+        assert!(report.skipped[0].path().unwrap().ends_with("0001-broken.json"));
+        assert_eq!(report.skipped[1].kind(), &ErrorKind::Validation);
+        assert_eq!(report.skipped[1].field(), Some("fixCost"));
+    }
+
+    #[test]
+    fn load_report_does_not_read_loaded_directories_again() {
+        let base = repository_with_files(&[
+            ("events/0001-broken.json", r#"{ "type": "event", "title": 3 }"#),
+        ]);
+        let loader = DeckLoader::create(base.path().to_str().unwrap());
+
+        assert!(loader.get_event_cards().is_empty());
+        fs::remove_file(base.path().join("events/0001-broken.json")).unwrap();
+        let report = loader.load_report();
+
+        assert_eq!(report.skipped.len(), 1);
+        assert!(report.skipped[0].path().unwrap().ends_with("0001-broken.json"));
+    }
+
+    #[test]
+    fn stored_cards_have_directory_and_number_as_id() {
+        let base = repository_with_files(&[
+            (
+                "events/0002-audit.json",
+                r#"{ "type": "event", "title": "Audit", "description": "", "effect": { "other": "x" } }"#,
+            ),
+            (
+                "events/0001-party.json",
+                r#"{ "type": "event", "title": "Party", "description": "", "effect": { "other": "x" } }"#,
+            ),
+            ("events/0003-broken.json", r#"{ "type": "event" }"#),
+        ]);
+        let loader = DeckLoader::create(base.path().to_str().unwrap());
+
+        let ids: Vec<String> = loader.get_stored_cards().into_iter().map(|s| s.id).collect();
+        let found = loader.find_stored_card("events/0002").unwrap();
+
+        assert_eq!(ids, vec!["events/0001", "events/0002"]);
+        assert_eq!(loader.get_card_files().len(), 3);
+        assert!(loader.get_card_files()[2].card.is_err());
+        assert_eq!(found.card.title().value(), "Audit");
+        assert!(found.path.ends_with("events/0002-audit.json"));
+        assert!(loader.find_stored_card("events/0003").is_none());
+    }
+
+    #[test]
+    fn target_taxonomy_is_read_once() {
+        let base = repository_with_files(&[("targets.json", r#"{ "data": ["backup"] }"#)]);
+        let loader = DeckLoader::create(base.path().to_str().unwrap());
+
+        let first = loader.get_target_taxonomy();
+        fs::write(base.path().join(TargetTaxonomy::FILE_NAME), r#"{ "network": [] }"#).unwrap();
+        let second = loader.get_target_taxonomy();
+
+        assert_eq!(first, second);
+        assert!(second.contains(&Target::new("backup")));
+    }
+
+    #[test]
+    fn broken_rule_sets_are_returned_as_errors() {
+        let base = repository_with_files(&[
+            ("rules/strict.json", r#"{ "name": "Strict", "attackGrace": true, "consumeOnNextFix": true, "durationTick": 1 }"#),
+            ("rules/broken.json", r#"{ "name": 3 }"#),
+        ]);
+        let loader = DeckLoader::create(base.path().to_str().unwrap());
+
+        let (rule_sets, errors) = loader.load_rule_sets();
+
+        assert_eq!(rule_sets.len(), 1);
+        assert_eq!(errors.len(), 1);
+        assert_eq!(errors[0].kind(), &ErrorKind::Parse);
+        assert!(errors[0].path().unwrap().ends_with("broken.json"));
+    }
+
+    #[test]
+    fn broken_scenarios_are_returned_as_errors() {
+        let base = repository_with_files(&[
+            ("scenarios/audit.json", r#"{ "title": "Audit year", "description": "", "preset": { "resources": 10, "reputation": 50, "resourceGain": 5, "multiplier": 1 }, "goal": { "minimumResources": 0, "minimumReputation": 20 } }"#),
+            ("scenarios/broken.json", r#"{ "title": 3 }"#),
+        ]);
+        let loader = DeckLoader::create(base.path().to_str().unwrap());
+
+        let (scenarios, errors) = loader.load_scenarios();
+
+        assert_eq!(scenarios.len(), 1);
+        assert_eq!(errors.len(), 1);
+        assert_eq!(errors[0].kind(), &ErrorKind::Parse);
+        assert!(errors[0].path().unwrap().ends_with("broken.json"));
+        assert_eq!(loader.get_scenarios().len(), 1);
     }
 }
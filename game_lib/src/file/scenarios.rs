@@ -0,0 +1,61 @@
+use std::fs;
+use std::io;
+use std::io::Write;
+use std::path::{Path, PathBuf};
+
+use crate::cards::game_variants::scenario::Scenario;
+use crate::errors::{ErrorKind, GameLibError, GameLibResult};
+use crate::file::cards::sanitize_filename;
+use crate::file::format::{read_versioned, to_versioned_json, FileKind};
+use crate::file::general::{ensure_directory_exists, get_files_in_directory_with_filter};
+
+pub const SCENARIO_DIRECTORY: &str = "scenarios";
+
+/// All scenario files of the card repository sorted by name. A repository without scenario directory
+/// has no scenarios.
+pub fn get_scenario_files(base_path: &str) -> io::Result<Vec<PathBuf>> {
+    let path = PathBuf::from(base_path).join(SCENARIO_DIRECTORY);
+    if !path.exists() {
+        return Ok(vec![]);
+    }
+    let mut files: Vec<PathBuf> =
+        get_files_in_directory_with_filter(path.to_str().unwrap(), ".json")?
+            .into_iter()
+            .map(PathBuf::from)
+            .collect();
+    files.sort();
+    Ok(files)
+}
+
+/// Reads a single scenario file, older versions of the format are upgraded. The error contains line
+/// and column if the file is no valid scenario.
+pub fn read_scenario_file(path: &Path) -> GameLibResult<Scenario> {
+    let content = fs::read_to_string(path).map_err(|e| {
+        let msg = format!("Could not read {}", path.display());
+        GameLibError::create_with_original(ErrorKind::IO, msg.as_str(), e.to_string())
+    })?;
+    read_versioned(FileKind::Scenario, SCENARIO_DIRECTORY, content.as_str())
+        .map_err(|e| GameLibError::parse(path, e))
+}
+
+/// Writes the scenario into the scenario directory, named after its title. Existing scenarios are not
+/// overwritten.
+pub fn write_scenario_to_file(scenario: &Scenario, base_path: &str) -> io::Result<PathBuf> {
+    let directory = PathBuf::from(base_path).join(SCENARIO_DIRECTORY);
+    ensure_directory_exists(directory.to_str().unwrap())?;
+
+    let file_name = format!("{}.json", sanitize_filename(scenario.title.value()));
+    let path = directory.join(file_name);
+    if path.exists() {
+        return Err(io::Error::new(
+            io::ErrorKind::AlreadyExists,
+            format!("Scenario file {} already exists", path.display()),
+        ));
+    }
+
+    let serialized = to_versioned_json(FileKind::Scenario, scenario)
+        .map_err(|e| io::Error::other(e.to_string()))?;
+    let mut file = fs::File::create(&path)?;
+    file.write_all(serialized.as_bytes())?;
+    Ok(path)
+}
@@ -0,0 +1,117 @@
+use schemars::generate::SchemaSettings;
+use schemars::JsonSchema;
+use serde_json::{json, Value};
+
+use crate::cards::game_variants::scenario::Scenario;
+use crate::cards::properties::target_taxonomy::TargetTaxonomy;
+use crate::cards::types::card_model::Card;
+use crate::file::format::{FileKind, VERSION_FIELD};
+
+/*
+JSON Schemas of the files of a card repository, generated from the types the files are read into, so
+they can not drift from the format. Editors use them to check a file while it is written. Draft 7 is
+used because it is the draft most editors support.
+
+Targets are limited to the targets of the taxonomy of the repository, like `cards validate` does.
+Evaluation cards are created with the deck and are left out, `cards validate` rejects them in files. Rules
+which need more than one field, e.g. a fix cost whose min is not above its max, are only checked by
+`cards validate`.
+ */
+
+/// Schema of a card file of any type.
+pub fn card_schema(taxonomy: &TargetTaxonomy) -> Value {
+    let mut schema = schema_for::<Card>(FileKind::Card, taxonomy);
+    remove_evaluation_card(&mut schema, "");
+    schema
+}
+
+/// Schema of a scenario file.
+pub fn scenario_schema(taxonomy: &TargetTaxonomy) -> Value {
+    let mut schema = schema_for::<Scenario>(FileKind::Scenario, taxonomy);
+    remove_evaluation_card(&mut schema, "/definitions/Card");
+    schema
+}
+
+fn schema_for<T: JsonSchema>(kind: FileKind, taxonomy: &TargetTaxonomy) -> Value {
+    let mut schema = SchemaSettings::draft07()
+        .into_generator()
+        .into_root_schema_for::<T>()
+        .to_value();
+    if let Some(target) = schema.pointer_mut("/definitions/Target") {
+        *target = json!({ "type": "string", "enum": taxonomy.all_targets() });
+    }
+    // the version is written next to the content of the file, see `to_versioned_json`
+    if let Some(root) = schema.as_object_mut() {
+        let properties = root.entry("properties").or_insert_with(|| json!({}));
+        properties[VERSION_FIELD] = json!({
+            "description": "Version of the file format",
+            "type": "integer",
+            "minimum": 0,
+            "maximum": kind.current_version()
+        });
+    }
+    schema
+}
+
+/// Removes the evaluation variant from the card types at the pointer and its definition.
+fn remove_evaluation_card(schema: &mut Value, card_pointer: &str) {
+    if let Some(Value::Array(variants)) = schema.pointer_mut(&format!("{}/oneOf", card_pointer)) {
+        variants.retain(|variant| variant["properties"]["type"]["const"] != "evaluation");
+    }
+    if let Some(Value::Object(definitions)) = schema.pointer_mut("/definitions") {
+        definitions.remove("EvaluationCard");
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::BTreeMap;
+
+    use crate::cards::properties::target::Target;
+
+    use super::*;
+
+    #[test]
+    fn card_schema_has_a_variant_per_card_type() {
+        let schema = card_schema(&TargetTaxonomy::default());
+
+        let types: Vec<&str> = schema["oneOf"]
+            .as_array()
+            .unwrap()
+            .iter()
+            .map(|variant| variant["properties"]["type"]["const"].as_str().unwrap())
+            .collect();
+
+        assert_eq!(
+            types,
+            vec!["event", "attack", "oopsie", "lucky", "control"]
+        );
+        assert!(schema["definitions"]["EvaluationCard"].is_null());
+        assert_eq!(schema["properties"]["version"]["maximum"], 4);
+    }
+
+    #[test]
+    fn targets_are_limited_to_the_taxonomy() {
+        let taxonomy = TargetTaxonomy::new(BTreeMap::from([(
+            Target::new("data"),
+            vec![Target::new("database")],
+        )]));
+
+        let schema = card_schema(&taxonomy);
+
+        assert_eq!(
+            schema["definitions"]["Target"]["enum"],
+            json!(["data", "database"])
+        );
+    }
+
+    #[test]
+    fn scenario_schema_contains_cards_of_scripted_events() {
+        let schema = scenario_schema(&TargetTaxonomy::default());
+
+        assert_eq!(schema["title"], "Scenario");
+        assert!(schema["definitions"]["Card"].is_object());
+        assert_eq!(schema["definitions"]["Card"]["oneOf"].as_array().unwrap().len(), 5);
+        assert_eq!(schema["definitions"]["Title"]["minLength"], 1);
+    }
+}
@@ -6,13 +6,16 @@ use crate::world::resources::Resources;
 
 pub type ActionResult<T> = Result<T, ActionError>;
 
+/// Errors carry the board the game continues with. It is boxed to keep results of actions small.
 #[derive(Clone, Debug, PartialEq)]
 pub enum ActionError {
     NoCardsLeft,
-    WrongCardType(Board),
-    AttackForceClosed(Board),
-    InvalidState(Board),
-    NotEnoughResources(Board, Resources),
+    WrongCardType(Box<Board>),
+    AttackForceClosed(Box<Board>),
+    InvalidState(Box<Board>),
+    NotEnoughResources(Box<Board>, Resources),
+    NotEnoughPeopleHours(Box<Board>, Resources),
+    FixInProgress(Box<Board>),
 }
 
 impl Error for ActionError {}
@@ -27,6 +30,10 @@ impl Display for ActionError {
             ActionError::NotEnoughResources(_, costs) => {
                 format!("Not enough resources, fix would have cost {}", costs)
             }
+            ActionError::NotEnoughPeopleHours(_, hours) => {
+                format!("Not enough people-hours, fix needs {}", hours)
+            }
+            ActionError::FixInProgress(_) => "The fix is already in progress".to_string(),
         };
         write!(f, "GameError: {:?}: {}", self, message)
     }
@@ -8,6 +8,13 @@ pub fn add_resources(board: Board, resources: &Resources) -> Board {
     }
 }
 
+pub fn add_people_hours(board: Board, people_hours: &Resources) -> Board {
+    Board {
+        current_people_hours: &board.current_people_hours + people_hours,
+        ..board
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use crate::world::actions::add_resources::add_resources;
@@ -0,0 +1,144 @@
+/*
+Control cards are drawn like every other card but only protect their targets once they are bought. The
+price is multiplied by the resource fix multiplier, like fix costs. A bought control protects its
+targets until it is closed.
+ */
+use uuid::Uuid;
+
+use crate::cards::types::card_model::Card;
+use crate::cards::types::control::ControlCard;
+use crate::world::actions::action_error::ActionError::{InvalidState, NotEnoughResources, WrongCardType};
+use crate::world::actions::action_error::ActionResult;
+use crate::world::board::Board;
+use crate::world::resource_fix_multiplier::ResourceFixMultiplier;
+use crate::world::resources::Resources;
+
+pub fn buy_control_card(
+    board: Board,
+    card_id: &Uuid,
+    fix_multiplier: &ResourceFixMultiplier,
+) -> ActionResult<(Board, Resources)> {
+    if let Some(card) = board.open_cards.clone().get(card_id) {
+        match &**card {
+            Card::Control(cc) => buy_if_affordable(card_id, cc, board, fix_multiplier),
+            Card::Event(_) | Card::Attack(_) | Card::Oopsie(_) | Card::Lucky(_) | Card::Evaluation(_) => {
+                Err(WrongCardType(Box::new(board)))
+            }
+        }
+    } else {
+        Err(InvalidState(Box::new(board)))
+    }
+}
+
+fn buy_if_affordable(
+    card_id: &Uuid,
+    control: &ControlCard,
+    board: Board,
+    fix_multiplier: &ResourceFixMultiplier,
+) -> ActionResult<(Board, Resources)> {
+    if board.bought_controls.contains(card_id) {
+        return Err(InvalidState(Box::new(board)));
+    }
+
+    let price = control.cost * fix_multiplier;
+    if board.current_resources < price {
+        return Err(NotEnoughResources(Box::new(board), price));
+    }
+
+    let mut bought_controls = board.bought_controls.clone();
+    bought_controls.insert(*card_id);
+    Ok((
+        Board {
+            current_resources: board.current_resources - price,
+            bought_controls,
+            ..board
+        },
+        price,
+    ))
+}
+
+#[cfg(test)]
+mod tests {
+    use fake::Fake;
+    use rstest::rstest;
+
+    use crate::cards::types::attack::tests::FakeAttackCard;
+    use crate::cards::types::attack::AttackCard;
+    use crate::cards::types::control::tests::FakeControlCard;
+    use crate::cards::types::event::tests::FakeEventCard;
+    use crate::cards::types::event::EventCard;
+    use crate::cards::types::oopsie::tests::FakeOopsieCard;
+    use crate::cards::types::oopsie::OopsieCard;
+    use crate::world::actions::action_error::ActionError;
+    use crate::world::board::tests::generate_board_with_open_card;
+
+    use super::*;
+
+    fn generate_board_with_control(cost: usize, resources: usize) -> (Uuid, Board) {
+        let control = ControlCard {
+            cost: Resources::new(cost),
+            ..FakeControlCard.fake()
+        };
+        let (card_id, board, _) = generate_board_with_open_card(Card::from(control));
+        (
+            card_id,
+            Board {
+                current_resources: Resources::new(resources),
+                ..board
+            },
+        )
+    }
+
+    #[test]
+    fn buy_control_card_pays_multiplied_price_and_marks_control_as_bought() {
+        let (card_id, board) = generate_board_with_control(5, 20);
+
+        let (result, price) =
+            buy_control_card(board, &card_id, &ResourceFixMultiplier::new(2)).unwrap();
+
+        assert_eq!(price, Resources::new(10));
+        assert_eq!(result.current_resources, Resources::new(10));
+        assert!(result.bought_controls.contains(&card_id));
+    }
+
+    #[test]
+    fn buy_control_card_fails_without_enough_resources() {
+        let (card_id, board) = generate_board_with_control(5, 4);
+        let expected_board = board.clone();
+
+        let result =
+            buy_control_card(board, &card_id, &ResourceFixMultiplier::default()).unwrap_err();
+
+        assert_eq!(
+            result,
+            ActionError::NotEnoughResources(Box::new(expected_board), Resources::new(5))
+        );
+    }
+
+    #[test]
+    fn buy_control_card_twice_is_invalid() {
+        let (card_id, board) = generate_board_with_control(5, 20);
+        let (bought, _) =
+            buy_control_card(board, &card_id, &ResourceFixMultiplier::default()).unwrap();
+        let expected_board = bought.clone();
+
+        let result =
+            buy_control_card(bought, &card_id, &ResourceFixMultiplier::default()).unwrap_err();
+
+        assert_eq!(result, ActionError::InvalidState(Box::new(expected_board)));
+    }
+
+    #[rstest]
+    #[case::attack_card(Card::from(FakeAttackCard.fake::<AttackCard>()))]
+    #[case::event_card(Card::from(FakeEventCard.fake::<EventCard>()))]
+    #[case::oopsie_card(Card::from(FakeOopsieCard.fake::<OopsieCard>()))]
+    fn buy_control_card_returns_wrong_card_type_for_other_cards(#[case] card: Card) {
+        let (card_id, board, _) = generate_board_with_open_card(card);
+        let expected_board = board.clone();
+
+        let result =
+            buy_control_card(board, &card_id, &ResourceFixMultiplier::default()).unwrap_err();
+
+        assert_eq!(result, ActionError::WrongCardType(Box::new(expected_board)));
+    }
+}
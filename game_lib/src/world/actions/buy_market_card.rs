@@ -0,0 +1,108 @@
+use uuid::Uuid;
+
+use crate::cards::types::card_model::Card;
+use crate::world::actions::action_error::ActionError::NotEnoughResources;
+use crate::world::actions::action_error::ActionResult;
+use crate::world::board::Board;
+use crate::world::market::MarketOffer;
+use crate::world::resource_fix_multiplier::ResourceFixMultiplier;
+use crate::world::resources::Resources;
+
+/// Pays for a card of the market and places it on the board. The price is multiplied by the resource
+/// fix multiplier. A bought control protects its targets right away.
+pub fn buy_market_card(
+    board: Board,
+    offer: &MarketOffer,
+    fix_multiplier: &ResourceFixMultiplier,
+) -> ActionResult<(Board, Resources)> {
+    let price = offer.price * fix_multiplier;
+    if board.current_resources < price {
+        return Err(NotEnoughResources(Box::new(board), price));
+    }
+
+    let card_id = Uuid::new_v4();
+    let mut open_cards = board.open_cards.clone();
+    open_cards.insert(card_id, offer.card.clone());
+    let mut bought_controls = board.bought_controls.clone();
+    if let Card::Control(_) = &*offer.card {
+        bought_controls.insert(card_id);
+    }
+
+    Ok((
+        Board {
+            current_resources: board.current_resources - price,
+            open_cards,
+            bought_controls,
+            ..board
+        },
+        price,
+    ))
+}
+
+#[cfg(test)]
+mod tests {
+    use std::rc::Rc;
+
+    use fake::Fake;
+
+    use crate::cards::types::control::tests::FakeControlCard;
+    use crate::cards::types::control::ControlCard;
+    use crate::cards::types::lucky::tests::FakeLuckyCard;
+    use crate::cards::types::lucky::LuckyCard;
+    use crate::world::actions::action_error::ActionError;
+
+    use super::*;
+
+    fn board_with_resources(resources: usize) -> Board {
+        Board {
+            current_resources: Resources::new(resources),
+            ..Board::empty()
+        }
+    }
+
+    #[test]
+    fn buy_market_card_places_card_on_board() {
+        let offer = MarketOffer {
+            card: Rc::new(Card::from(FakeLuckyCard.fake::<LuckyCard>())),
+            price: Resources::new(3),
+        };
+
+        let (board, price) =
+            buy_market_card(board_with_resources(10), &offer, &ResourceFixMultiplier::new(2))
+                .unwrap();
+
+        assert_eq!(price, Resources::new(6));
+        assert_eq!(board.current_resources, Resources::new(4));
+        assert_eq!(board.open_cards.len(), 1);
+        assert!(board.bought_controls.is_empty());
+    }
+
+    #[test]
+    fn buy_market_card_marks_controls_as_bought() {
+        let offer = MarketOffer {
+            card: Rc::new(Card::from(FakeControlCard.fake::<ControlCard>())),
+            price: Resources::new(3),
+        };
+
+        let (board, _) =
+            buy_market_card(board_with_resources(10), &offer, &ResourceFixMultiplier::default())
+                .unwrap();
+
+        let control_id = board.open_cards.keys().next().unwrap();
+        assert!(board.bought_controls.contains(control_id));
+    }
+
+    #[test]
+    fn buy_market_card_fails_without_enough_resources() {
+        let offer = MarketOffer {
+            card: Rc::new(Card::from(FakeLuckyCard.fake::<LuckyCard>())),
+            price: Resources::new(11),
+        };
+        let board = board_with_resources(10);
+
+        let result =
+            buy_market_card(board.clone(), &offer, &ResourceFixMultiplier::default()).unwrap_err();
+
+        assert_eq!(result, ActionError::NotEnoughResources(Box::new(board), Resources::new(11)));
+    }
+}
@@ -11,25 +11,47 @@ use uuid::Uuid;
 
 use crate::cards::properties::cost_modifier::CostModifier;
 use crate::cards::properties::effect::Effect;
-use crate::cards::properties::target::Target;
-use crate::cards::types::card_model::Card;
-use crate::world::board::{Board, Incident};
+use crate::cards::properties::incident_impact::IncidentImpact;
+use crate::cards::properties::mitigation::Mitigation;
+use crate::cards::properties::target_taxonomy::TargetTaxonomy;
+use crate::cards::types::card_model::{Card, CardTrait};
+use crate::world::board::Board;
 use crate::world::deck::{CardRc, Deck};
+use crate::world::incident::{Incident, IncidentResolution};
+use crate::world::resource_fix_multiplier::ResourceFixMultiplier;
 use crate::world::resources::Resources;
 
-pub(crate) fn calculate_board(board: Board, deck: &Deck) -> Board {
+pub(crate) fn calculate_board(
+    board: Board,
+    deck: &Deck,
+    fix_multiplier: &ResourceFixMultiplier,
+    taxonomy: &TargetTaxonomy,
+) -> Board {
     let remaining_rounds = calculate_remaining_rounds(deck);
     let fix_modifier = calculate_cost_modifier(&board);
-    let active_incidents = determine_active_incidents(&board);
+    let board_in_round = Board {
+        current_round: deck.played_cards,
+        ..board
+    };
+    let active_incidents = determine_active_incidents(&board_in_round, fix_multiplier, taxonomy);
+    let resolved_incidents = resolve_vanished_incidents(&board_in_round, &active_incidents);
     Board {
         turns_remaining: remaining_rounds,
         cost_modifier: fix_modifier,
         active_incidents,
-        ..board
+        resolved_incidents,
+        ..board_in_round
     }
 }
 
-fn determine_active_incidents(board: &Board) -> Vec<Incident> {
+/// Matches attacks and oopsies with matching targets, see `TargetTaxonomy`. Incidents which are
+/// already active keep their state, new ones start in the current round. Bought controls protecting
+/// targets of the attack block new incidents or reduce their costs.
+fn determine_active_incidents(
+    board: &Board,
+    fix_multiplier: &ResourceFixMultiplier,
+    taxonomy: &TargetTaxonomy,
+) -> Vec<Incident> {
     let attacks = board
         .open_cards
         .iter()
@@ -54,13 +76,28 @@ fn determine_active_incidents(board: &Board) -> Vec<Incident> {
         })
         .collect::<Vec<_>>();
 
+    let controls = board
+        .open_cards
+        .iter()
+        .filter(|(id, _)| board.bought_controls.contains(id))
+        .filter_map(|(_, card)| match card.effect() {
+            Effect::Protection(_, targets, mitigation) => Some((targets, mitigation)),
+            _ => None,
+        })
+        .collect::<Vec<_>>();
+
     let mut incidents = Vec::new();
 
     for (attack_id, attack) in attacks.iter() {
-        let attack_targets = match &attack.effect {
-            Effect::Incident(_, targets, _) => targets,
+        let (attack_targets, impact) = match &attack.effect {
+            Effect::Incident(_, targets, impact) => (targets, impact),
             _ => continue,
         };
+        let mitigation = controls
+            .iter()
+            .filter(|(targets, _)| taxonomy.any_matches(targets, attack_targets))
+            .map(|(_, mitigation)| (*mitigation).clone())
+            .reduce(Mitigation::strongest);
 
         for (oopsie_id, oopsie) in oopsies.iter() {
             let oopsie_targets = match &oopsie.effect {
@@ -68,23 +105,65 @@ fn determine_active_incidents(board: &Board) -> Vec<Incident> {
                 _ => continue,
             };
 
-            let attack_set: HashSet<_> = HashSet::from_iter(attack_targets.iter());
-            let oopsie_set: HashSet<_> = HashSet::from_iter(oopsie_targets.iter());
-            let has_matching_target = attack_set.intersection(&oopsie_set).next().is_some();
-
-            if has_matching_target {
-                incidents.push(Incident {
-                    attack_card_id: **attack_id,
-                    attack_title: attack.title.value().to_string(),
-                    oopsie_card_id: **oopsie_id,
-                    oopsie_title: oopsie.title.value().to_string(),
-                });
+            if taxonomy.any_matches(attack_targets, oopsie_targets) {
+                let already_active = board
+                    .active_incidents
+                    .iter()
+                    .find(|incident| incident.is_caused_by(attack_id, oopsie_id));
+                let new_incident = || {
+                    Incident::start(
+                        **attack_id,
+                        attack.title.value(),
+                        **oopsie_id,
+                        oopsie.title.value(),
+                        board.current_round,
+                        initial_incident_cost(impact, fix_multiplier),
+                    )
+                };
+                match (already_active, &mitigation) {
+                    (Some(incident), _) => incidents.push(incident.clone()),
+                    (None, Some(Mitigation::Block)) => {}
+                    (None, Some(Mitigation::Reduce(reduction))) => {
+                        incidents.push(new_incident().with_mitigation(reduction.clone()))
+                    }
+                    (None, None) => incidents.push(new_incident()),
+                }
             }
         }
     }
     incidents
 }
 
+fn initial_incident_cost(
+    impact: &IncidentImpact,
+    fix_multiplier: &ResourceFixMultiplier,
+) -> Resources {
+    match impact {
+        IncidentImpact::Fixed(r) => r * fix_multiplier,
+        IncidentImpact::PartOfRevenue(_) => Resources::new(0),
+    }
+}
+
+/// Incidents are resolved by the actions removing their cards. If an incident vanishes from the board
+/// without being resolved by an action, it is considered force closed.
+fn resolve_vanished_incidents(board: &Board, active_incidents: &[Incident]) -> Vec<Incident> {
+    let mut resolved_incidents = board.resolved_incidents.clone();
+    resolved_incidents.extend(
+        board
+            .active_incidents
+            .iter()
+            .filter(|incident| {
+                !active_incidents.iter().any(|active| {
+                    active.is_caused_by(&incident.attack_card_id, &incident.oopsie_card_id)
+                })
+            })
+            .map(|incident| {
+                incident.resolve(board.current_round, IncidentResolution::ForceClosed)
+            }),
+    );
+    resolved_incidents
+}
+
 fn calculate_cost_modifier(board: &Board) -> Option<CostModifier> {
     let new_modifier = board
         .open_cards
@@ -110,6 +189,7 @@ fn get_modifier(
         Card::Oopsie(_) => None,
         Card::Lucky(l) => get_modifier_from_effect(&l.effect, cards_to_use.contains(card_id)),
         Card::Evaluation(_) => None,
+        Card::Control(_) => None,
     }
 }
 
@@ -118,6 +198,7 @@ fn get_modifier_from_effect(effect: &Effect, card_is_active: bool) -> Option<Cos
         Effect::Immediate(_) => None,
         Effect::AttackSurface(_, _) => None,
         Effect::Incident(_, _, _) => None,
+        Effect::Protection(_, _, _) => None,
         Effect::OnNextFix(_, m) => Some(m.clone()),
         Effect::OnUsingForFix(_, m) => {
             if card_is_active {
@@ -148,6 +229,9 @@ mod tests {
     use crate::cards::types::attack::tests::FakeAttackCard;
     use crate::cards::types::attack::AttackCard;
     use crate::cards::types::card_model::Card;
+    use crate::cards::types::control::tests::FakeControlCard;
+    use crate::cards::types::control::ControlCard;
+    use crate::world::part_of_hundred::PartOfHundred;
     use crate::cards::types::event::tests::FakeEventCard;
     use crate::cards::types::event::EventCard;
     use crate::cards::types::lucky::tests::FakeLuckyCard;
@@ -311,11 +395,17 @@ mod tests {
 
         let expected_board = Board {
             turns_remaining: 1,
+            current_round: 2,
             cost_modifier: Some(event_modifier),
             ..board.clone()
         };
 
-        let new_board = calculate_board(board, &deck);
+        let new_board = calculate_board(
+            board,
+            &deck,
+            &ResourceFixMultiplier::default(),
+            &TargetTaxonomy::default(),
+        );
 
         assert_eq!(new_board, expected_board)
     }
@@ -323,7 +413,11 @@ mod tests {
     #[test]
     fn determine_active_incidents_empty_board_no_incidents() {
         let empty_board = Board::empty();
-        let active_incidents = determine_active_incidents(&empty_board);
+        let active_incidents = determine_active_incidents(
+            &empty_board,
+            &ResourceFixMultiplier::default(),
+            &TargetTaxonomy::default(),
+        );
         assert_eq!(active_incidents, vec![])
     }
 
@@ -347,23 +441,17 @@ mod tests {
             ..Board::empty()
         };
 
-        let active_incidents = determine_active_incidents(&board);
+        let active_incidents = determine_active_incidents(
+            &board,
+            &ResourceFixMultiplier::default(),
+            &TargetTaxonomy::default(),
+        );
 
         assert_vec_eq_ignore_order(
             active_incidents,
             vec![
-                Incident {
-                    attack_card_id: uuid_attack_backend_1,
-                    attack_title: "a1".to_string(),
-                    oopsie_card_id: uuid_oopsie_backend,
-                    oopsie_title: "o1".to_string()
-                },
-                Incident {
-                    attack_card_id: uuid_attack_backend_2,
-                    attack_title: "a2".to_string(),
-                    oopsie_card_id: uuid_oopsie_backend,
-                    oopsie_title: "o1".to_string()
-                },
+                Incident::start(uuid_attack_backend_1, "a1", uuid_oopsie_backend, "o1", 0, Resources::new(10)),
+                Incident::start(uuid_attack_backend_2, "a2", uuid_oopsie_backend, "o1", 0, Resources::new(10)),
             ]
         );
     }
@@ -388,27 +476,135 @@ mod tests {
             ..Board::empty()
         };
 
-        let active_incidents = determine_active_incidents(&board);
+        let active_incidents = determine_active_incidents(
+            &board,
+            &ResourceFixMultiplier::default(),
+            &TargetTaxonomy::default(),
+        );
 
         assert_vec_eq_ignore_order(
             active_incidents,
             vec![
-                Incident {
-                    attack_card_id: uuid_attack,
-                    attack_title: "a".to_string(),
-                    oopsie_card_id: uuid_oopsie_backend_1,
-                    oopsie_title: "o1".to_string()
-                },
-                Incident {
-                    attack_card_id: uuid_attack,
-                    attack_title: "a".to_string(),
-                    oopsie_card_id: uuid_oopsie_backend_2,
-                    oopsie_title: "o2".to_string()
-                }
+                Incident::start(uuid_attack, "a", uuid_oopsie_backend_1, "o1", 0, Resources::new(10)),
+                Incident::start(uuid_attack, "a", uuid_oopsie_backend_2, "o2", 0, Resources::new(10))
             ]
         );
     }
 
+    #[test]
+    fn determine_active_incidents_matches_targets_of_taxonomy() {
+        let (uuid_oopsie, oopsie_card_rc) = generate_oopsie(Target::new("network"), "o");
+        let (uuid_attack, attack_card_rc) = generate_attack(Target::new("infrastructure"), "a");
+        let (uuid_other_oopsie, other_oopsie_card_rc) = generate_oopsie(Target::new("frontend"), "o2");
+        let cards = vec![
+            (uuid_oopsie, oopsie_card_rc),
+            (uuid_attack, attack_card_rc),
+            (uuid_other_oopsie, other_oopsie_card_rc),
+        ];
+        let board = Board {
+            open_cards: cards.into_iter().collect(),
+            ..Board::empty()
+        };
+        let taxonomy = TargetTaxonomy::new(
+            vec![(Target::new("infrastructure"), vec![Target::new("network")])]
+                .into_iter()
+                .collect(),
+        );
+
+        let active_incidents =
+            determine_active_incidents(&board, &ResourceFixMultiplier::default(), &taxonomy);
+
+        assert_eq!(
+            active_incidents,
+            vec![Incident::start(uuid_attack, "a", uuid_oopsie, "o", 0, Resources::new(10))]
+        );
+    }
+
+    #[rstest]
+    #[case::bought_control_blocks(Mitigation::Block, true, vec![])]
+    #[case::bought_control_reduces(
+        Mitigation::Reduce(PartOfHundred::new(40)),
+        true,
+        vec![Resources::new(6)]
+    )]
+    #[case::control_not_bought(Mitigation::Block, false, vec![Resources::new(10)])]
+    fn determine_active_incidents_applies_bought_controls(
+        #[case] mitigation: Mitigation,
+        #[case] is_bought: bool,
+        #[case] expected_costs: Vec<Resources>,
+    ) {
+        let (uuid_oopsie, oopsie_card_rc) = generate_oopsie(Target::new("backend"), "o");
+        let (uuid_attack, attack_card_rc) = generate_attack(Target::new("backend"), "a");
+        let uuid_control = Uuid::new_v4();
+        let control = ControlCard {
+            effect: Effect::Protection(
+                FakeEffectDescription.fake(),
+                vec![Target::new("backend")],
+                mitigation,
+            ),
+            ..FakeControlCard.fake::<ControlCard>()
+        };
+        let cards = vec![
+            (uuid_oopsie, oopsie_card_rc),
+            (uuid_attack, attack_card_rc),
+            (uuid_control, Rc::new(Card::from(control))),
+        ];
+        let board = Board {
+            open_cards: cards.into_iter().collect(),
+            bought_controls: if is_bought {
+                vec![uuid_control].into_iter().collect()
+            } else {
+                Default::default()
+            },
+            ..Board::empty()
+        };
+
+        let active_incidents = determine_active_incidents(
+            &board,
+            &ResourceFixMultiplier::default(),
+            &TargetTaxonomy::default(),
+        );
+
+        let costs: Vec<Resources> = active_incidents.iter().map(|i| i.accrued_cost).collect();
+        assert_eq!(costs, expected_costs);
+    }
+
+    #[test]
+    fn determine_active_incidents_ignores_controls_for_other_targets() {
+        let (uuid_oopsie, oopsie_card_rc) = generate_oopsie(Target::new("backend"), "o");
+        let (uuid_attack, attack_card_rc) = generate_attack(Target::new("backend"), "a");
+        let uuid_control = Uuid::new_v4();
+        let control = ControlCard {
+            effect: Effect::Protection(
+                FakeEffectDescription.fake(),
+                vec![Target::new("frontend")],
+                Mitigation::Block,
+            ),
+            ..FakeControlCard.fake::<ControlCard>()
+        };
+        let cards = vec![
+            (uuid_oopsie, oopsie_card_rc),
+            (uuid_attack, attack_card_rc),
+            (uuid_control, Rc::new(Card::from(control))),
+        ];
+        let board = Board {
+            open_cards: cards.into_iter().collect(),
+            bought_controls: vec![uuid_control].into_iter().collect(),
+            ..Board::empty()
+        };
+
+        let active_incidents = determine_active_incidents(
+            &board,
+            &ResourceFixMultiplier::default(),
+            &TargetTaxonomy::default(),
+        );
+
+        assert_eq!(
+            active_incidents,
+            vec![Incident::start(uuid_attack, "a", uuid_oopsie, "o", 0, Resources::new(10))]
+        );
+    }
+
     fn generate_oopsie(target: Target, title: &str) -> (Uuid, Rc<Card>) {
         (
             Uuid::new_v4(),
@@ -430,7 +626,7 @@ mod tests {
                 effect: Effect::Incident(
                     FakeEffectDescription.fake(),
                     vec![target],
-                    FakeFixedIncidentImpact.fake(),
+                    IncidentImpact::Fixed(Resources::new(10)),
                 ),
                 title: Title::new(title),
                 ..FakeAttackCard.fake::<AttackCard>()
@@ -459,14 +655,13 @@ mod tests {
             ..Board::empty()
         };
 
-        let active_incidents = determine_active_incidents(&board);
+        let active_incidents = determine_active_incidents(
+            &board,
+            &ResourceFixMultiplier::default(),
+            &TargetTaxonomy::default(),
+        );
 
-        let expected_incident = Incident {
-            attack_card_id: uuid_attack,
-            attack_title: "a".to_string(),
-            oopsie_card_id: uuid_oopsie,
-            oopsie_title: "o".to_string()
-        };
+        let expected_incident = Incident::start(uuid_attack, "a", uuid_oopsie, "o", 0, Resources::new(10));
 
         assert_vec_eq_ignore_order(active_incidents, vec![expected_incident])
     }
@@ -491,10 +686,16 @@ mod tests {
 
         let expected_board = Board {
             turns_remaining: 1,
+            current_round: 2,
             ..board.clone()
         };
 
-        let new_board = calculate_board(board, &deck);
+        let new_board = calculate_board(
+            board,
+            &deck,
+            &ResourceFixMultiplier::default(),
+            &TargetTaxonomy::default(),
+        );
 
         assert_eq!(new_board, expected_board)
     }
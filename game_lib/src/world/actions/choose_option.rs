@@ -0,0 +1,168 @@
+/*
+Decision events present options to the players. Choosing an option applies its consequences and removes
+the event from the board. If the players can not pay for an option, nothing happens.
+ */
+use uuid::Uuid;
+
+use crate::cards::properties::decision::{Consequence, DecisionOption};
+use crate::cards::types::card_model::Card;
+use crate::world::actions::action_error::ActionError::{InvalidState, NotEnoughResources, WrongCardType};
+use crate::world::actions::action_error::ActionResult;
+use crate::world::board::Board;
+
+pub fn choose_event_option(
+    board: Board,
+    card_id: &Uuid,
+    option_index: usize,
+) -> ActionResult<(Board, DecisionOption)> {
+    let option = match board.open_cards.get(card_id).map(|card| &**card) {
+        Some(Card::Event(event)) => match event.options.get(option_index) {
+            Some(option) => option.clone(),
+            None => return Err(InvalidState(Box::new(board))),
+        },
+        Some(Card::Attack(_))
+        | Some(Card::Oopsie(_))
+        | Some(Card::Lucky(_))
+        | Some(Card::Evaluation(_))
+        | Some(Card::Control(_)) => return Err(WrongCardType(Box::new(board))),
+        None => return Err(InvalidState(Box::new(board))),
+    };
+
+    let costs = option.costs();
+    if board.current_resources < costs {
+        return Err(NotEnoughResources(Box::new(board), costs));
+    }
+
+    let mut open_cards = board.open_cards.clone();
+    open_cards.remove(card_id);
+    let board_with_consequences = option
+        .consequences
+        .iter()
+        .fold(board, apply_consequence);
+    Ok((
+        Board {
+            open_cards,
+            ..board_with_consequences
+        },
+        option,
+    ))
+}
+
+fn apply_consequence(board: Board, consequence: &Consequence) -> Board {
+    match consequence {
+        Consequence::PayResources(r) => Board {
+            current_resources: board.current_resources - *r,
+            ..board
+        },
+        Consequence::GainResources(r) => Board {
+            current_resources: board.current_resources + *r,
+            ..board
+        },
+        Consequence::LoseReputation(r) => Board {
+            current_reputation: &board.current_reputation - r,
+            ..board
+        },
+        Consequence::GainReputation(r) => Board {
+            current_reputation: &board.current_reputation + r,
+            ..board
+        },
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use fake::Fake;
+
+    use crate::cards::types::event::tests::FakeEventCard;
+    use crate::cards::types::event::EventCard;
+    use crate::cards::types::lucky::tests::FakeLuckyCard;
+    use crate::cards::types::lucky::LuckyCard;
+    use crate::world::actions::action_error::ActionError;
+    use crate::world::board::tests::generate_board_with_open_card;
+    use crate::world::reputation::Reputation;
+    use crate::world::resources::Resources;
+
+    use super::*;
+
+    fn generate_board_with_decision() -> (Uuid, Board) {
+        let event = EventCard {
+            options: vec![
+                DecisionOption::new(
+                    "Patch now",
+                    vec![Consequence::PayResources(Resources::new(10))],
+                ),
+                DecisionOption::new(
+                    "Accept the risk",
+                    vec![Consequence::LoseReputation(Reputation::new(5))],
+                ),
+            ],
+            ..FakeEventCard.fake()
+        };
+        let (card_id, board, _) = generate_board_with_open_card(Card::from(event));
+        (
+            card_id,
+            Board {
+                current_resources: Resources::new(15),
+                current_reputation: Reputation::new(50),
+                ..board
+            },
+        )
+    }
+
+    #[test]
+    fn choose_event_option_pays_and_removes_event() {
+        let (card_id, board) = generate_board_with_decision();
+
+        let (result, option) = choose_event_option(board, &card_id, 0).unwrap();
+
+        assert_eq!(option.label, "Patch now");
+        assert_eq!(result.current_resources, Resources::new(5));
+        assert_eq!(result.current_reputation, Reputation::new(50));
+        assert!(!result.open_cards.contains_key(&card_id));
+    }
+
+    #[test]
+    fn choose_event_option_applies_reputation_loss() {
+        let (card_id, board) = generate_board_with_decision();
+
+        let (result, _) = choose_event_option(board, &card_id, 1).unwrap();
+
+        assert_eq!(result.current_resources, Resources::new(15));
+        assert_eq!(result.current_reputation, Reputation::new(45));
+    }
+
+    #[test]
+    fn choose_event_option_fails_without_enough_resources() {
+        let (card_id, board) = generate_board_with_decision();
+        let poor_board = Board {
+            current_resources: Resources::new(9),
+            ..board
+        };
+
+        let result = choose_event_option(poor_board.clone(), &card_id, 0).unwrap_err();
+
+        assert_eq!(
+            result,
+            ActionError::NotEnoughResources(Box::new(poor_board), Resources::new(10))
+        );
+    }
+
+    #[test]
+    fn choose_unknown_option_is_invalid() {
+        let (card_id, board) = generate_board_with_decision();
+
+        let result = choose_event_option(board.clone(), &card_id, 2).unwrap_err();
+
+        assert_eq!(result, ActionError::InvalidState(Box::new(board)));
+    }
+
+    #[test]
+    fn choose_option_of_other_card_type_is_wrong_card_type() {
+        let (card_id, board, _) =
+            generate_board_with_open_card(Card::from(FakeLuckyCard.fake::<LuckyCard>()));
+
+        let result = choose_event_option(board.clone(), &card_id, 0).unwrap_err();
+
+        assert_eq!(result, ActionError::WrongCardType(Box::new(board)));
+    }
+}
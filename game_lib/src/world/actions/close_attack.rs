@@ -3,6 +3,7 @@ use std::rc::Rc;
 
 use uuid::Uuid;
 
+use crate::cards::game_variants::rule_set::RuleSet;
 use crate::cards::properties::duration::Duration;
 use crate::cards::types::attack::AttackCard;
 use crate::cards::types::card_model::Card;
@@ -10,18 +11,27 @@ use crate::world::actions::action_error::ActionError::WrongCardType;
 use crate::world::actions::action_error::{ActionError, ActionResult};
 use crate::world::board::Board;
 use crate::world::deck::CardRc;
+use crate::world::incident::IncidentResolution;
+use crate::world::actions::track_incidents::{escalate_incidents, resolve_incidents_of_card};
+use crate::world::resource_fix_multiplier::ResourceFixMultiplier;
 
 /*
 Decreased the duration of all AttackCards with a limited duration. Removes the cards from the board
-if they hit zero duration.
+if they hit zero duration and resolves their incidents as expired. Attacks which remain on the board
+escalate the impact of their unresolved incidents. The rules define by how much the duration decreases
+and whether freshly drawn attacks are spared.
 */
-pub fn update_attack_cards(board: Board) -> Board {
+pub fn update_attack_cards(
+    board: Board,
+    fix_multiplier: &ResourceFixMultiplier,
+    rules: &RuleSet,
+) -> Board {
     let mut open_cards = HashMap::new();
     let drawn_card_id = board.drawn_card.clone().map(|card| card.id);
     for (key, card) in board.open_cards.iter() {
         let card_to_insert = match &**card {
-            Card::Attack(ac) => handle_attack_card(drawn_card_id, key, card, ac),
-            Card::Event(_) | Card::Oopsie(_) | Card::Lucky(_) | Card::Evaluation(_) => {
+            Card::Attack(ac) => handle_attack_card(drawn_card_id, key, card, ac, rules),
+            Card::Event(_) | Card::Oopsie(_) | Card::Lucky(_) | Card::Evaluation(_) | Card::Control(_) => {
                 Some(card.clone())
             }
         };
@@ -34,25 +44,50 @@ pub fn update_attack_cards(board: Board) -> Board {
         }
     }
 
-    Board {
+    let expired_attacks: Vec<Uuid> = board
+        .open_cards
+        .keys()
+        .filter(|key| !open_cards.contains_key(key))
+        .cloned()
+        .collect();
+
+    let updated_board = Board {
         open_cards: open_cards.clone(),
         ..board
+    };
+
+    let board_without_expired = expired_attacks.iter().fold(updated_board, |b, id| {
+        resolve_incidents_of_card(b, id, IncidentResolution::AttackExpired)
+    });
+
+    match drawn_card_id {
+        None => board_without_expired, // if no card was drawn no round has passed
+        Some(_) => escalate_incidents(board_without_expired, fix_multiplier),
     }
 }
 
-fn handle_attack_card(drawn_card_id: Option<Uuid>, key: &Uuid, card: &CardRc, ac: &AttackCard) -> Option<Rc<Card>> {
+fn handle_attack_card(
+    drawn_card_id: Option<Uuid>,
+    key: &Uuid,
+    card: &CardRc,
+    ac: &AttackCard,
+    rules: &RuleSet,
+) -> Option<Rc<Card>> {
     match drawn_card_id {
         None => Some(card.clone()), // if no card was drawn nothing should change
-        Some(id) => if key == &id {
+        Some(id) => if key == &id && rules.attack_grace {
             Some(card.clone())
         } else {
-            decrease_duration(card, ac)
+            decrease_duration(card, ac, rules.duration_tick)
         }
     }
 }
 
-fn decrease_duration(card: &CardRc, ac: &AttackCard) -> Option<Rc<Card>> {
-    let new_duration = ac.duration.decrease();
+fn decrease_duration(card: &CardRc, ac: &AttackCard, tick: usize) -> Option<Rc<Card>> {
+    if tick == 0 {
+        return Some(card.clone());
+    }
+    let new_duration = (0..tick).fold(ac.duration.clone(), |duration, _| duration.decrease());
     if let Some(value) = new_duration.value() {
         if *value == 0 {
             return None;
@@ -66,6 +101,8 @@ fn decrease_duration(card: &CardRc, ac: &AttackCard) -> Option<Rc<Card>> {
                 description: ac.description.clone(),
                 effect: ac.effect.clone(),
                 duration: new_duration,
+                follow_ups: ac.follow_ups.clone(),
+                escalation: ac.escalation.clone(),
             };
             Some(Rc::new(Card::from(attack_card)))
         }
@@ -82,13 +119,13 @@ fn decrease_duration(card: &CardRc, ac: &AttackCard) -> Option<Rc<Card>> {
 pub fn manually_close_attack_card(board: Board, card_id: &Uuid) -> ActionResult<Board> {
     if let Some(card) = board.open_cards.get(card_id) {
         match &**card {
-            Card::Oopsie(_) | Card::Lucky(_) | Card::Event(_) | Card::Evaluation(_) => {
-                Err(WrongCardType(board.clone()))
+            Card::Oopsie(_) | Card::Lucky(_) | Card::Event(_) | Card::Evaluation(_) | Card::Control(_) => {
+                Err(WrongCardType(Box::new(board.clone())))
             }
             Card::Attack(ac) => close_attack_card(&ac.duration.clone(), board, card_id),
         }
     } else {
-        Err(ActionError::InvalidState(board.clone()))
+        Err(ActionError::InvalidState(Box::new(board.clone())))
     }
 }
 
@@ -104,13 +141,23 @@ fn close_attack_card(duration: &Duration, board: Board, id: &Uuid) -> ActionResu
     match duration {
         Duration::Rounds(r) => {
             if r == &0usize {
-                Ok(updated_board)
+                Ok(resolve_incidents_of_card(updated_board, id, IncidentResolution::AttackExpired))
             } else {
-                Err(ActionError::AttackForceClosed(updated_board))
+                Err(ActionError::AttackForceClosed(Box::new(resolve_incidents_of_card(
+                    updated_board,
+                    id,
+                    IncidentResolution::ForceClosed,
+                ))))
             }
         }
-        Duration::UntilClosed => Err(ActionError::AttackForceClosed(updated_board)),
-        Duration::None => Ok(updated_board),
+        Duration::UntilClosed => Err(ActionError::AttackForceClosed(Box::new(
+            resolve_incidents_of_card(updated_board, id, IncidentResolution::ForceClosed),
+        ))),
+        Duration::None => Ok(resolve_incidents_of_card(
+            updated_board,
+            id,
+            IncidentResolution::AttackExpired,
+        )),
     }
 }
 
@@ -123,7 +170,13 @@ mod tests {
     use rstest::rstest;
     use uuid::Uuid;
 
+    use crate::cards::game_variants::rule_set::RuleSet;
     use crate::cards::properties::duration::Duration;
+    use crate::cards::properties::effect::Effect;
+    use crate::cards::properties::effect_description::tests::FakeEffectDescription;
+    use crate::cards::properties::escalation::Escalation;
+    use crate::cards::properties::incident_impact::IncidentImpact;
+    use crate::cards::properties::target::Target;
     use crate::cards::types::attack::tests::FakeAttackCard;
     use crate::cards::types::attack::AttackCard;
     use crate::cards::types::card_model::Card;
@@ -139,6 +192,10 @@ mod tests {
     use crate::world::actions::close_attack::{manually_close_attack_card, update_attack_cards};
     use crate::world::board::tests::{generate_board_with_freshly_drawn_card, generate_board_with_open_card, remove_card_from_open_cards};
     use crate::world::board::Board;
+    use crate::world::deck::CardRc;
+    use crate::world::incident::Incident;
+    use crate::world::resource_fix_multiplier::ResourceFixMultiplier;
+    use crate::world::resources::Resources;
 
     #[test]
     fn update_attack_cards_reduces_attack_duration() {
@@ -153,7 +210,11 @@ mod tests {
 
         let (card_id, board, card_rc) = generate_board_with_open_card(Card::from(attack));
 
-        let board_after_update = update_attack_cards(board);
+        let board_after_update = update_attack_cards(
+            board,
+            &ResourceFixMultiplier::default(),
+            &RuleSet::default(),
+        );
 
         let updated_card = board_after_update.open_cards.get(&card_id).unwrap();
 
@@ -174,7 +235,11 @@ mod tests {
 
         let (card_id, board, card_rc) = generate_board_with_freshly_drawn_card(Card::from(attack));
 
-        let board_after_update = update_attack_cards(board);
+        let board_after_update = update_attack_cards(
+            board,
+            &ResourceFixMultiplier::default(),
+            &RuleSet::default(),
+        );
 
         let updated_card = board_after_update.open_cards.get(&card_id).unwrap();
 
@@ -182,6 +247,60 @@ mod tests {
         assert!(Rc::ptr_eq(&card_rc, updated_card)); // card reference is not changed
     }
 
+    fn duration_of(card: &CardRc) -> Duration {
+        match &**card {
+            Card::Attack(ac) => ac.duration.clone(),
+            _ => panic!("Must be an attack card"),
+        }
+    }
+
+    #[test]
+    fn update_attack_cards_reduces_freshly_drawn_attack_without_attack_grace() {
+        let attack = AttackCard {
+            duration: Duration::new(Some(5)),
+            ..FakeAttackCard.fake()
+        };
+        let (card_id, board, _) = generate_board_with_freshly_drawn_card(Card::from(attack));
+        let rules = RuleSet {
+            attack_grace: false,
+            ..RuleSet::default()
+        };
+
+        let board_after_update =
+            update_attack_cards(board, &ResourceFixMultiplier::default(), &rules);
+
+        let updated_card = board_after_update.open_cards.get(&card_id).unwrap();
+        assert_eq!(duration_of(updated_card), Duration::new(Some(4)));
+    }
+
+    #[rstest]
+    #[case::no_ticking(0, Some(Duration::new(Some(5))))]
+    #[case::two_per_round(2, Some(Duration::new(Some(3))))]
+    #[case::beyond_duration(6, None)]
+    fn update_attack_cards_reduces_duration_by_duration_tick(
+        #[case] duration_tick: usize,
+        #[case] expected: Option<Duration>,
+    ) {
+        let attack = AttackCard {
+            duration: Duration::new(Some(5)),
+            ..FakeAttackCard.fake()
+        };
+        let (card_id, board, _) = generate_board_with_open_card(Card::from(attack));
+        let rules = RuleSet {
+            duration_tick,
+            ..RuleSet::default()
+        };
+
+        let board_after_update =
+            update_attack_cards(board, &ResourceFixMultiplier::default(), &rules);
+
+        let duration = board_after_update
+            .open_cards
+            .get(&card_id)
+            .map(duration_of);
+        assert_eq!(duration, expected);
+    }
+
 
     #[test]
     fn update_attack_cards_removes_attack_when_duration_is_0() {
@@ -191,7 +310,11 @@ mod tests {
         };
         let (id, board, _) = generate_board_with_open_card(Card::from(attack));
 
-        let board_after_update = update_attack_cards(board);
+        let board_after_update = update_attack_cards(
+            board,
+            &ResourceFixMultiplier::default(),
+            &RuleSet::default(),
+        );
 
         assert!(!board_after_update.open_cards.contains_key(&id));
     }
@@ -205,7 +328,11 @@ mod tests {
 
         let (id, board, _card_rc) = generate_board_with_open_card(Card::from(attack));
 
-        let board_after_update = update_attack_cards(board);
+        let board_after_update = update_attack_cards(
+            board,
+            &ResourceFixMultiplier::default(),
+            &RuleSet::default(),
+        );
 
         assert!(!board_after_update.open_cards.contains_key(&id));
     }
@@ -219,13 +346,59 @@ mod tests {
 
         let (card_id, board, card_rc) = generate_board_with_open_card(Card::from(attack));
 
-        let board_after_update = update_attack_cards(board);
+        let board_after_update = update_attack_cards(
+            board,
+            &ResourceFixMultiplier::default(),
+            &RuleSet::default(),
+        );
 
         let card_after_update = board_after_update.open_cards.get(&card_id).unwrap();
 
         assert!(Rc::ptr_eq(&card_rc, card_after_update))
     }
 
+    #[test]
+    fn update_attack_cards_escalates_attacks_with_unresolved_incidents() {
+        let attack = AttackCard {
+            effect: Effect::Incident(
+                FakeEffectDescription.fake(),
+                vec![Target::new("network")],
+                IncidentImpact::Fixed(Resources::new(10)),
+            ),
+            duration: Duration::UntilClosed,
+            escalation: Escalation::Linear(5),
+            ..FakeAttackCard.fake()
+        };
+        let (card_id, board, _) = generate_board_with_open_card(Card::from(attack));
+        let board_with_incident = Board {
+            active_incidents: vec![Incident::start(
+                card_id,
+                "a",
+                Uuid::new_v4(),
+                "o",
+                1,
+                Resources::new(10),
+            )],
+            current_round: 2,
+            ..board
+        };
+
+        let result = update_attack_cards(
+            board_with_incident,
+            &ResourceFixMultiplier::default(),
+            &RuleSet::default(),
+        );
+
+        match &**result.open_cards.get(&card_id).unwrap() {
+            Card::Attack(AttackCard {
+                effect: Effect::Incident(_, _, impact),
+                ..
+            }) => assert_eq!(impact, &IncidentImpact::Fixed(Resources::new(15))),
+            _ => panic!("Should be an attack card with an incident"),
+        }
+        assert_eq!(result.active_incidents[0].accrued_cost, Resources::new(15));
+    }
+
     #[rstest]
     #[case::LuckyCard(Card::from(FakeLuckyCard.fake::<LuckyCard>()))]
     #[case::EventCard(Card::from(FakeEventCard.fake::<EventCard>()))]
@@ -236,7 +409,11 @@ mod tests {
 
         let expected_board = Board { ..board.clone() };
 
-        let result = update_attack_cards(board);
+        let result = update_attack_cards(
+            board,
+            &ResourceFixMultiplier::default(),
+            &RuleSet::default(),
+        );
 
         assert_eq!(result, expected_board);
     }
@@ -254,7 +431,7 @@ mod tests {
 
         let err_result = manually_close_attack_card(board, &card_id).unwrap_err();
 
-        assert_eq!(err_result, ActionError::AttackForceClosed(expected_board))
+        assert_eq!(err_result, ActionError::AttackForceClosed(Box::new(expected_board)))
     }
 
     #[test]
@@ -270,7 +447,7 @@ mod tests {
 
         let err_result = manually_close_attack_card(board, &card_id).unwrap_err();
 
-        assert_eq!(err_result, ActionError::AttackForceClosed(expected_board))
+        assert_eq!(err_result, ActionError::AttackForceClosed(Box::new(expected_board)))
     }
 
     #[test]
@@ -317,7 +494,7 @@ mod tests {
 
         let result = manually_close_attack_card(board, &card_id).unwrap_err();
 
-        assert_eq!(result, ActionError::WrongCardType(expected_board));
+        assert_eq!(result, ActionError::WrongCardType(Box::new(expected_board)));
     }
 
     #[test]
@@ -332,7 +509,7 @@ mod tests {
 
         let result = manually_close_attack_card(board, &Uuid::new_v4()).unwrap_err();
 
-        assert_eq!(result, ActionError::InvalidState(expected_board));
+        assert_eq!(result, ActionError::InvalidState(Box::new(expected_board)));
     }
 
     fn create_board_with_card_removed(card_id: &Uuid, board: &Board) -> Board {
@@ -0,0 +1,71 @@
+use uuid::Uuid;
+
+use crate::cards::types::card_model::Card;
+use crate::world::actions::action_error::ActionError::{InvalidState, WrongCardType};
+use crate::world::actions::action_error::ActionResult;
+use crate::world::board::Board;
+
+/// Removes a control card from the board. A bought control stops protecting its targets.
+pub fn close_control_card(board: Board, card_id: &Uuid) -> ActionResult<Board> {
+    if let Some(card) = board.open_cards.clone().get(card_id) {
+        match &**card {
+            Card::Control(_) => {
+                let mut open_cards = board.open_cards.clone();
+                open_cards.remove(card_id);
+                let mut bought_controls = board.bought_controls.clone();
+                bought_controls.remove(card_id);
+                Ok(Board {
+                    open_cards,
+                    bought_controls,
+                    ..board
+                })
+            }
+            Card::Event(_) | Card::Attack(_) | Card::Oopsie(_) | Card::Lucky(_) | Card::Evaluation(_) => {
+                Err(WrongCardType(Box::new(board)))
+            }
+        }
+    } else {
+        Err(InvalidState(Box::new(board)))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use fake::Fake;
+
+    use crate::cards::types::control::tests::FakeControlCard;
+    use crate::cards::types::control::ControlCard;
+    use crate::cards::types::event::tests::FakeEventCard;
+    use crate::cards::types::event::EventCard;
+    use crate::world::actions::action_error::ActionError;
+    use crate::world::board::tests::{generate_board_with_open_card, remove_card_from_open_cards};
+
+    use super::*;
+
+    #[test]
+    fn close_control_card_removes_card_and_protection() {
+        let (card_id, board, _) =
+            generate_board_with_open_card(Card::from(FakeControlCard.fake::<ControlCard>()));
+        let bought_board = Board {
+            bought_controls: vec![card_id].into_iter().collect(),
+            ..board
+        };
+        let expected_open_cards = remove_card_from_open_cards(&bought_board, &card_id);
+
+        let result = close_control_card(bought_board, &card_id).unwrap();
+
+        assert_eq!(result.open_cards, expected_open_cards);
+        assert!(result.bought_controls.is_empty());
+    }
+
+    #[test]
+    fn close_control_card_returns_wrong_card_type_for_other_cards() {
+        let (card_id, board, _) =
+            generate_board_with_open_card(Card::from(FakeEventCard.fake::<EventCard>()));
+        let expected_board = board.clone();
+
+        let result = close_control_card(board, &card_id).unwrap_err();
+
+        assert_eq!(result, ActionError::WrongCardType(Box::new(expected_board)));
+    }
+}
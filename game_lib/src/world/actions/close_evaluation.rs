@@ -12,10 +12,10 @@ pub fn close_evaluation_card(board: Board, card_id: &Uuid) -> ActionResult<Board
     if let Some(card) = board.open_cards.clone().get(card_id) {
         match &**card {
             Card::Evaluation(ec) => close_if_allowed(card_id, ec, board),
-            Card::Attack(_) | Card::Oopsie(_) | Card::Lucky(_) | Card::Event(_) => Err(WrongCardType(board)),
+            Card::Attack(_) | Card::Oopsie(_) | Card::Lucky(_) | Card::Event(_) | Card::Control(_) => Err(WrongCardType(Box::new(board))),
         }
     } else {
-        Err(InvalidState(board))
+        Err(InvalidState(Box::new(board)))
     }
 }
 
@@ -28,7 +28,7 @@ fn close_if_allowed(card_id: &Uuid, ec: &EvaluationCard, board: Board) -> Action
             ..board
         })
     } else {
-        Err(InvalidState(board))
+        Err(InvalidState(Box::new(board)))
     }
 }
 
@@ -65,7 +65,7 @@ mod tests {
 
         let result = close_evaluation_card(board, &Uuid::new_v4()).unwrap_err();
 
-        assert_eq!(result, ActionError::InvalidState(expected_board))
+        assert_eq!(result, ActionError::InvalidState(Box::new(expected_board)))
     }
 
     #[rstest]
@@ -80,7 +80,7 @@ mod tests {
 
         let result = close_evaluation_card(board, &card_id).unwrap_err();
 
-        assert_eq!(result, ActionError::WrongCardType(expected_board));
+        assert_eq!(result, ActionError::WrongCardType(Box::new(expected_board)));
     }
 
 
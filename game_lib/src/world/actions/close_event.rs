@@ -12,10 +12,10 @@ pub fn close_event_card(board: Board, card_id: &Uuid) -> ActionResult<Board> {
     if let Some(card) = board.open_cards.clone().get(card_id) {
         match &**card {
             Card::Event(ec) => close_if_allowed(card_id, ec, board),
-            Card::Attack(_) | Card::Oopsie(_) | Card::Lucky(_) | Card::Evaluation(_) => Err(WrongCardType(board)),
+            Card::Attack(_) | Card::Oopsie(_) | Card::Lucky(_) | Card::Evaluation(_) | Card::Control(_) => Err(WrongCardType(Box::new(board))),
         }
     } else {
-        Err(InvalidState(board))
+        Err(InvalidState(Box::new(board)))
     }
 }
 
@@ -28,7 +28,7 @@ fn close_if_allowed(card_id: &Uuid, ec: &EventCard, board: Board) -> ActionResul
             ..board
         })
     } else {
-        Err(InvalidState(board))
+        Err(InvalidState(Box::new(board)))
     }
 }
 
@@ -67,7 +67,7 @@ mod tests {
 
         let result = close_event_card(board, &Uuid::new_v4()).unwrap_err();
 
-        assert_eq!(result, ActionError::InvalidState(expected_board))
+        assert_eq!(result, ActionError::InvalidState(Box::new(expected_board)))
     }
 
     #[rstest]
@@ -82,7 +82,7 @@ mod tests {
 
         let result = close_event_card(board, &card_id).unwrap_err();
 
-        assert_eq!(result, ActionError::WrongCardType(expected_board));
+        assert_eq!(result, ActionError::WrongCardType(Box::new(expected_board)));
     }
 
     #[test]
@@ -100,7 +100,7 @@ mod tests {
 
         let result = close_event_card(board, &card_id).unwrap_err();
 
-        assert_eq!(result, ActionError::InvalidState(expected_board));
+        assert_eq!(result, ActionError::InvalidState(Box::new(expected_board)));
     }
 
     #[test]
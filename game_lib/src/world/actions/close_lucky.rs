@@ -10,10 +10,10 @@ pub fn close_lucky_card(board: Board, card_id: &Uuid) -> ActionResult<Board> {
     if let Some(card) = board.open_cards.clone().get(card_id) {
         match &**card {
             Card::Lucky(lc) => close_if_allowed(card_id, lc, board),
-            Card::Attack(_) | Card::Oopsie(_) | Card::Event(_) | Card::Evaluation(_) => Err(WrongCardType(board)),
+            Card::Attack(_) | Card::Oopsie(_) | Card::Event(_) | Card::Evaluation(_) | Card::Control(_) => Err(WrongCardType(Box::new(board))),
         }
     } else {
-        Err(InvalidState(board))
+        Err(InvalidState(Box::new(board)))
     }
 }
 
@@ -29,7 +29,7 @@ fn close_if_allowed(card_id: &Uuid, lc: &LuckyCard, board: Board) -> ActionResul
             ..board
         })
     } else {
-        Err(InvalidState(board))
+        Err(InvalidState(Box::new(board)))
     }
 }
 
@@ -69,7 +69,7 @@ mod tests {
 
         let result = close_lucky_card(board, &Uuid::new_v4()).unwrap_err();
 
-        assert_eq!(result, ActionError::InvalidState(expected_board))
+        assert_eq!(result, ActionError::InvalidState(Box::new(expected_board)))
     }
 
     #[rstest]
@@ -84,7 +84,7 @@ mod tests {
 
         let result = close_lucky_card(board, &card_id).unwrap_err();
 
-        assert_eq!(result, ActionError::WrongCardType(expected_board));
+        assert_eq!(result, ActionError::WrongCardType(Box::new(expected_board)));
     }
 
     #[test]
@@ -102,7 +102,7 @@ mod tests {
 
         let result = close_lucky_card(board, &card_id).unwrap_err();
 
-        assert_eq!(result, ActionError::InvalidState(expected_board));
+        assert_eq!(result, ActionError::InvalidState(Box::new(expected_board)));
     }
 
     #[test]
@@ -4,12 +4,19 @@ add the fix_modifier and then multiply it by reource_fix_modifier. Then return a
 decreased resources and all used cards and cards with Effect:OnNextFix closed.
 
  If resources are not enough, return ActionError::NotEnoughRersources(Board)-
+
+Oopsies with more than one fix round are not closed. Instead, the fix is started and the first round of it
+is paid. The remaining rounds are paid by progress_oopsie_fixes.
+
+People-hours of a fix are not rolled and are needed in full before any budget is spent. Multi round fixes
+pay all of them when the fix is started.
  */
-use std::collections::HashSet;
+use std::collections::{HashMap, HashSet};
 
 use rand::{Rng, thread_rng};
 use uuid::Uuid;
 
+use crate::cards::game_variants::rule_set::RuleSet;
 use crate::cards::properties::effect::Effect;
 use crate::cards::properties::fix_cost::FixCost;
 use crate::cards::properties::cost_modifier::CostModifier;
@@ -17,7 +24,11 @@ use crate::cards::types::card_model::{Card, CardTrait};
 use crate::cards::types::oopsie::OopsieCard;
 use crate::world::actions::action_error::{ActionError, ActionResult};
 use crate::world::actions::action_error::ActionError::WrongCardType;
+use crate::world::actions::track_incidents::resolve_incidents_of_card;
 use crate::world::board::Board;
+use crate::world::deck::CardRc;
+use crate::world::fix_progress::FixProgress;
+use crate::world::incident::IncidentResolution;
 use crate::world::resource_fix_multiplier::ResourceFixMultiplier;
 use crate::world::resources::Resources;
 
@@ -25,14 +36,18 @@ pub fn try_and_pay_for_oopsie_fix(
     board: Board,
     card_id: &Uuid,
     resource_fix_multiplier: ResourceFixMultiplier,
+    rules: &RuleSet,
 ) -> ActionResult<(Board, Resources)> {
     if let Some(card) = board.open_cards.get(card_id) {
         match &**card {
-            Card::Attack(_) | Card::Lucky(_) | Card::Event(_) | Card::Evaluation(_) => Err(WrongCardType(board.clone())),
-            Card::Oopsie(oc) => try_and_close(&board, card_id, oc, resource_fix_multiplier),
+            Card::Attack(_) | Card::Lucky(_) | Card::Event(_) | Card::Evaluation(_) | Card::Control(_) => Err(WrongCardType(Box::new(board.clone()))),
+            Card::Oopsie(_) if board.fixes_in_progress.contains_key(card_id) => {
+                Err(ActionError::FixInProgress(Box::new(board.clone())))
+            }
+            Card::Oopsie(oc) => try_and_close(&board, card_id, oc, resource_fix_multiplier, rules),
         }
     } else {
-        Err(ActionError::InvalidState(board.clone()))
+        Err(ActionError::InvalidState(Box::new(board.clone())))
     }
 }
 
@@ -41,7 +56,13 @@ fn try_and_close(
     card_id: &Uuid,
     oopsie_card: &OopsieCard,
     resource_fix_multiplier: ResourceFixMultiplier,
+    rules: &RuleSet,
 ) -> ActionResult<(Board, Resources)> {
+    let people_hours = oopsie_card.fix_cost.people_hours_value() * resource_fix_multiplier;
+    if board.current_people_hours < people_hours {
+        return Err(ActionError::NotEnoughPeopleHours(Box::new(board.clone()), people_hours));
+    }
+
     let base_fix_cost = roll_dice(&oopsie_card.fix_cost);
     let modified_fix_cost = apply_fix_modifier(&board, &base_fix_cost);
     let real_fix_costs = modified_fix_cost * &resource_fix_multiplier;
@@ -49,31 +70,87 @@ fn try_and_close(
     let new_open_cards = &mut board.open_cards.clone();
     // remove used cards from board
     new_open_cards.retain(|id, card| {
-        !board.cards_to_use.contains(id) && !matches!(card.effect(), Effect::OnNextFix(_, _))
+        let consumed_by_fix = rules.consume_on_next_fix && matches!(card.effect(), Effect::OnNextFix(_, _));
+        !board.cards_to_use.contains(id) && !consumed_by_fix
     });
 
+    let fix_rounds = oopsie_card.fix_rounds.unwrap_or(1);
+    if fix_rounds > 1 {
+        return try_and_start_fix(
+            board,
+            card_id,
+            new_open_cards,
+            real_fix_costs,
+            people_hours,
+            fix_rounds,
+        );
+    }
+
     if board.current_resources >= real_fix_costs {
         new_open_cards.remove(card_id);
+        let fixed_board = Board {
+            current_resources: board.current_resources - real_fix_costs,
+            current_people_hours: board.current_people_hours - people_hours,
+            open_cards: new_open_cards.clone(),
+            cards_to_use: HashSet::new(),
+            cost_modifier: None,
+            fixed_oopsies: board.fixed_oopsies + 1,
+            ..board.clone()
+        };
+        Ok((
+            resolve_incidents_of_card(fixed_board, card_id, IncidentResolution::OopsieFixed),
+            real_fix_costs.clone(),
+        ))
+    } else {
+        Err(ActionError::NotEnoughResources(
+            Box::new(Board {
+                current_resources: Resources::new(0),
+                open_cards: new_open_cards.clone(),
+                cards_to_use: HashSet::new(),
+                cost_modifier: None,
+                ..board.clone()
+            }),
+            real_fix_costs,
+        ))
+    }
+}
+
+fn try_and_start_fix(
+    board: &Board,
+    card_id: &Uuid,
+    new_open_cards: &HashMap<Uuid, CardRc>,
+    real_fix_costs: Resources,
+    people_hours: Resources,
+    fix_rounds: usize,
+) -> ActionResult<(Board, Resources)> {
+    let progress = FixProgress::start(real_fix_costs, fix_rounds);
+    let first_installment = progress.next_installment();
+
+    if board.current_resources >= first_installment {
+        let mut fixes_in_progress = board.fixes_in_progress.clone();
+        fixes_in_progress.insert(*card_id, progress.advance());
         Ok((
             Board {
-                current_resources: &board.current_resources - &real_fix_costs,
+                current_resources: board.current_resources - first_installment,
+                current_people_hours: board.current_people_hours - people_hours,
                 open_cards: new_open_cards.clone(),
                 cards_to_use: HashSet::new(),
                 cost_modifier: None,
+                fixes_in_progress,
                 ..board.clone()
             },
-            real_fix_costs.clone(),
+            first_installment,
         ))
     } else {
         Err(ActionError::NotEnoughResources(
-            Board {
+            Box::new(Board {
                 current_resources: Resources::new(0),
                 open_cards: new_open_cards.clone(),
                 cards_to_use: HashSet::new(),
                 cost_modifier: None,
                 ..board.clone()
-            },
-            real_fix_costs,
+            }),
+            first_installment,
         ))
     }
 }
@@ -109,10 +186,12 @@ mod tests {
     use rstest::rstest;
     use uuid::Uuid;
 
+    use crate::cards::game_variants::rule_set::RuleSet;
     use crate::cards::properties::effect::Effect;
     use crate::cards::properties::effect_description::tests::FakeEffectDescription;
     use crate::cards::properties::fix_cost::FixCost;
     use crate::cards::properties::cost_modifier::CostModifier;
+    use crate::cards::properties::target_taxonomy::TargetTaxonomy;
     use crate::cards::types::attack::AttackCard;
     use crate::cards::types::attack::tests::FakeAttackCard;
     use crate::cards::types::card_model::Card;
@@ -130,6 +209,7 @@ mod tests {
     use crate::world::board::Board;
     use crate::world::board::tests::{generate_board_with_open_card, remove_card_from_open_cards};
     use crate::world::deck::Deck;
+    use crate::world::fix_progress::FixProgress;
     use crate::world::resource_fix_multiplier::ResourceFixMultiplier;
     use crate::world::resources::Resources;
 
@@ -139,11 +219,15 @@ mod tests {
         let (_, board, _) = generate_board_with_open_card(Card::from(oopsie_card));
         let expected_board = board.clone();
 
-        let result =
-            try_and_pay_for_oopsie_fix(board, &Uuid::new_v4(), ResourceFixMultiplier::default())
-                .unwrap_err();
+        let result = try_and_pay_for_oopsie_fix(
+            board,
+            &Uuid::new_v4(),
+            ResourceFixMultiplier::default(),
+            &RuleSet::default(),
+        )
+        .unwrap_err();
 
-        assert_eq!(result, ActionError::InvalidState(expected_board));
+        assert_eq!(result, ActionError::InvalidState(Box::new(expected_board)));
     }
 
     #[rstest]
@@ -155,10 +239,15 @@ mod tests {
         let (card_id, board, _) = generate_board_with_open_card(card);
         let expected_board = board.clone();
 
-        let result = try_and_pay_for_oopsie_fix(board, &card_id, ResourceFixMultiplier::default())
-            .unwrap_err();
+        let result = try_and_pay_for_oopsie_fix(
+            board,
+            &card_id,
+            ResourceFixMultiplier::default(),
+            &RuleSet::default(),
+        )
+        .unwrap_err();
 
-        assert_eq!(result, ActionError::WrongCardType(expected_board));
+        assert_eq!(result, ActionError::WrongCardType(Box::new(expected_board)));
     }
 
     #[test]
@@ -178,6 +267,7 @@ mod tests {
             current_resources: Resources::new(5),
             open_cards: remove_card_from_open_cards(&board_with_resourecs, &card_id),
             cost_modifier: None,
+            fixed_oopsies: 1,
             ..board_with_resourecs.clone()
         };
 
@@ -185,6 +275,7 @@ mod tests {
             board_with_resourecs,
             &card_id,
             ResourceFixMultiplier::default(),
+            &RuleSet::default(),
         )
         .unwrap();
 
@@ -192,6 +283,63 @@ mod tests {
         assert!(result.0.cost_modifier.is_none());
     }
 
+    #[test]
+    fn close_oopsie_card_with_fix_rounds_starts_fix_and_pays_first_round() {
+        let oopsie_card: OopsieCard = OopsieCard {
+            fix_cost: FixCost::new(9, 9).unwrap(),
+            fix_rounds: Some(3),
+            ..FakeOopsieCard.fake()
+        };
+
+        let (card_id, board, _) = generate_board_with_open_card(Card::from(oopsie_card));
+        let board_with_resourecs = Board {
+            current_resources: Resources::new(10),
+            ..board
+        };
+
+        let (result, paid) = try_and_pay_for_oopsie_fix(
+            board_with_resourecs,
+            &card_id,
+            ResourceFixMultiplier::default(),
+            &RuleSet::default(),
+        )
+        .unwrap();
+
+        assert_eq!(paid, Resources::new(3));
+        assert_eq!(result.current_resources, Resources::new(7));
+        assert!(result.open_cards.contains_key(&card_id));
+        assert_eq!(
+            result.fixes_in_progress.get(&card_id),
+            Some(&FixProgress::start(Resources::new(9), 3).advance())
+        );
+    }
+
+    #[test]
+    fn close_oopsie_card_returns_fix_in_progress_if_fix_was_started() {
+        let oopsie_card: OopsieCard = OopsieCard {
+            fix_rounds: Some(3),
+            ..FakeOopsieCard.fake()
+        };
+        let (card_id, board, _) = generate_board_with_open_card(Card::from(oopsie_card));
+        let board_with_fix = Board {
+            fixes_in_progress: vec![(card_id, FixProgress::start(Resources::new(9), 3).advance())]
+                .into_iter()
+                .collect(),
+            ..board
+        };
+        let expected_board = board_with_fix.clone();
+
+        let result = try_and_pay_for_oopsie_fix(
+            board_with_fix,
+            &card_id,
+            ResourceFixMultiplier::default(),
+            &RuleSet::default(),
+        )
+        .unwrap_err();
+
+        assert_eq!(result, ActionError::FixInProgress(Box::new(expected_board)));
+    }
+
     #[test]
     fn close_oopsie_card_with_resource_multiplier_returns_board_with_reduced_resources_and_closes_oopsie(
     ) {
@@ -210,6 +358,7 @@ mod tests {
             current_resources: Resources::new(0),
             open_cards: remove_card_from_open_cards(&board_with_resourecs, &card_id),
             cost_modifier: None,
+            fixed_oopsies: 1,
             ..board_with_resourecs.clone()
         };
 
@@ -217,6 +366,7 @@ mod tests {
             board_with_resourecs,
             &card_id,
             ResourceFixMultiplier::new(2),
+            &RuleSet::default(),
         )
         .unwrap();
 
@@ -242,6 +392,7 @@ mod tests {
             board_with_resourecs,
             &card_id,
             ResourceFixMultiplier::new(2),
+            &RuleSet::default(),
         )
         .unwrap();
 
@@ -297,6 +448,8 @@ mod tests {
                 played_cards: 10,
                 total: 10,
             },
+            &ResourceFixMultiplier::default(),
+            &TargetTaxonomy::default(),
         );
 
         let expected_board = Board {
@@ -304,12 +457,17 @@ mod tests {
             cards_to_use: HashSet::new(),
             cost_modifier: None,
             current_resources: Resources::new(0),
+            fixed_oopsies: 1,
             ..prepared_board.clone()
         };
 
-        let result =
-            try_and_pay_for_oopsie_fix(prepared_board, &oopsie_id, ResourceFixMultiplier::new(1))
-                .unwrap();
+        let result = try_and_pay_for_oopsie_fix(
+            prepared_board,
+            &oopsie_id,
+            ResourceFixMultiplier::new(1),
+            &RuleSet::default(),
+        )
+        .unwrap();
 
         assert_eq!(result.0, expected_board);
         assert_eq!(result.1, Resources::new(11));
@@ -362,6 +520,8 @@ mod tests {
                 played_cards: 10,
                 total: 10,
             },
+            &ResourceFixMultiplier::default(),
+            &TargetTaxonomy::default(),
         );
 
         dbg!("open cards: {}", prepared_board.clone().open_cards);
@@ -378,11 +538,15 @@ mod tests {
             ..prepared_board.clone()
         };
 
-        let result =
-            try_and_pay_for_oopsie_fix(prepared_board, &oopsie_id, ResourceFixMultiplier::new(1))
-                .unwrap_err();
+        let result = try_and_pay_for_oopsie_fix(
+            prepared_board,
+            &oopsie_id,
+            ResourceFixMultiplier::new(1),
+            &RuleSet::default(),
+        )
+        .unwrap_err();
 
-        assert_eq!(result,  ActionError::NotEnoughResources(expected_board, Resources::new(11)));
+        assert_eq!(result,  ActionError::NotEnoughResources(Box::new(expected_board), Resources::new(11)));
     }
 
 
@@ -413,12 +577,112 @@ mod tests {
             board_with_resourecs,
             &card_id,
             ResourceFixMultiplier::new(2),
+            &RuleSet::default(),
+        )
+        .unwrap_err();
+
+        assert_eq!(
+            result,
+            ActionError::NotEnoughResources(Box::new(expected_board), real_fix_costs)
+        )
+    }
+
+    #[test]
+    fn close_oopsie_card_pays_people_hours_of_the_fix() {
+        let oopsie_card: OopsieCard = OopsieCard {
+            fix_cost: FixCost::new(5, 5)
+                .unwrap()
+                .with_people_hours(Resources::new(2)),
+            ..FakeOopsieCard.fake()
+        };
+
+        let (card_id, board, _) = generate_board_with_open_card(Card::from(oopsie_card));
+        let board_with_resources = Board {
+            current_resources: Resources::new(10),
+            current_people_hours: Resources::new(5),
+            ..board
+        };
+
+        let (result, paid) = try_and_pay_for_oopsie_fix(
+            board_with_resources,
+            &card_id,
+            ResourceFixMultiplier::new(2),
+            &RuleSet::default(),
+        )
+        .unwrap();
+
+        assert_eq!(paid, Resources::new(10));
+        assert_eq!(result.current_resources, Resources::new(0));
+        assert_eq!(result.current_people_hours, Resources::new(1));
+        assert!(!result.open_cards.contains_key(&card_id));
+    }
+
+    #[test]
+    fn close_oopsie_card_returns_error_if_not_enough_people_hours_and_keeps_board_unchanged() {
+        let oopsie_card: OopsieCard = OopsieCard {
+            fix_cost: FixCost::new(5, 5)
+                .unwrap()
+                .with_people_hours(Resources::new(4)),
+            ..FakeOopsieCard.fake()
+        };
+
+        let (card_id, board, _) = generate_board_with_open_card(Card::from(oopsie_card));
+        let board_with_resources = Board {
+            current_resources: Resources::new(10),
+            current_people_hours: Resources::new(3),
+            ..board
+        };
+
+        let result = try_and_pay_for_oopsie_fix(
+            board_with_resources.clone(),
+            &card_id,
+            ResourceFixMultiplier::default(),
+            &RuleSet::default(),
         )
         .unwrap_err();
 
         assert_eq!(
             result,
-            ActionError::NotEnoughResources(expected_board, real_fix_costs)
+            ActionError::NotEnoughPeopleHours(Box::new(board_with_resources), Resources::new(4))
+        );
+    }
+
+    #[test]
+    fn close_oopsie_card_keeps_next_fix_cards_if_they_are_not_consumed() {
+        let oopsie_card: OopsieCard = OopsieCard {
+            fix_cost: FixCost::new(5, 5).unwrap(),
+            ..FakeOopsieCard.fake()
+        };
+        let (oopsie_id, board, _) = generate_board_with_open_card(Card::from(oopsie_card));
+        let event_id = Uuid::new_v4();
+        let event_card = Card::from(EventCard {
+            effect: Effect::OnNextFix(
+                FakeEffectDescription.fake(),
+                CostModifier::Increase(Resources::new(1)),
+            ),
+            ..FakeEventCard.fake()
+        });
+        let mut open_cards = board.open_cards.clone();
+        open_cards.insert(event_id, Rc::new(event_card));
+        let board_with_event = Board {
+            open_cards,
+            current_resources: Resources::new(10),
+            ..board
+        };
+        let rules = RuleSet {
+            consume_on_next_fix: false,
+            ..RuleSet::default()
+        };
+
+        let (result, _) = try_and_pay_for_oopsie_fix(
+            board_with_event,
+            &oopsie_id,
+            ResourceFixMultiplier::default(),
+            &rules,
         )
+        .unwrap();
+
+        assert!(result.open_cards.contains_key(&event_id));
+        assert!(!result.open_cards.contains_key(&oopsie_id));
     }
 }
@@ -19,7 +19,13 @@ pub(crate) fn draw_card_and_place_on_board(
         new_deck,
     } = draw_card(deck)?;
     let new_board = add_drawn_card_to_board(board, drawn_card)?;
-    Ok((new_deck, new_board))
+    Ok((
+        new_deck.clone(),
+        Board {
+            current_round: new_deck.played_cards,
+            ..new_board
+        },
+    ))
 }
 
 fn draw_card(deck: Deck) -> ActionResult<CardAndNewDeck> {
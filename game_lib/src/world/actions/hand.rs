@@ -0,0 +1,123 @@
+/*
+Lucky cards can be kept in a hand instead of being placed on the board right away. Cards in the hand are
+hidden from the shared board and have no effect until they are played. The hand has a size limit, lucky
+cards drawn while the hand is full are placed on the board as usual. A hand size of 0 disables the hand.
+ */
+use uuid::Uuid;
+
+use crate::cards::types::card_model::Card;
+use crate::world::actions::action_error::ActionError::InvalidState;
+use crate::world::actions::action_error::ActionResult;
+use crate::world::board::Board;
+
+/// Moves the freshly drawn card from the board into the hand if it is a lucky card and there is room
+/// left in the hand.
+pub(crate) fn take_drawn_card_into_hand(board: Board, hand_size: usize) -> Board {
+    let drawn_card = match &board.drawn_card {
+        Some(drawn) => drawn.clone(),
+        None => return board,
+    };
+    let is_lucky = matches!(&*drawn_card.card, Card::Lucky(_));
+    if !is_lucky
+        || board.hand.len() >= hand_size
+        || !board.open_cards.contains_key(&drawn_card.id)
+    {
+        return board;
+    }
+
+    let mut open_cards = board.open_cards.clone();
+    open_cards.remove(&drawn_card.id);
+    let mut hand = board.hand.clone();
+    hand.insert(drawn_card.id, drawn_card.card);
+    Board {
+        open_cards,
+        hand,
+        ..board
+    }
+}
+
+/// Plays a card of the hand. It is placed on the board with the same id and takes effect like any
+/// other lucky card on the board.
+pub(crate) fn play_card_from_hand(board: Board, card_id: &Uuid) -> ActionResult<Board> {
+    let card = match board.hand.get(card_id) {
+        Some(card) => card.clone(),
+        None => return Err(InvalidState(Box::new(board))),
+    };
+
+    let mut hand = board.hand.clone();
+    hand.remove(card_id);
+    let mut open_cards = board.open_cards.clone();
+    open_cards.insert(*card_id, card);
+    Ok(Board {
+        open_cards,
+        hand,
+        ..board
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use fake::Fake;
+
+    use crate::cards::types::event::tests::FakeEventCard;
+    use crate::cards::types::event::EventCard;
+    use crate::cards::types::lucky::tests::FakeLuckyCard;
+    use crate::cards::types::lucky::LuckyCard;
+    use crate::world::actions::action_error::ActionError;
+    use crate::world::board::tests::generate_board_with_freshly_drawn_card;
+
+    use super::*;
+
+    #[test]
+    fn drawn_lucky_card_is_taken_into_hand() {
+        let (card_id, board, card) =
+            generate_board_with_freshly_drawn_card(Card::from(FakeLuckyCard.fake::<LuckyCard>()));
+
+        let result = take_drawn_card_into_hand(board, 2);
+
+        assert!(!result.open_cards.contains_key(&card_id));
+        assert_eq!(result.hand.get(&card_id), Some(&card));
+    }
+
+    #[test]
+    fn drawn_lucky_card_stays_on_board_if_hand_is_full() {
+        let (card_id, board, _) =
+            generate_board_with_freshly_drawn_card(Card::from(FakeLuckyCard.fake::<LuckyCard>()));
+
+        let result = take_drawn_card_into_hand(board.clone(), 0);
+
+        assert_eq!(result, board);
+        assert!(result.open_cards.contains_key(&card_id));
+    }
+
+    #[test]
+    fn drawn_card_of_other_type_stays_on_board() {
+        let (_, board, _) =
+            generate_board_with_freshly_drawn_card(Card::from(FakeEventCard.fake::<EventCard>()));
+
+        let result = take_drawn_card_into_hand(board.clone(), 2);
+
+        assert_eq!(result, board);
+    }
+
+    #[test]
+    fn played_card_is_placed_on_board() {
+        let (card_id, board, card) =
+            generate_board_with_freshly_drawn_card(Card::from(FakeLuckyCard.fake::<LuckyCard>()));
+        let board_with_hand = take_drawn_card_into_hand(board, 1);
+
+        let result = play_card_from_hand(board_with_hand, &card_id).unwrap();
+
+        assert!(result.hand.is_empty());
+        assert_eq!(result.open_cards.get(&card_id), Some(&card));
+    }
+
+    #[test]
+    fn playing_unknown_card_is_invalid() {
+        let board = Board::empty();
+
+        let result = play_card_from_hand(board.clone(), &Uuid::new_v4()).unwrap_err();
+
+        assert_eq!(result, ActionError::InvalidState(Box::new(board)));
+    }
+}
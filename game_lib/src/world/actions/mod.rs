@@ -1,9 +1,16 @@
 pub(crate) mod action_error;
 pub(crate) mod add_resources;
+pub(crate) mod buy_control;
+pub(crate) mod buy_market_card;
 pub(crate) mod calculate_board;
+pub(crate) mod choose_option;
 pub(crate) mod close_attack;
+pub(crate) mod close_control;
 pub(crate) mod close_oopsie;
+pub(crate) mod progress_oopsie_fixes;
+pub(crate) mod scripted_events;
 pub(crate) mod draw_card;
+pub(crate) mod hand;
 pub(crate) mod subtract_resources;
 pub(crate) mod use_lucky_card;
 pub(crate) mod close_event;
@@ -12,3 +19,5 @@ pub(crate) mod close_evaluation;
 pub(crate) mod add_reputation;
 pub(crate) mod subtract_reputation;
 
+pub(crate) mod track_incidents;
+pub(crate) mod trigger_follow_ups;
@@ -0,0 +1,157 @@
+/*
+Oopsies which take several rounds to fix pay one installment of their fix costs every round. If there
+are not enough resources, the fix stalls for this round. Once the last round is paid the oopsie is
+closed and its incidents are resolved. Fixes of oopsies which left the board otherwise are dropped.
+ */
+use uuid::Uuid;
+
+use crate::world::actions::track_incidents::resolve_incidents_of_card;
+use crate::world::board::Board;
+use crate::world::incident::IncidentResolution;
+
+pub(crate) fn progress_oopsie_fixes(board: Board) -> Board {
+    let mut oopsie_ids: Vec<Uuid> = board.fixes_in_progress.keys().cloned().collect();
+    // sorted to pay the fixes in a stable order if resources are short
+    oopsie_ids.sort();
+
+    oopsie_ids.iter().fold(board, progress_fix)
+}
+
+fn progress_fix(board: Board, oopsie_id: &Uuid) -> Board {
+    let mut fixes_in_progress = board.fixes_in_progress.clone();
+    let progress = match fixes_in_progress.remove(oopsie_id) {
+        Some(progress) => progress,
+        None => return board,
+    };
+
+    if !board.open_cards.contains_key(oopsie_id) {
+        return Board {
+            fixes_in_progress,
+            ..board
+        };
+    }
+
+    let installment = progress.next_installment();
+    if board.current_resources < installment {
+        return board;
+    }
+
+    let advanced = progress.advance();
+    let paid_board = Board {
+        current_resources: board.current_resources - installment,
+        ..board
+    };
+
+    if advanced.is_complete() {
+        let mut open_cards = paid_board.open_cards.clone();
+        open_cards.remove(oopsie_id);
+        let fixed_board = Board {
+            open_cards,
+            fixes_in_progress,
+            fixed_oopsies: paid_board.fixed_oopsies + 1,
+            ..paid_board
+        };
+        resolve_incidents_of_card(fixed_board, oopsie_id, IncidentResolution::OopsieFixed)
+    } else {
+        fixes_in_progress.insert(*oopsie_id, advanced);
+        Board {
+            fixes_in_progress,
+            ..paid_board
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use fake::Fake;
+
+    use crate::cards::types::card_model::Card;
+    use crate::cards::types::oopsie::tests::FakeOopsieCard;
+    use crate::cards::types::oopsie::OopsieCard;
+    use crate::world::board::tests::generate_board_with_open_card;
+    use crate::world::fix_progress::FixProgress;
+    use crate::world::incident::Incident;
+    use crate::world::resources::Resources;
+
+    use super::*;
+
+    fn generate_board_with_fix(progress: FixProgress, resources: usize) -> (Uuid, Board) {
+        let oopsie: OopsieCard = FakeOopsieCard.fake();
+        let (oopsie_id, board, _) = generate_board_with_open_card(Card::from(oopsie));
+        let board_with_fix = Board {
+            current_resources: Resources::new(resources),
+            fixes_in_progress: vec![(oopsie_id, progress)].into_iter().collect(),
+            ..board
+        };
+        (oopsie_id, board_with_fix)
+    }
+
+    #[test]
+    fn progress_oopsie_fixes_pays_next_round() {
+        let progress = FixProgress::start(Resources::new(9), 3).advance();
+        let (oopsie_id, board) = generate_board_with_fix(progress.clone(), 10);
+
+        let result = progress_oopsie_fixes(board);
+
+        assert_eq!(result.current_resources, Resources::new(7));
+        assert!(result.open_cards.contains_key(&oopsie_id));
+        assert_eq!(
+            result.fixes_in_progress.get(&oopsie_id),
+            Some(&progress.advance())
+        );
+    }
+
+    #[test]
+    fn progress_oopsie_fixes_closes_oopsie_after_last_round() {
+        let progress = FixProgress::start(Resources::new(9), 2).advance();
+        let (oopsie_id, board) = generate_board_with_fix(progress, 10);
+        let board_with_incident = Board {
+            active_incidents: vec![Incident::start(
+                Uuid::new_v4(),
+                "attack",
+                oopsie_id,
+                "oopsie",
+                0,
+                Resources::new(0),
+            )],
+            ..board
+        };
+
+        let result = progress_oopsie_fixes(board_with_incident);
+
+        assert_eq!(result.current_resources, Resources::new(5));
+        assert!(!result.open_cards.contains_key(&oopsie_id));
+        assert!(result.fixes_in_progress.is_empty());
+        assert!(result.active_incidents.is_empty());
+        assert_eq!(result.fixed_oopsies, 1);
+        assert_eq!(
+            result.resolved_incidents[0].resolution,
+            Some(IncidentResolution::OopsieFixed)
+        );
+    }
+
+    #[test]
+    fn progress_oopsie_fixes_stalls_without_resources() {
+        let progress = FixProgress::start(Resources::new(9), 3).advance();
+        let (_, board) = generate_board_with_fix(progress, 2);
+        let expected_board = board.clone();
+
+        let result = progress_oopsie_fixes(board);
+
+        assert_eq!(result, expected_board);
+    }
+
+    #[test]
+    fn progress_oopsie_fixes_drops_fixes_of_closed_oopsies() {
+        let progress = FixProgress::start(Resources::new(9), 3).advance();
+        let (oopsie_id, board) = generate_board_with_fix(progress, 10);
+        let mut open_cards = board.open_cards.clone();
+        open_cards.remove(&oopsie_id);
+        let board_without_oopsie = Board { open_cards, ..board };
+
+        let result = progress_oopsie_fixes(board_without_oopsie);
+
+        assert!(result.fixes_in_progress.is_empty());
+        assert_eq!(result.current_resources, Resources::new(10));
+    }
+}
@@ -0,0 +1,48 @@
+use std::rc::Rc;
+
+use uuid::Uuid;
+
+use crate::cards::game_variants::schedule::ScriptedEvent;
+use crate::world::board::Board;
+
+/// Places the cards scripted for the given round on the board. The board has to be calculated afterwards,
+/// like after drawing a card, so scripted attacks and oopsies take effect in their round.
+pub(crate) fn place_scripted_events(board: Board, events: &[ScriptedEvent], round: usize) -> Board {
+    let mut open_cards = board.open_cards.clone();
+    for event in events.iter().filter(|event| event.round == round) {
+        open_cards.insert(Uuid::new_v4(), Rc::new(event.card.clone()));
+    }
+    Board {
+        open_cards,
+        ..board
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use fake::Fake;
+
+    use crate::cards::types::card_model::Card;
+    use crate::cards::types::event::tests::FakeEventCard;
+
+    use super::*;
+
+    #[test]
+    fn place_scripted_events_places_only_cards_of_the_round() {
+        let events = vec![
+            ScriptedEvent {
+                round: 2,
+                card: Card::Event(FakeEventCard.fake()),
+            },
+            ScriptedEvent {
+                round: 3,
+                card: Card::Event(FakeEventCard.fake()),
+            },
+        ];
+
+        let board = place_scripted_events(Board::empty(), &events, 2);
+
+        assert_eq!(board.open_cards.len(), 1);
+        assert_eq!(**board.open_cards.values().next().unwrap(), events[0].card);
+    }
+}
@@ -12,10 +12,10 @@ pub fn subtract_resources(board: Board, resources: &Resources) -> ActionResult<B
         })
     } else {
         Err(NotEnoughResources(
-            Board {
+            Box::new(Board {
                 current_resources: Resources::new(0),
                 ..board
-            },
+            }),
             resources.clone(),
         ))
     }
@@ -63,7 +63,7 @@ mod tests {
 
         assert_eq!(
             result,
-            ActionError::NotEnoughResources(expected_board, Resources::new(11))
+            ActionError::NotEnoughResources(Box::new(expected_board), Resources::new(11))
         );
     }
 }
@@ -0,0 +1,309 @@
+/*
+Incidents are detected in calculate_board, but their lifecycle is driven by the actions. Every round the
+active incidents accrue their costs and escalate, whenever an attack or oopsie leaves the board the
+incidents it was involved in are resolved and moved to the resolved incidents of the board.
+ */
+use std::collections::BTreeMap;
+use std::rc::Rc;
+
+use uuid::Uuid;
+
+use crate::cards::properties::effect::Effect;
+use crate::cards::properties::incident_impact::IncidentImpact;
+use crate::cards::types::attack::AttackCard;
+use crate::cards::types::card_model::Card;
+use crate::world::board::Board;
+use crate::world::incident::{Incident, IncidentResolution};
+use crate::world::resource_fix_multiplier::ResourceFixMultiplier;
+use crate::world::resources::Resources;
+
+/// Accrues the costs of all active incidents for a new round. Incidents with an impact relative to
+/// the revenue cost their part of the income of the round, i.e. the resource gain scaled by
+/// reputation, in every round they are active. Fixed impacts are accrued once when the incident
+/// starts. Mitigated incidents only accrue the part left by the control.
+pub(crate) fn accrue_incident_costs(board: Board, income: &Resources) -> Board {
+    let active_incidents = board
+        .active_incidents
+        .iter()
+        .map(|incident| match get_incident_impact(&board, incident) {
+            Some(IncidentImpact::PartOfRevenue(part)) => {
+                incident.accrue(&incident.mitigate(&part.of(income)))
+            }
+            Some(IncidentImpact::Fixed(_)) | None => incident.clone(),
+        })
+        .collect();
+
+    Board {
+        active_incidents,
+        ..board
+    }
+}
+
+/// Escalates the impact of all attacks with unresolved incidents according to their escalation
+/// schedule. The escalated impact is stored on the attack card, so relative impacts cost more from now
+/// on. The increase of a fixed impact is accrued by the active incidents of the attack right away, the
+/// same way the fixed impact was accrued when they started.
+pub(crate) fn escalate_incidents(board: Board, fix_multiplier: &ResourceFixMultiplier) -> Board {
+    // the oldest incident of an attack determines how long the attack is unresolved
+    let mut first_round_of_attack: BTreeMap<Uuid, usize> = BTreeMap::new();
+    for incident in board.active_incidents.iter() {
+        let round = first_round_of_attack
+            .entry(incident.attack_card_id)
+            .or_insert(incident.started_in_round);
+        *round = (*round).min(incident.started_in_round);
+    }
+
+    first_round_of_attack
+        .iter()
+        .fold(board, |b, (attack_id, started_in_round)| {
+            let rounds_unresolved = b.current_round.saturating_sub(*started_in_round);
+            escalate_attack(b, attack_id, rounds_unresolved, fix_multiplier)
+        })
+}
+
+/// Resolves all active incidents the card with the given id is involved in.
+pub(crate) fn resolve_incidents_of_card(
+    board: Board,
+    card_id: &Uuid,
+    resolution: IncidentResolution,
+) -> Board {
+    let (resolved, active): (Vec<Incident>, Vec<Incident>) = board
+        .active_incidents
+        .iter()
+        .cloned()
+        .partition(|incident| incident.involves(card_id));
+
+    let mut resolved_incidents = board.resolved_incidents.clone();
+    resolved_incidents.extend(
+        resolved
+            .iter()
+            .map(|incident| incident.resolve(board.current_round, resolution)),
+    );
+
+    Board {
+        active_incidents: active,
+        resolved_incidents,
+        ..board
+    }
+}
+
+fn escalate_attack(
+    board: Board,
+    attack_id: &Uuid,
+    rounds_unresolved: usize,
+    fix_multiplier: &ResourceFixMultiplier,
+) -> Board {
+    let attack = match board.open_cards.get(attack_id).map(|card| &**card) {
+        Some(Card::Attack(attack)) => attack.clone(),
+        _ => return board,
+    };
+    let increase = attack.escalation.increase_after(rounds_unresolved);
+    let (description, targets, impact) = match &attack.effect {
+        Effect::Incident(description, targets, impact) if increase > 0 => {
+            (description.clone(), targets.clone(), impact.clone())
+        }
+        _ => return board,
+    };
+
+    let escalated_impact = impact.escalate(increase);
+    let escalated_attack = AttackCard {
+        effect: Effect::Incident(description, targets, escalated_impact.clone()),
+        ..attack
+    };
+    let mut open_cards = board.open_cards.clone();
+    open_cards.insert(*attack_id, Rc::new(Card::from(escalated_attack)));
+
+    let additional_cost = match escalated_impact {
+        IncidentImpact::Fixed(_) => Resources::new(increase) * fix_multiplier,
+        IncidentImpact::PartOfRevenue(_) => Resources::new(0),
+    };
+    let active_incidents = board
+        .active_incidents
+        .iter()
+        .map(|incident| {
+            if &incident.attack_card_id == attack_id {
+                incident.accrue(&incident.mitigate(&additional_cost))
+            } else {
+                incident.clone()
+            }
+        })
+        .collect();
+
+    Board {
+        open_cards,
+        active_incidents,
+        ..board
+    }
+}
+
+fn get_incident_impact(board: &Board, incident: &Incident) -> Option<IncidentImpact> {
+    match board.open_cards.get(&incident.attack_card_id).map(|card| &**card) {
+        Some(Card::Attack(attack)) => match &attack.effect {
+            Effect::Incident(_, _, impact) => Some(impact.clone()),
+            _ => None,
+        },
+        _ => None,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::rc::Rc;
+
+    use fake::Fake;
+
+    use crate::cards::properties::effect_description::tests::FakeEffectDescription;
+    use crate::cards::properties::escalation::Escalation;
+    use crate::cards::properties::target::Target;
+    use crate::cards::types::attack::tests::FakeAttackCard;
+    use crate::cards::types::attack::AttackCard;
+    use crate::world::part_of_hundred::PartOfHundred;
+
+    use super::*;
+
+    fn generate_board_with_incident(impact: IncidentImpact) -> (Board, Incident) {
+        generate_board_with_escalating_incident(impact, Escalation::None)
+    }
+
+    fn generate_board_with_escalating_incident(
+        impact: IncidentImpact,
+        escalation: Escalation,
+    ) -> (Board, Incident) {
+        let attack_id = Uuid::new_v4();
+        let attack = Card::from(AttackCard {
+            effect: Effect::Incident(
+                FakeEffectDescription.fake(),
+                vec![Target::new("network")],
+                impact,
+            ),
+            escalation,
+            ..FakeAttackCard.fake()
+        });
+        let incident = Incident::start(
+            attack_id,
+            "attack",
+            Uuid::new_v4(),
+            "oopsie",
+            1,
+            Resources::new(0),
+        );
+        let board = Board {
+            open_cards: vec![(attack_id, Rc::new(attack))].into_iter().collect(),
+            active_incidents: vec![incident.clone()],
+            current_round: 2,
+            ..Board::empty()
+        };
+        (board, incident)
+    }
+
+    #[test]
+    fn accrue_incident_costs_adds_part_of_revenue() {
+        let (board, _) =
+            generate_board_with_incident(IncidentImpact::PartOfRevenue(PartOfHundred::new(50)));
+
+        let result = accrue_incident_costs(board, &Resources::new(10));
+
+        assert_eq!(result.active_incidents[0].accrued_cost, Resources::new(5));
+    }
+
+    #[test]
+    fn accrue_incident_costs_does_not_add_fixed_impact_again() {
+        let (board, _) = generate_board_with_incident(IncidentImpact::Fixed(Resources::new(10)));
+
+        let result = accrue_incident_costs(board, &Resources::new(10));
+
+        assert_eq!(result.active_incidents[0].accrued_cost, Resources::new(0));
+    }
+
+    fn impact_of_attack(board: &Board, incident: &Incident) -> IncidentImpact {
+        get_incident_impact(board, incident).unwrap()
+    }
+
+    #[test]
+    fn escalate_incidents_increases_fixed_impact_and_accrues_increase() {
+        let (board, incident) = generate_board_with_escalating_incident(
+            IncidentImpact::Fixed(Resources::new(10)),
+            Escalation::Linear(5),
+        );
+
+        let result = escalate_incidents(board, &ResourceFixMultiplier::new(2));
+
+        assert_eq!(
+            impact_of_attack(&result, &incident),
+            IncidentImpact::Fixed(Resources::new(15))
+        );
+        assert_eq!(result.active_incidents[0].accrued_cost, Resources::new(10));
+    }
+
+    #[test]
+    fn escalate_incidents_increases_relative_impact_without_accruing() {
+        let (board, incident) = generate_board_with_escalating_incident(
+            IncidentImpact::PartOfRevenue(PartOfHundred::new(10)),
+            Escalation::Linear(5),
+        );
+
+        let result = escalate_incidents(board, &ResourceFixMultiplier::default());
+
+        assert_eq!(
+            impact_of_attack(&result, &incident),
+            IncidentImpact::PartOfRevenue(PartOfHundred::new(15))
+        );
+        assert_eq!(result.active_incidents[0].accrued_cost, Resources::new(0));
+    }
+
+    #[test]
+    fn escalate_incidents_waits_for_next_step() {
+        // the incident started in round 1 and the board is in round 2
+        let (board, _) = generate_board_with_escalating_incident(
+            IncidentImpact::Fixed(Resources::new(10)),
+            Escalation::Stepped {
+                every: 2,
+                increase: 5,
+            },
+        );
+        let expected_board = board.clone();
+
+        let result = escalate_incidents(board, &ResourceFixMultiplier::default());
+
+        assert_eq!(result, expected_board);
+    }
+
+    #[test]
+    fn escalate_incidents_ignores_attacks_without_escalation() {
+        let (board, _) = generate_board_with_incident(IncidentImpact::Fixed(Resources::new(10)));
+        let expected_board = board.clone();
+
+        let result = escalate_incidents(board, &ResourceFixMultiplier::default());
+
+        assert_eq!(result, expected_board);
+    }
+
+    #[test]
+    fn resolve_incidents_of_card_moves_incident_to_resolved() {
+        let (board, incident) =
+            generate_board_with_incident(IncidentImpact::Fixed(Resources::new(10)));
+
+        let result = resolve_incidents_of_card(
+            board,
+            &incident.oopsie_card_id,
+            IncidentResolution::OopsieFixed,
+        );
+
+        assert!(result.active_incidents.is_empty());
+        assert_eq!(
+            result.resolved_incidents,
+            vec![incident.resolve(2, IncidentResolution::OopsieFixed)]
+        );
+    }
+
+    #[test]
+    fn resolve_incidents_of_uninvolved_card_changes_nothing() {
+        let (board, _) = generate_board_with_incident(IncidentImpact::Fixed(Resources::new(10)));
+        let expected_board = board.clone();
+
+        let result =
+            resolve_incidents_of_card(board, &Uuid::new_v4(), IncidentResolution::ForceClosed);
+
+        assert_eq!(result, expected_board);
+    }
+}
@@ -0,0 +1,183 @@
+/*
+Attacks can declare follow-up attacks, which are triggered once the attack caused an incident. A
+follow-up is either placed on the board right away or inserted into the remaining deck. Every attack
+triggers its follow-ups only once, even if it causes more than one incident.
+
+This has to be called after the incidents were determined by calculate_board.
+ */
+use std::collections::BTreeSet;
+use std::rc::Rc;
+
+use uuid::Uuid;
+
+use crate::cards::properties::follow_up_attack::{FollowUpAttack, FollowUpTiming};
+use crate::cards::types::card_model::Card;
+use crate::world::board::Board;
+use crate::world::deck::Deck;
+
+pub(crate) fn trigger_follow_up_attacks(deck: Deck, board: Board) -> (Deck, Board) {
+    let attacks_to_trigger: BTreeSet<Uuid> = board
+        .active_incidents
+        .iter()
+        .map(|incident| incident.attack_card_id)
+        .filter(|id| !board.triggered_follow_ups.contains(id))
+        .collect();
+
+    attacks_to_trigger
+        .iter()
+        .fold((deck, board), |(d, b), attack_id| {
+            trigger_follow_ups_of_attack(d, b, attack_id)
+        })
+}
+
+fn trigger_follow_ups_of_attack(deck: Deck, board: Board, attack_id: &Uuid) -> (Deck, Board) {
+    let follow_ups = match board.open_cards.get(attack_id).map(|card| &**card) {
+        Some(Card::Attack(attack)) => attack.follow_ups.clone(),
+        _ => vec![],
+    };
+
+    let mut triggered_follow_ups = board.triggered_follow_ups.clone();
+    triggered_follow_ups.insert(*attack_id);
+    let marked_board = Board {
+        triggered_follow_ups,
+        ..board
+    };
+
+    follow_ups
+        .iter()
+        .fold((deck, marked_board), |(d, b), follow_up| {
+            place_follow_up(d, b, follow_up)
+        })
+}
+
+fn place_follow_up(deck: Deck, board: Board, follow_up: &FollowUpAttack) -> (Deck, Board) {
+    let card = Rc::new(Card::from(follow_up.attack.clone()));
+    match follow_up.timing {
+        FollowUpTiming::Immediately => {
+            let mut open_cards = board.open_cards.clone();
+            open_cards.insert(Uuid::new_v4(), card);
+            (
+                deck,
+                Board {
+                    open_cards,
+                    ..board
+                },
+            )
+        }
+        FollowUpTiming::InRounds(rounds) => (deck.insert_card(card, rounds), board),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use fake::Fake;
+
+    use crate::cards::properties::title::Title;
+    use crate::cards::types::attack::tests::FakeAttackCard;
+    use crate::cards::types::attack::AttackCard;
+    use crate::cards::types::event::tests::FakeEventCard;
+    use crate::world::incident::Incident;
+    use crate::world::resources::Resources;
+
+    use super::*;
+
+    fn create_follow_up(title: &str, timing: FollowUpTiming) -> FollowUpAttack {
+        FollowUpAttack::new(
+            AttackCard {
+                title: Title::new(title),
+                ..FakeAttackCard.fake()
+            },
+            timing,
+        )
+    }
+
+    fn create_board_with_incident(follow_ups: Vec<FollowUpAttack>) -> (Uuid, Board) {
+        let attack_id = Uuid::new_v4();
+        let attack = AttackCard {
+            follow_ups,
+            ..FakeAttackCard.fake()
+        };
+        // the attack hits two oopsies
+        let incidents = vec![
+            Incident::start(attack_id, "attack", Uuid::new_v4(), "o1", 1, Resources::new(0)),
+            Incident::start(attack_id, "attack", Uuid::new_v4(), "o2", 1, Resources::new(0)),
+        ];
+        let board = Board {
+            open_cards: vec![(attack_id, Rc::new(Card::from(attack)))]
+                .into_iter()
+                .collect(),
+            active_incidents: incidents,
+            ..Board::empty()
+        };
+        (attack_id, board)
+    }
+
+    fn create_deck() -> Deck {
+        Deck::new(
+            (0..3)
+                .map(|_| Rc::new(Card::Event(FakeEventCard.fake())))
+                .collect(),
+        )
+    }
+
+    #[test]
+    fn immediate_follow_up_is_placed_on_board() {
+        let follow_up = create_follow_up("ransomware", FollowUpTiming::Immediately);
+        let (attack_id, board) = create_board_with_incident(vec![follow_up.clone()]);
+        let deck = create_deck();
+
+        let (new_deck, new_board) = trigger_follow_up_attacks(deck.clone(), board);
+
+        assert_eq!(new_deck, deck);
+        assert_eq!(new_board.open_cards.len(), 2);
+        assert!(new_board
+            .open_cards
+            .values()
+            .any(|card| **card == Card::from(follow_up.attack.clone())));
+        assert!(new_board.triggered_follow_ups.contains(&attack_id));
+    }
+
+    #[test]
+    fn delayed_follow_up_is_inserted_into_deck() {
+        let follow_up = create_follow_up("credential theft", FollowUpTiming::InRounds(2));
+        let (_, board) = create_board_with_incident(vec![follow_up.clone()]);
+
+        let (new_deck, new_board) = trigger_follow_up_attacks(create_deck(), board);
+
+        assert_eq!(new_board.open_cards.len(), 1);
+        assert_eq!(new_deck.remaining_cards.len(), 4);
+        assert_eq!(
+            *new_deck.remaining_cards[1],
+            Card::from(follow_up.attack.clone())
+        );
+    }
+
+    #[test]
+    fn follow_ups_are_triggered_only_once() {
+        let follow_up = create_follow_up("credential theft", FollowUpTiming::InRounds(1));
+        let (_, board) = create_board_with_incident(vec![follow_up]);
+
+        let (deck_after_first, board_after_first) =
+            trigger_follow_up_attacks(create_deck(), board);
+        let (deck_after_second, _) =
+            trigger_follow_up_attacks(deck_after_first.clone(), board_after_first);
+
+        assert_eq!(deck_after_first.remaining_cards.len(), 4);
+        assert_eq!(deck_after_second, deck_after_first);
+    }
+
+    #[test]
+    fn attacks_without_incident_do_not_trigger_follow_ups() {
+        let follow_up = create_follow_up("ransomware", FollowUpTiming::Immediately);
+        let (_, board) = create_board_with_incident(vec![follow_up]);
+        let board_without_incident = Board {
+            active_incidents: vec![],
+            ..board
+        };
+
+        let (_, new_board) = trigger_follow_up_attacks(create_deck(), board_without_incident);
+
+        assert_eq!(new_board.open_cards.len(), 1);
+        assert!(new_board.triggered_follow_ups.is_empty());
+    }
+}
@@ -19,11 +19,11 @@ where
 {
     if let Some(card) = board.open_cards.get(id) {
         match &**card {
-            Card::Event(_) | Card::Attack(_) | Card::Oopsie(_) | Card::Evaluation(_) => Err(WrongCardType(board)),
+            Card::Event(_) | Card::Attack(_) | Card::Oopsie(_) | Card::Evaluation(_) | Card::Control(_) => Err(WrongCardType(Box::new(board))),
             Card::Lucky(_) => Ok(func(board, id)),
         }
     } else {
-        Err(InvalidState(board))
+        Err(InvalidState(Box::new(board)))
     }
 }
 
@@ -132,7 +132,7 @@ mod tests {
         if let Some(err) = result.err() {
             match err {
                 ActionError::InvalidState(b) => {
-                    assert_eq!(b, board)
+                    assert_eq!(*b, board)
                 }
                 _ => panic!("Expected invalid state!"),
             }
@@ -161,7 +161,7 @@ mod tests {
         if let Some(err) = result.err() {
             match err {
                 ActionError::WrongCardType(b) => {
-                    assert_eq!(b, board)
+                    assert_eq!(*b, board)
                 }
                 _ => panic!("Expected WrongCardType"),
             }
@@ -234,7 +234,7 @@ mod tests {
         if let Some(err) = result.err() {
             match err {
                 ActionError::InvalidState(b) => {
-                    assert_eq!(b, board)
+                    assert_eq!(*b, board)
                 }
                 _ => panic!("Expected InvalidState"),
             }
@@ -263,7 +263,7 @@ mod tests {
         if let Some(err) = result.err() {
             match err {
                 ActionError::WrongCardType(b) => {
-                    assert_eq!(b, board)
+                    assert_eq!(*b, board)
                 }
                 _ => panic!("Expected WrongCardType"),
             }
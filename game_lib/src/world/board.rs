@@ -3,9 +3,9 @@ use std::collections::{HashMap, HashSet};
 use uuid::Uuid;
 
 use crate::cards::properties::cost_modifier::CostModifier;
-use crate::cards::types::attack::AttackCard;
-use crate::cards::types::oopsie::OopsieCard;
 use crate::world::deck::{CardRc, Deck};
+use crate::world::fix_progress::FixProgress;
+use crate::world::incident::Incident;
 use crate::world::reputation::Reputation;
 use crate::world::resources::Resources;
 /*
@@ -23,47 +23,75 @@ pub(crate) struct CardRcWithId {
 
 #[derive(Debug, Clone, PartialEq)]
 pub struct Board {
+    /// Budget of the players.
     pub current_resources: Resources,
+    /// Staff time of the players, needed for fixes which require people-hours.
+    pub current_people_hours: Resources,
     pub current_reputation: Reputation,
     pub(crate) drawn_card: Option<CardRcWithId>,
     pub open_cards: HashMap<Uuid, CardRc>,
     pub cards_to_use: HashSet<Uuid>,
     pub active_incidents: Vec<Incident>,
+    pub resolved_incidents: Vec<Incident>,
+    /// Attacks which already triggered their follow-up attacks.
+    pub triggered_follow_ups: HashSet<Uuid>,
+    /// Oopsies which are fixed over several rounds and are not finished yet.
+    pub fixes_in_progress: HashMap<Uuid, FixProgress>,
+    /// Control cards which were bought and protect their targets.
+    pub bought_controls: HashSet<Uuid>,
+    /// Lucky cards kept in the hand of the players. They are hidden and have no effect until played.
+    pub hand: HashMap<Uuid, CardRc>,
     pub cost_modifier: Option<CostModifier>,
     pub turns_remaining: usize,
-}
-#[derive(Debug, Clone, PartialEq, PartialOrd, Ord, Eq)]
-pub struct Incident {
-    pub attack_card_id: Uuid,
-    pub attack_title: String,
-    pub oopsie_card_id: Uuid,
-    pub oopsie_title: String,
+    pub current_round: usize,
+    /// Number of oopsies fixed so far, including fixes over several rounds.
+    pub fixed_oopsies: usize,
+    /// Reputation at the end of every finished round.
+    pub reputation_history: Vec<Reputation>,
 }
 
 impl Board {
     pub fn init(deck: &Deck, start_resources: Resources, start_reputation: Reputation) -> Self {
         Board {
             current_resources: start_resources,
+            current_people_hours: Resources::new(0),
             current_reputation: start_reputation,
             drawn_card: None,
             open_cards: HashMap::new(),
             cards_to_use: HashSet::new(),
             active_incidents: Vec::new(),
+            resolved_incidents: Vec::new(),
+            triggered_follow_ups: HashSet::new(),
+            fixes_in_progress: HashMap::new(),
+            bought_controls: HashSet::new(),
+            hand: HashMap::new(),
             cost_modifier: None,
             turns_remaining: deck.total,
+            current_round: deck.played_cards,
+            fixed_oopsies: 0,
+            reputation_history: Vec::new(),
         }
     }
 
     pub fn empty() -> Self {
         Board {
             current_resources: Resources::new(0),
+            current_people_hours: Resources::new(0),
             current_reputation: Reputation::start_value(),
             drawn_card: None,
             open_cards: HashMap::new(),
             cards_to_use: HashSet::new(),
             active_incidents: Vec::new(),
+            resolved_incidents: Vec::new(),
+            triggered_follow_ups: HashSet::new(),
+            fixes_in_progress: HashMap::new(),
+            bought_controls: HashSet::new(),
+            hand: HashMap::new(),
             cost_modifier: None,
             turns_remaining: 0,
+            current_round: 0,
+            fixed_oopsies: 0,
+            reputation_history: Vec::new(),
         }
     }
 }
@@ -0,0 +1,315 @@
+use std::fmt::{Display, Formatter};
+use std::str::FromStr;
+
+use crate::cards::properties::effect::Effect;
+use crate::cards::properties::target::Target;
+use crate::cards::properties::target_taxonomy::TargetTaxonomy;
+use crate::cards::types::card_model::{Card, CardCategory, CardTrait};
+use crate::world::deck::{CardRc, DeckRepository};
+
+/// Kind of the effect of a card, named like the effect in the card files.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum EffectKind {
+    Immediate,
+    AttackSurface,
+    Incident,
+    Protection,
+    OnNextFix,
+    OnUsingForFix,
+    Other,
+    Nop,
+}
+
+impl EffectKind {
+    pub const ALL: [EffectKind; 8] = [
+        EffectKind::Immediate,
+        EffectKind::AttackSurface,
+        EffectKind::Incident,
+        EffectKind::Protection,
+        EffectKind::OnNextFix,
+        EffectKind::OnUsingForFix,
+        EffectKind::Other,
+        EffectKind::Nop,
+    ];
+
+    pub fn of(effect: &Effect) -> Self {
+        match effect {
+            Effect::Immediate(_) => EffectKind::Immediate,
+            Effect::AttackSurface(_, _) => EffectKind::AttackSurface,
+            Effect::Incident(_, _, _) => EffectKind::Incident,
+            Effect::Protection(_, _, _) => EffectKind::Protection,
+            Effect::OnNextFix(_, _) => EffectKind::OnNextFix,
+            Effect::OnUsingForFix(_, _) => EffectKind::OnUsingForFix,
+            Effect::Other(_) => EffectKind::Other,
+            Effect::NOP => EffectKind::Nop,
+        }
+    }
+
+    fn name(&self) -> &'static str {
+        match self {
+            EffectKind::Immediate => "immediate",
+            EffectKind::AttackSurface => "attackSurface",
+            EffectKind::Incident => "incident",
+            EffectKind::Protection => "protection",
+            EffectKind::OnNextFix => "onNextFix",
+            EffectKind::OnUsingForFix => "onUsingForFix",
+            EffectKind::Other => "other",
+            EffectKind::Nop => "nop",
+        }
+    }
+}
+
+impl Display for EffectKind {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.name())
+    }
+}
+
+impl FromStr for EffectKind {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        EffectKind::ALL
+            .into_iter()
+            .find(|kind| kind.name().eq_ignore_ascii_case(s))
+            .ok_or_else(|| format!("unknown effect kind '{}'", s))
+    }
+}
+
+/// Parses a card type as it is written in the card files, e.g. `oopsie`.
+pub fn parse_card_category(name: &str) -> Result<CardCategory, String> {
+    match name.to_lowercase().as_str() {
+        "event" => Ok(Card::EVENT_CARD),
+        "attack" => Ok(Card::ATTACK_CARD),
+        "oopsie" => Ok(Card::OOPSIE_CARD),
+        "lucky" => Ok(Card::LUCKY_CARD),
+        "control" => Ok(Card::CONTROL_CARD),
+        _ => Err(format!("unknown card type '{}'", name)),
+    }
+}
+
+/// Costs of a card as range: the fix cost of an oopsie, the price of a control or of a lucky card.
+pub fn cost_range(card: &Card) -> Option<(usize, usize)> {
+    match card {
+        Card::Oopsie(oopsie) => Some((*oopsie.fix_cost.min_value(), *oopsie.fix_cost.max_value())),
+        Card::Control(control) => Some((*control.cost.value(), *control.cost.value())),
+        Card::Lucky(lucky) => lucky.price.as_ref().map(|price| (*price.value(), *price.value())),
+        _ => None,
+    }
+}
+
+/// Filters for cards. Every filter which is set must match, an empty query matches all cards.
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct CardQuery {
+    pub category: Option<CardCategory>,
+    /// Matches cards with a target which is part of this target or contains it.
+    pub target: Option<Target>,
+    pub effect_kind: Option<EffectKind>,
+    /// Matches cards whose cost range overlaps with this range, cards without costs never match.
+    pub min_cost: Option<usize>,
+    pub max_cost: Option<usize>,
+    /// Case-insensitive search in title, description and effect description.
+    pub text: Option<String>,
+}
+
+impl CardQuery {
+    pub fn matches(&self, card: &Card, taxonomy: &TargetTaxonomy) -> bool {
+        self.matches_category(card)
+            && self.matches_target(card, taxonomy)
+            && self
+                .effect_kind
+                .is_none_or(|kind| kind == EffectKind::of(card.effect()))
+            && self.matches_costs(card)
+            && self.matches_text(card)
+    }
+
+    /// All cards of the repository which match the query, ordered by card type.
+    pub fn find<T: DeckRepository>(&self, repository: &T, taxonomy: &TargetTaxonomy) -> Vec<CardRc> {
+        repository
+            .get_all_cards()
+            .into_iter()
+            .filter(|card| self.matches(card, taxonomy))
+            .collect()
+    }
+
+    fn matches_category(&self, card: &Card) -> bool {
+        self.category
+            .is_none_or(|category| &category == card.category())
+    }
+
+    fn matches_target(&self, card: &Card, taxonomy: &TargetTaxonomy) -> bool {
+        match &self.target {
+            None => true,
+            Some(target) => card
+                .effect()
+                .targets()
+                .iter()
+                .any(|t| taxonomy.matches(t, target)),
+        }
+    }
+
+    fn matches_costs(&self, card: &Card) -> bool {
+        if self.min_cost.is_none() && self.max_cost.is_none() {
+            return true;
+        }
+        match cost_range(card) {
+            None => false,
+            Some((min, max)) => {
+                self.min_cost.is_none_or(|lower| max >= lower)
+                    && self.max_cost.is_none_or(|upper| min <= upper)
+            }
+        }
+    }
+
+    fn matches_text(&self, card: &Card) -> bool {
+        match &self.text {
+            None => true,
+            Some(text) => {
+                let text = text.to_lowercase();
+                [
+                    card.title().value(),
+                    card.description().value(),
+                    effect_description(card.effect()),
+                ]
+                .iter()
+                .any(|value| value.to_lowercase().contains(text.as_str()))
+            }
+        }
+    }
+}
+
+fn effect_description(effect: &Effect) -> &str {
+    match effect {
+        Effect::Immediate(d)
+        | Effect::AttackSurface(d, _)
+        | Effect::Incident(d, _, _)
+        | Effect::Protection(d, _, _)
+        | Effect::OnNextFix(d, _)
+        | Effect::OnUsingForFix(d, _)
+        | Effect::Other(d) => d.value(),
+        Effect::NOP => "",
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::BTreeMap;
+    use std::rc::Rc;
+
+    use rstest::rstest;
+
+    use crate::cards::properties::description::Description;
+    use crate::cards::properties::effect_description::EffectDescription;
+    use crate::cards::properties::fix_cost::FixCost;
+    use crate::cards::properties::title::Title;
+    use crate::cards::types::event::EventCard;
+    use crate::cards::types::oopsie::OopsieCard;
+
+    use super::*;
+
+    fn oopsie(title: &str, target: &str, min: usize, max: usize) -> Card {
+        Card::Oopsie(OopsieCard {
+            title: Title::from(title.to_string()),
+            description: Description::from("Something is misconfigured".to_string()),
+            effect: Effect::AttackSurface(EffectDescription::new("Exposed"), vec![Target::new(target)]),
+            fix_cost: FixCost::new(min, max).unwrap(),
+            fix_rounds: None,
+        })
+    }
+
+    fn event(title: &str, effect: &str) -> Card {
+        Card::Event(EventCard {
+            title: Title::from(title.to_string()),
+            description: Description::from("Something happens".to_string()),
+            effect: Effect::Other(EffectDescription::new(effect)),
+            options: vec![],
+        })
+    }
+
+    struct FakeRepository;
+
+    impl DeckRepository for FakeRepository {
+        fn get_event_cards(&self) -> Vec<CardRc> {
+            vec![Rc::new(event("Audit", "Nothing happens"))]
+        }
+
+        fn get_lucky_cards(&self) -> Vec<CardRc> {
+            vec![]
+        }
+
+        fn get_oopsie_cards(&self) -> Vec<CardRc> {
+            vec![
+                Rc::new(oopsie("Open port", "database", 2, 4)),
+                Rc::new(oopsie("Weak password", "frontend", 6, 8)),
+            ]
+        }
+
+        fn get_attack_cards(&self) -> Vec<CardRc> {
+            vec![]
+        }
+
+        fn get_control_cards(&self) -> Vec<CardRc> {
+            vec![]
+        }
+    }
+
+    fn titles(query: &CardQuery) -> Vec<String> {
+        query
+            .find(&FakeRepository, &TargetTaxonomy::default())
+            .iter()
+            .map(|card| card.title().value().to_string())
+            .collect()
+    }
+
+    #[test]
+    fn empty_query_finds_all_cards() {
+        assert_eq!(
+            titles(&CardQuery::default()),
+            vec!["Audit", "Open port", "Weak password"]
+        );
+    }
+
+    #[rstest]
+    #[case(CardQuery { category: Some(Card::EVENT_CARD), ..CardQuery::default() }, vec!["Audit"])]
+    #[case(CardQuery { target: Some(Target::new("frontend")), ..CardQuery::default() }, vec!["Weak password"])]
+    #[case(CardQuery { effect_kind: Some(EffectKind::Other), ..CardQuery::default() }, vec!["Audit"])]
+    #[case(CardQuery { min_cost: Some(5), ..CardQuery::default() }, vec!["Weak password"])]
+    #[case(CardQuery { max_cost: Some(2), ..CardQuery::default() }, vec!["Open port"])]
+    #[case(CardQuery { min_cost: Some(5), max_cost: Some(5), ..CardQuery::default() }, vec![])]
+    #[case(CardQuery { text: Some("PASSWORD".to_string()), ..CardQuery::default() }, vec!["Weak password"])]
+    #[case(CardQuery { text: Some("nothing".to_string()), ..CardQuery::default() }, vec!["Audit"])]
+    fn filters_cards(#[case] query: CardQuery, #[case] expected: Vec<&str>) {
+        assert_eq!(titles(&query), expected);
+    }
+
+    #[test]
+    fn target_filter_uses_the_taxonomy() {
+        let taxonomy = TargetTaxonomy::new(BTreeMap::from([(
+            Target::new("data"),
+            vec![Target::new("database")],
+        )]));
+        let query = CardQuery {
+            target: Some(Target::new("data")),
+            ..CardQuery::default()
+        };
+
+        let found = query.find(&FakeRepository, &taxonomy);
+
+        assert_eq!(found.len(), 1);
+        assert_eq!(found[0].title().value(), "Open port");
+    }
+
+    #[rstest]
+    #[case("incident", Ok(EffectKind::Incident))]
+    #[case("AttackSurface", Ok(EffectKind::AttackSurface))]
+    #[case("magic", Err("unknown effect kind 'magic'".to_string()))]
+    fn parses_effect_kinds(#[case] name: &str, #[case] expected: Result<EffectKind, String>) {
+        assert_eq!(name.parse::<EffectKind>(), expected);
+    }
+
+    #[test]
+    fn parses_card_types_like_in_card_files() {
+        assert_eq!(parse_card_category("oopsie"), Ok(Card::OOPSIE_CARD));
+        assert!(parse_card_category("evaluation").is_err());
+    }
+}
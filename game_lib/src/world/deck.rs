@@ -4,7 +4,11 @@ use log::warn;
 use rand::prelude::{SliceRandom, ThreadRng};
 use std::rc::Rc;
 use rand::thread_rng;
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+use crate::cards::game_variants::rule_set::RuleSet;
 use crate::cards::game_variants::scenario::Scenario;
+use crate::cards::properties::target_taxonomy::TargetTaxonomy;
 
 /// This represents the current deck of cards. It also keeps count of the already played cards and the
 /// remaining cards. This file also contains all the methods needed to create a new Deck. E.g shuffling the cards
@@ -33,6 +37,19 @@ impl Deck {
     pub(crate) fn get_remaining_card_count(&self) -> usize {
         self.remaining_cards.len()
     }
+
+    /// Inserts a card so that it is drawn in the given number of rounds. If there are fewer cards
+    /// left, the card is put at the end of the deck.
+    pub(crate) fn insert_card(&self, card: CardRc, in_rounds: usize) -> Deck {
+        let mut remaining_cards = self.remaining_cards.clone();
+        let position = in_rounds.saturating_sub(1).min(remaining_cards.len());
+        remaining_cards.insert(position, card);
+        Deck {
+            remaining_cards,
+            played_cards: self.played_cards,
+            total: self.total + 1,
+        }
+    }
 }
 
 pub struct PreparedDeck {
@@ -41,13 +58,15 @@ pub struct PreparedDeck {
     evaluation: Vec<EvaluationCard>,
 }
 
-#[derive(Debug, Clone, Copy)]
+#[derive(Debug, Clone, Copy, PartialEq, Default, Serialize, Deserialize, JsonSchema)]
+#[serde(rename_all = "camelCase", default)]
 pub struct DeckComposition {
     pub events: usize,
     pub attacks: usize,
     pub oopsies: usize,
     pub lucky: usize,
     pub evaluation: usize,
+    pub controls: usize,
 }
 pub type CardRc = Rc<Card>;
 
@@ -56,10 +75,28 @@ pub trait DeckRepository {
     fn get_lucky_cards(&self) -> Vec<CardRc>;
     fn get_oopsie_cards(&self) -> Vec<CardRc>;
     fn get_attack_cards(&self) -> Vec<CardRc>;
+    fn get_control_cards(&self) -> Vec<CardRc>;
+
+    /// Cards of all types, ordered by card type like the directories of the card repository.
+    fn get_all_cards(&self) -> Vec<CardRc> {
+        [
+            self.get_event_cards(),
+            self.get_attack_cards(),
+            self.get_oopsie_cards(),
+            self.get_lucky_cards(),
+            self.get_control_cards(),
+        ]
+        .concat()
+    }
 }
 
 pub trait GameVariantsRepository {
     fn get_scenarios(&self) -> Vec<Rc<Scenario>>;
+    fn get_rule_sets(&self) -> Vec<Rc<RuleSet>>;
+}
+
+pub trait TargetTaxonomyRepository {
+    fn get_target_taxonomy(&self) -> TargetTaxonomy;
 }
 
 /// Defines a trait for deck preparation.
@@ -96,6 +133,15 @@ impl DeckPreparation for PreparedDeck {
         .unwrap();
         cards.append(&mut lucky_cards.clone());
 
+        // control cards are optional, repositories created before them have none
+        if composition.controls > 0 {
+            let total_control_cards = access.get_control_cards().to_vec();
+            let control_cards = draw(composition.controls, total_control_cards, &Card::CONTROL_CARD)
+            .map_err(|e| format!("Control Cards: {}", e).to_string())
+            .unwrap();
+            cards.append(&mut control_cards.clone());
+        }
+
         let total_attack_cards = access.get_attack_cards().to_vec();
         let attack_cards = draw(composition.attacks, total_attack_cards, &Card::ATTACK_CARD)
         .map_err(|e| format!("Attack Cards: {}", e).to_string())
@@ -140,6 +186,40 @@ impl DeckPreparation for PreparedDeck {
 }
 
 impl PreparedDeck {
+    /// Makes sure the given cards are part of the deck. A missing card replaces a drawn card of the same
+    /// type which is not required itself, if there is none the card is added.
+    pub fn include(self, required: &[CardRc]) -> PreparedDeck {
+        let is_required = |card: &CardRc| {
+            required
+                .iter()
+                .any(|r| r.title().value() == card.title().value())
+        };
+        let mut cards = self.cards;
+        let mut attacks = self.attacks;
+        for card in required {
+            let pile = if let Card::Attack(_) = &**card {
+                &mut attacks
+            } else {
+                &mut cards
+            };
+            if pile.iter().any(|c| c.title().value() == card.title().value()) {
+                continue;
+            }
+            match pile
+                .iter()
+                .position(|c| c.category() == card.category() && !is_required(c))
+            {
+                Some(index) => pile[index] = card.clone(),
+                None => pile.push(card.clone()),
+            }
+        }
+        PreparedDeck {
+            cards,
+            attacks,
+            evaluation: self.evaluation,
+        }
+    }
+
     fn add_attack_cards(
         mut rng: &mut ThreadRng,
         attack_graces: usize,
@@ -242,8 +322,9 @@ mod tests {
     use fake::Fake;
     use collections::HashSet;
     use crate::cards::types::event::EventCard;
-    use crate::cards::types::event::tests::FakeEventCard;
+    use crate::cards::types::event::tests::{event_with_title, FakeEventCard};
     use crate::cards::types::lucky::tests::FakeLuckyCard;
+    use crate::cards::types::attack::tests::FakeAttackCard;
     use super::*;
 
     fn extract_cards_from_result(res: &Vec<CardRc>) -> Vec<&EventCard> {
@@ -255,6 +336,63 @@ mod tests {
     }
 
 
+
+    #[test]
+    fn include_replaces_drawn_card_of_same_type_with_required_card() {
+        let prepared = PreparedDeck {
+            cards: vec![event_with_title("Drawn"), Rc::new(Card::Lucky(FakeLuckyCard.fake()))],
+            attacks: vec![],
+            evaluation: vec![],
+        };
+
+        let result = prepared.include(&[event_with_title("Required")]);
+
+        let titles: Vec<&str> = result.cards.iter().map(|c| c.title().value()).collect();
+        assert_eq!(result.cards.len(), 2);
+        assert!(titles.contains(&"Required"));
+        assert!(!titles.contains(&"Drawn"));
+    }
+
+    #[test]
+    fn include_adds_required_card_without_card_of_same_type_and_keeps_present_ones() {
+        let prepared = PreparedDeck {
+            cards: vec![event_with_title("Required")],
+            attacks: vec![],
+            evaluation: vec![],
+        };
+        let attack: CardRc = Rc::new(Card::Attack(FakeAttackCard.fake()));
+
+        let result = prepared.include(&[event_with_title("Required"), attack.clone()]);
+
+        assert_eq!(result.cards.len(), 1);
+        assert_eq!(result.attacks, vec![attack]);
+    }
+
+    #[test]
+    fn insert_card_places_card_to_be_drawn_in_given_rounds() {
+        let cards: Vec<CardRc> = (0..3)
+            .map(|_| Rc::new(Card::Event(FakeEventCard.fake())))
+            .collect();
+        let deck = Deck::new(cards.clone());
+        let card_to_insert: CardRc = Rc::new(Card::Lucky(FakeLuckyCard.fake()));
+
+        let result = deck.insert_card(card_to_insert.clone(), 2);
+
+        assert_eq!(result.remaining_cards[1], card_to_insert);
+        assert_eq!(result.remaining_cards.len(), 4);
+        assert_eq!(result.total, 4);
+    }
+
+    #[test]
+    fn insert_card_places_card_at_end_if_deck_is_too_small() {
+        let deck = Deck::new(vec![Rc::new(Card::Event(FakeEventCard.fake()))]);
+        let card_to_insert: CardRc = Rc::new(Card::Lucky(FakeLuckyCard.fake()));
+
+        let result = deck.insert_card(card_to_insert.clone(), 5);
+
+        assert_eq!(result.remaining_cards.last(), Some(&card_to_insert));
+    }
+
     #[test]
     fn draw_from_no_valid_cards_should_result_in_error() {
         let count: usize = 3;
@@ -0,0 +1,78 @@
+use crate::world::resources::Resources;
+
+/*
+Oopsies can take several rounds to fix. The fix costs are rolled once when the fix starts and are spread
+evenly across the rounds, the remainder is paid in the last round. Until the last round is paid the
+oopsie stays on the board and its attack surface stays exposed. Like the board, the progress is never
+mutated in place.
+ */
+#[derive(Debug, Clone, PartialEq)]
+pub struct FixProgress {
+    pub total_cost: Resources,
+    pub paid: Resources,
+    pub rounds_total: usize,
+    pub rounds_done: usize,
+}
+
+impl FixProgress {
+    pub fn start(total_cost: Resources, rounds_total: usize) -> Self {
+        FixProgress {
+            total_cost,
+            paid: Resources::new(0),
+            rounds_total: rounds_total.max(1),
+            rounds_done: 0,
+        }
+    }
+
+    /// Costs of the next round of the fix.
+    pub fn next_installment(&self) -> Resources {
+        if self.rounds_done + 1 >= self.rounds_total {
+            self.total_cost - self.paid
+        } else {
+            Resources::new(self.total_cost.value() / self.rounds_total)
+        }
+    }
+
+    /// Pays the next installment and finishes one round of the fix.
+    pub fn advance(&self) -> Self {
+        FixProgress {
+            paid: self.paid + self.next_installment(),
+            rounds_done: self.rounds_done + 1,
+            ..self.clone()
+        }
+    }
+
+    pub fn is_complete(&self) -> bool {
+        self.rounds_done >= self.rounds_total
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn costs_are_spread_across_rounds_with_remainder_in_last_round() {
+        let progress = FixProgress::start(Resources::new(10), 3);
+
+        let first = progress.next_installment();
+        let second = progress.advance().next_installment();
+        let last = progress.advance().advance().next_installment();
+
+        assert_eq!(
+            (first, second, last),
+            (Resources::new(3), Resources::new(3), Resources::new(4))
+        );
+    }
+
+    #[test]
+    fn fix_is_complete_after_all_rounds() {
+        let progress = FixProgress::start(Resources::new(10), 2).advance();
+        assert!(!progress.is_complete());
+
+        let completed = progress.advance();
+
+        assert!(completed.is_complete());
+        assert_eq!(completed.paid, Resources::new(10));
+    }
+}
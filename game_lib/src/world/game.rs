@@ -2,25 +2,40 @@ use std::collections::HashMap;
 
 use uuid::Uuid;
 
+use crate::cards::game_variants::rule_set::RuleSet;
+use crate::cards::game_variants::schedule::{scheduled_gain, ScheduledIncome, ScriptedEvent};
 use crate::cards::properties::cost_modifier::CostModifier;
+use crate::cards::properties::target_taxonomy::TargetTaxonomy;
 use crate::cards::types::card_model::Card;
 use crate::world::actions::action_error::{ActionError, ActionResult};
 use crate::world::actions::add_reputation::add_reputation;
-use crate::world::actions::add_resources::add_resources;
+use crate::world::actions::add_resources::{add_people_hours, add_resources};
+use crate::world::actions::buy_control::buy_control_card;
+use crate::world::actions::buy_market_card::buy_market_card;
 use crate::world::actions::calculate_board::calculate_board;
+use crate::world::actions::choose_option::choose_event_option;
 use crate::world::actions::close_attack::{manually_close_attack_card, update_attack_cards};
+use crate::world::actions::close_control::close_control_card;
 use crate::world::actions::close_evaluation::close_evaluation_card;
 use crate::world::actions::close_event::close_event_card;
 use crate::world::actions::close_lucky::close_lucky_card;
 use crate::world::actions::close_oopsie::try_and_pay_for_oopsie_fix;
 use crate::world::actions::draw_card::draw_card_and_place_on_board;
+use crate::world::actions::hand::{play_card_from_hand, take_drawn_card_into_hand};
+use crate::world::actions::progress_oopsie_fixes::progress_oopsie_fixes;
+use crate::world::actions::scripted_events::place_scripted_events;
 use crate::world::actions::subtract_reputation::subtract_reputation;
 use crate::world::actions::subtract_resources::subtract_resources;
+use crate::world::actions::track_incidents::accrue_incident_costs;
+use crate::world::actions::trigger_follow_ups::trigger_follow_up_attacks;
 use crate::world::actions::use_lucky_card::{activate_lucky_card, deactivate_lucky_card};
 use crate::world::board::Board;
 use crate::world::deck::{CardRc, Deck};
-use crate::world::game::GameActionResult::{FixFailed, InvalidAction, OopsieFixed};
+use crate::world::market::Market;
+use crate::world::objective::{Objective, ObjectiveProgress};
+use crate::world::game::GameActionResult::{FixFailed, FixStarted, InvalidAction, OopsieFixed};
 use crate::world::reputation::Reputation;
+use crate::world::reputation_income::ReputationIncome;
 use crate::world::resource_fix_multiplier::ResourceFixMultiplier;
 use crate::world::resources::Resources;
 
@@ -39,7 +54,17 @@ pub enum GameActionResult {
     NotEnoughResources,
     NothingPayed,
     OopsieFixed(Resources),
+    /// The oopsie takes several rounds to fix, the first round was paid.
+    FixStarted(Resources),
     FixFailed(Resources),
+    /// The fix needs more people-hours than available, contains the people-hours needed.
+    NotEnoughPeopleHours(Resources),
+    /// A control card was bought for the given resources and protects its targets now.
+    ControlBought(Resources),
+    /// A card of the market was bought for the given resources and placed on the board.
+    CardBought(Resources),
+    /// An option of a decision event was chosen, contains the label of the option.
+    OptionChosen(String),
     AttackForceClosed,
     InvalidAction,
     Success,
@@ -55,7 +80,23 @@ pub struct Game {
     /// this property contains the result of the last action performed by the player. Use this to know what happened.
     pub action_status: GameActionResult,
     pub resource_gain: Resources,
+    /// People-hours gained every round.
+    pub people_hours_gain: Resources,
+    /// Optional bands which scale the resource gain by the current reputation.
+    pub reputation_income: ReputationIncome,
     pub fix_multiplier: ResourceFixMultiplier,
+    /// Used to find out which attacks hit which oopsies.
+    pub target_taxonomy: TargetTaxonomy,
+    /// Cards which can be bought in the current round.
+    pub market: Market,
+    /// Number of lucky cards the players can keep in their hand, 0 disables the hand.
+    pub hand_size: usize,
+    /// Rules of the mechanics which can be changed for experiments.
+    pub rules: RuleSet,
+    /// Changes of the resource gain in later rounds.
+    pub income_schedule: Vec<ScheduledIncome>,
+    /// Cards placed on the board in addition to the drawn cards in given rounds.
+    pub scripted_events: Vec<ScriptedEvent>,
 }
 
 #[derive(Debug, Clone, PartialEq, Copy)]
@@ -64,6 +105,14 @@ pub struct GameInitSettings {
     pub resources: Resources,
     pub fix_multiplier: ResourceFixMultiplier,
     pub reputation: Reputation,
+    /// Number of cards offered in the market each round, 0 disables the market.
+    pub market_size: usize,
+    /// Number of lucky cards which can be kept in the hand, 0 disables the hand.
+    pub hand_size: usize,
+    /// People-hours available at the start of the game.
+    pub people_hours: Resources,
+    /// People-hours gained every round.
+    pub people_hours_gain: Resources,
 }
 
 pub struct CardCount {
@@ -78,6 +127,10 @@ impl Default for GameInitSettings {
             resources: Resources::default(),
             fix_multiplier: ResourceFixMultiplier::default(),
             reputation: Reputation::start_value(),
+            market_size: 0,
+            hand_size: 0,
+            people_hours: Resources::new(0),
+            people_hours_gain: Resources::new(0),
         }
     }
 }
@@ -101,13 +154,83 @@ impl Game {
         }
     }
 
+    /// Sets the target taxonomy of the card repository and re calculates the active incidents with it.
+    pub fn set_target_taxonomy(&self, target_taxonomy: TargetTaxonomy) -> Game {
+        let game = Game {
+            target_taxonomy,
+            ..self.clone()
+        };
+        let status = match &game.status {
+            GameStatus::Start(b) => GameStatus::Start(game.recalculate_board(b.clone())),
+            GameStatus::InProgress(b) => GameStatus::InProgress(game.recalculate_board(b.clone())),
+            GameStatus::Finished(b) => GameStatus::Finished(b.clone()),
+        };
+        Game { status, ..game }
+    }
+
+    /// Sets the rules of the game mechanics.
+    pub fn set_rule_set(&self, rules: RuleSet) -> Game {
+        Game {
+            rules,
+            ..self.clone()
+        }
+    }
+
+    /// Sets the changes of the resource gain in later rounds.
+    pub fn set_income_schedule(&self, income_schedule: Vec<ScheduledIncome>) -> Game {
+        Game {
+            income_schedule,
+            ..self.clone()
+        }
+    }
+
+    /// Sets the cards which are placed on the board in given rounds.
+    pub fn set_scripted_events(&self, scripted_events: Vec<ScriptedEvent>) -> Game {
+        Game {
+            scripted_events,
+            ..self.clone()
+        }
+    }
+
+    /// Sets the reputation bands which scale the resource gain, no bands pay out the full gain.
+    pub fn set_reputation_income(&self, reputation_income: ReputationIncome) -> Game {
+        Game {
+            reputation_income,
+            ..self.clone()
+        }
+    }
+
+    /// Resources gained at the start of the next round, the resource gain scaled by the current
+    /// reputation.
+    pub fn current_income(&self) -> Resources {
+        self.reputation_income
+            .scale(&self.resource_gain, &self.get_board().current_reputation)
+    }
+
+    /// Evaluates an objective of the scenario on the current board.
+    pub fn evaluate_objective(&self, objective: &Objective) -> ObjectiveProgress {
+        objective.evaluate(
+            self.get_board(),
+            &self.target_taxonomy,
+            matches!(self.status, GameStatus::Finished(_)),
+        )
+    }
+
+    /// Sets the cards which can be bought in the market and draws the first offer from them.
+    pub fn set_market_pool(&self, pool: Vec<CardRc>) -> Game {
+        Game {
+            market: Market::new(pool, self.market.size).refresh(),
+            ..self.clone()
+        }
+    }
+
     /// Marks a lucky card as activated and re calculates the board to take effects of this card into account.
     pub fn activate_lucky_card(&self, card_id: &Uuid) -> Game {
         match &self.status {
             GameStatus::Start(b) | GameStatus::InProgress(b) => {
                 match activate_lucky_card(b.clone(), card_id) {
                     Ok(new_board) => Game {
-                        status: GameStatus::InProgress(calculate_board(new_board, &self.deck)),
+                        status: GameStatus::InProgress(self.recalculate_board(new_board)),
                         action_status: GameActionResult::Success,
                         ..self.clone()
                     },
@@ -130,7 +253,7 @@ impl Game {
             GameStatus::Start(b) | GameStatus::InProgress(b) => {
                 match deactivate_lucky_card(b.clone(), card_id) {
                     Ok(new_board) => Game {
-                        status: GameStatus::InProgress(calculate_board(new_board, &self.deck)),
+                        status: GameStatus::InProgress(self.recalculate_board(new_board)),
                         action_status: GameActionResult::Success,
                         ..self.clone()
                     },
@@ -155,6 +278,17 @@ impl Game {
         }
     }
 
+    /// The board with the reputation of the finished round recorded, done before the next round starts.
+    fn finish_round(&self) -> Board {
+        let board = self.get_board();
+        let mut reputation_history = board.reputation_history.clone();
+        reputation_history.push(board.current_reputation);
+        Board {
+            reputation_history,
+            ..board.clone()
+        }
+    }
+
     fn get_board(&self) -> &Board {
         match self.status {
             GameStatus::Start(ref board)
@@ -170,27 +304,79 @@ impl Game {
         init_settings: GameInitSettings,
     ) -> Self {
         
-        let board = Board::init(&deck, init_settings.resources, init_settings.reputation);
-        let status = GameStatus::Start(calculate_board(board, &deck));
+        let board = Board {
+            current_people_hours: init_settings.people_hours,
+            ..Board::init(&deck, init_settings.resources, init_settings.reputation)
+        };
+        let target_taxonomy = TargetTaxonomy::default();
+        let status = GameStatus::Start(calculate_board(
+            board,
+            &deck,
+            &init_settings.fix_multiplier,
+            &target_taxonomy,
+        ));
 
         Game {
             deck,
             status,
             action_status: GameActionResult::Success,
             resource_gain: init_settings.resource_gain,
+            people_hours_gain: init_settings.people_hours_gain,
+            reputation_income: ReputationIncome::default(),
             fix_multiplier: init_settings.fix_multiplier,
+            target_taxonomy,
+            market: Market::new(vec![], init_settings.market_size),
+            hand_size: init_settings.hand_size,
+            rules: RuleSet::default(),
+            income_schedule: vec![],
+            scripted_events: vec![],
         }
     }
 
     /// Starts the next round. This also draws a new card and places it on the board and adds
-    /// the freshly gained resources.
+    /// the freshly gained resources. Scheduled income changes and scripted events of the round are
+    /// applied.
     pub fn next_round(&self) -> Self {
+        let round = self.deck.played_cards + 1;
+        match scheduled_gain(&self.income_schedule, round) {
+            Some(resource_gain) => Game {
+                resource_gain,
+                ..self.clone()
+            }
+            .draw_next_round(round),
+            None => self.draw_next_round(round),
+        }
+    }
+
+    fn draw_next_round(&self, round: usize) -> Self {
         if let Ok((new_deck, board)) =
-            draw_card_and_place_on_board(self.deck.clone(), self.get_board().clone())
+            draw_card_and_place_on_board(self.deck.clone(), self.finish_round())
         {
-            let board_with_added_resources = add_resources(board, &self.resource_gain);
-            let updated_attacks_board = update_attack_cards(board_with_added_resources);
-            let new_board = calculate_board(updated_attacks_board, &new_deck);
+            let board_with_hand = take_drawn_card_into_hand(board, self.hand_size);
+            // incidents cost their part of the income the players actually get
+            let income = self.current_income();
+            let board_with_added_resources = add_people_hours(
+                add_resources(board_with_hand, &income),
+                &self.people_hours_gain,
+            );
+            let board_with_progressed_fixes = progress_oopsie_fixes(board_with_added_resources);
+            let board_with_accrued_incidents =
+                accrue_incident_costs(board_with_progressed_fixes, &income);
+            let updated_attacks_board = update_attack_cards(
+                board_with_accrued_incidents,
+                &self.fix_multiplier,
+                &self.rules,
+            );
+            // scripted cards are placed like the drawn card, the board is calculated with them
+            let board_with_scripted_events =
+                place_scripted_events(updated_attacks_board, &self.scripted_events, round);
+            let calculated_board = calculate_board(
+                board_with_scripted_events,
+                &new_deck,
+                &self.fix_multiplier,
+                &self.target_taxonomy,
+            );
+            let (new_deck, new_board) = self.trigger_follow_ups(new_deck, calculated_board);
 
             let status = if new_board.turns_remaining == 0 {
                 GameStatus::Finished(new_board)
@@ -201,6 +387,7 @@ impl Game {
                 action_status: GameActionResult::Success,
                 deck: new_deck,
                 status,
+                market: self.market.refresh(),
                 ..self.clone()
             }
         } else {
@@ -222,6 +409,17 @@ impl Game {
         }
     }
 
+    /// Manually set the people-hours gain for the next round.
+    pub fn set_people_hours_gain(&self, new_gain: Resources) -> Self {
+        match &self.status {
+            GameStatus::Start(_) | GameStatus::InProgress(_) => Game {
+                people_hours_gain: new_gain,
+                ..self.clone()
+            },
+            GameStatus::Finished(_) => Game { ..self.clone() },
+        }
+    }
+
     /// Manually pay resources
     pub fn pay_resources(&self, to_pay: &Resources) -> Self {
         match &self.status {
@@ -230,10 +428,10 @@ impl Game {
 
                 let (b, res) = match new_board {
                     Ok(b) => (b, GameActionResult::Success),
-                    Err(e) => handle_action_error(board, &self.deck, e),
+                    Err(e) => self.handle_action_error(board, e),
                 };
                 Game {
-                    status: GameStatus::InProgress(calculate_board(b, &self.deck)),
+                    status: GameStatus::InProgress(self.recalculate_board(b)),
                     action_status: res,
                     ..self.clone()
                 }
@@ -251,7 +449,7 @@ impl Game {
             GameStatus::InProgress(b) => {
                 let new_board = add_reputation(b.clone(), value);
                 Game {
-                    status: GameStatus::InProgress(calculate_board(new_board, &self.deck)),
+                    status: GameStatus::InProgress(self.recalculate_board(new_board)),
                     action_status: GameActionResult::Success,
                     ..self.clone()
                 }
@@ -259,7 +457,7 @@ impl Game {
             GameStatus::Start(b) => {
                 let new_board = add_reputation(b.clone(), value);
                 Game {
-                    status: GameStatus::Start(calculate_board(new_board, &self.deck)),
+                    status: GameStatus::Start(self.recalculate_board(new_board)),
                     action_status: GameActionResult::Success,
                     ..self.clone()
                 }
@@ -277,7 +475,7 @@ impl Game {
             GameStatus::InProgress(b) => {
                 let new_board = subtract_reputation(b.clone(), value);
                 Game {
-                    status: GameStatus::InProgress(calculate_board(new_board, &self.deck)),
+                    status: GameStatus::InProgress(self.recalculate_board(new_board)),
                     action_status: GameActionResult::Success,
                     ..self.clone()
                 }
@@ -285,7 +483,7 @@ impl Game {
             GameStatus::Start(b) => {
                 let new_board = subtract_reputation(b.clone(), value);
                 Game {
-                    status: GameStatus::Start(calculate_board(new_board, &self.deck)),
+                    status: GameStatus::Start(self.recalculate_board(new_board)),
                     action_status: GameActionResult::Success,
                     ..self.clone()
                 }
@@ -302,14 +500,14 @@ impl Game {
     fn handle_non_oopsie_close(&self, result: ActionResult<Board>) -> Self {
         match result {
             Ok(b) => Game {
-                status: GameStatus::InProgress(calculate_board(b, &self.deck)),
+                status: GameStatus::InProgress(self.recalculate_board(b)),
                 action_status: GameActionResult::Success,
                 ..self.clone()
             },
             Err(err) => {
-                let (b, r) = handle_action_error(self.get_board(), &self.deck, err);
+                let (b, r) = self.handle_action_error(self.get_board(), err);
                 Game {
-                    status: GameStatus::InProgress(calculate_board(b, &self.deck)),
+                    status: GameStatus::InProgress(self.recalculate_board(b)),
                     action_status: r,
                     ..self.clone()
                 }
@@ -317,6 +515,104 @@ impl Game {
         }
     }
 
+    /// Buys a control card on the board. Once bought it protects its targets against new incidents.
+    pub fn buy_control(&self, card_id: &Uuid) -> Self {
+        match &self.status {
+            GameStatus::InProgress(board) => {
+                match buy_control_card(board.clone(), card_id, &self.fix_multiplier) {
+                    Ok((b, price)) => Game {
+                        status: GameStatus::InProgress(self.recalculate_board(b)),
+                        action_status: GameActionResult::ControlBought(price),
+                        ..self.clone()
+                    },
+                    Err(ActionError::NotEnoughResources(b, _)) => Game {
+                        status: GameStatus::InProgress(*b),
+                        action_status: GameActionResult::NotEnoughResources,
+                        ..self.clone()
+                    },
+                    Err(_) => Game {
+                        action_status: InvalidAction,
+                        ..self.clone()
+                    },
+                }
+            }
+            GameStatus::Start(_) | GameStatus::Finished(_) => self.clone(),
+        }
+    }
+
+    /// Chooses an option of a decision event. The consequences of the option are applied and the event
+    /// is removed from the board.
+    pub fn choose_option(&self, card_id: &Uuid, option_index: usize) -> Self {
+        match &self.status {
+            GameStatus::Start(b) | GameStatus::InProgress(b) => {
+                match choose_event_option(b.clone(), card_id, option_index) {
+                    Ok((new_board, option)) => Game {
+                        status: GameStatus::InProgress(self.recalculate_board(new_board)),
+                        action_status: GameActionResult::OptionChosen(option.label),
+                        ..self.clone()
+                    },
+                    Err(err) => Game {
+                        action_status: Self::action_status_of(&err),
+                        ..self.clone()
+                    },
+                }
+            }
+            GameStatus::Finished(_) => Game {
+                action_status: InvalidAction,
+                ..self.clone()
+            },
+        }
+    }
+
+    /// Plays a lucky card from the hand and places it on the board.
+    pub fn play_card_from_hand(&self, card_id: &Uuid) -> Self {
+        match &self.status {
+            GameStatus::Start(b) | GameStatus::InProgress(b) => {
+                match play_card_from_hand(b.clone(), card_id) {
+                    Ok(new_board) => Game {
+                        status: GameStatus::InProgress(self.recalculate_board(new_board)),
+                        action_status: GameActionResult::Success,
+                        ..self.clone()
+                    },
+                    Err(_) => Game {
+                        action_status: InvalidAction,
+                        ..self.clone()
+                    },
+                }
+            }
+            GameStatus::Finished(_) => Game {
+                action_status: InvalidAction,
+                ..self.clone()
+            },
+        }
+    }
+
+    /// Buys an offer of the market and places the card on the board.
+    pub fn buy_market_card(&self, offer_id: &Uuid) -> Self {
+        let (market, offer) = match (&self.status, self.market.take_offer(offer_id)) {
+            (GameStatus::Start(_) | GameStatus::InProgress(_), Some(taken)) => taken,
+            _ => {
+                return Game {
+                    action_status: InvalidAction,
+                    ..self.clone()
+                }
+            }
+        };
+        match buy_market_card(self.get_board().clone(), &offer, &self.fix_multiplier) {
+            Ok((b, price)) => Game {
+                status: GameStatus::InProgress(self.recalculate_board(b)),
+                action_status: GameActionResult::CardBought(price),
+                market,
+                ..self.clone()
+            },
+            // the board and the market stay as they were
+            Err(err) => Game {
+                action_status: Self::action_status_of(&err),
+                ..self.clone()
+            },
+        }
+    }
+
     /// Close a card if allowed.
     pub fn close_card(&self, card_id: &Uuid) -> Self {
         match &self.status {
@@ -334,24 +630,40 @@ impl Game {
                         Card::Lucky(_) => {
                             self.handle_non_oopsie_close(close_lucky_card(board.clone(), card_id))
                         }
+                        Card::Control(_) => {
+                            self.handle_non_oopsie_close(close_control_card(board.clone(), card_id))
+                        }
                         Card::Oopsie(_) => {
                             let result = try_and_pay_for_oopsie_fix(
                                 board.clone(),
                                 card_id,
-                                self.fix_multiplier.clone(),
+                                self.fix_multiplier,
+                                &self.rules,
                             );
                             match result {
-                                Ok((b, r)) => Game {
-                                    status: GameStatus::InProgress(calculate_board(b, &self.deck)),
-                                    action_status: OopsieFixed(r),
-                                    ..self.clone()
-                                },
+                                Ok((b, r)) => {
+                                    let action_status = if b.fixes_in_progress.contains_key(card_id)
+                                    {
+                                        FixStarted(r)
+                                    } else {
+                                        OopsieFixed(r)
+                                    };
+                                    Game {
+                                        status: GameStatus::InProgress(self.recalculate_board(b)),
+                                        action_status,
+                                        ..self.clone()
+                                    }
+                                }
                                 Err(e) => match e {
                                     ActionError::NotEnoughResources(b, r) => Game {
-                                        status: GameStatus::InProgress(b),
+                                        status: GameStatus::InProgress(*b),
                                         action_status: FixFailed(r),
                                         ..self.clone()
                                     },
+                                    ActionError::NotEnoughPeopleHours(_, h) => Game {
+                                        action_status: GameActionResult::NotEnoughPeopleHours(h),
+                                        ..self.clone()
+                                    },
                                     _ => Game {
                                         action_status: InvalidAction,
                                         ..self.clone()
@@ -390,15 +702,51 @@ impl Game {
     }
 }
 
-fn handle_action_error(board: &Board, deck: &Deck, err: ActionError) -> (Board, GameActionResult) {
-    match err {
-        ActionError::AttackForceClosed(b) => (b.clone(), GameActionResult::AttackForceClosed),
-        ActionError::NoCardsLeft => (board.clone(), InvalidAction),
-        ActionError::WrongCardType(b) | ActionError::InvalidState(b) => {
-            (calculate_board(b, deck), InvalidAction)
+impl Game {
+    fn recalculate_board(&self, board: Board) -> Board {
+        calculate_board(board, &self.deck, &self.fix_multiplier, &self.target_taxonomy)
+    }
+
+    /// Triggers follow-up attacks of all attacks which caused an incident. Follow-ups placed on the
+    /// board directly can cause incidents themselves, so this is repeated until no new follow-ups
+    /// are triggered.
+    fn trigger_follow_ups(&self, deck: Deck, board: Board) -> (Deck, Board) {
+        let mut current = (deck, board);
+        loop {
+            let triggered_before = current.1.triggered_follow_ups.len();
+            let (deck, board) = trigger_follow_up_attacks(current.0, current.1);
+            if board.triggered_follow_ups.len() == triggered_before {
+                return (deck, board);
+            }
+            let board = calculate_board(board, &deck, &self.fix_multiplier, &self.target_taxonomy);
+            current = (deck, board);
         }
-        ActionError::NotEnoughResources(_, _) => {
-            (board.clone(), GameActionResult::NotEnoughResources)
+    }
+
+    fn handle_action_error(&self, board: &Board, err: ActionError) -> (Board, GameActionResult) {
+        let action_status = Self::action_status_of(&err);
+        let board = match err {
+            ActionError::AttackForceClosed(b) => *b,
+            ActionError::WrongCardType(b) | ActionError::InvalidState(b) => self.recalculate_board(*b),
+            ActionError::NoCardsLeft
+            | ActionError::NotEnoughResources(_, _)
+            | ActionError::NotEnoughPeopleHours(_, _)
+            | ActionError::FixInProgress(_) => board.clone(),
+        };
+        (board, action_status)
+    }
+
+    fn action_status_of(err: &ActionError) -> GameActionResult {
+        match err {
+            ActionError::AttackForceClosed(_) => GameActionResult::AttackForceClosed,
+            ActionError::NotEnoughResources(_, _) => GameActionResult::NotEnoughResources,
+            ActionError::NotEnoughPeopleHours(_, hours) => {
+                GameActionResult::NotEnoughPeopleHours(*hours)
+            }
+            ActionError::NoCardsLeft
+            | ActionError::WrongCardType(_)
+            | ActionError::InvalidState(_)
+            | ActionError::FixInProgress(_) => InvalidAction,
         }
     }
 }
@@ -407,12 +755,22 @@ fn handle_action_error(board: &Board, deck: &Deck, err: ActionError) -> (Board,
 mod tests {
     use fake::Fake;
     use std::collections::{HashMap, HashSet};
+    use std::rc::Rc;
 
+    use uuid::Uuid;
+
+    use crate::cards::game_variants::rule_set::RuleSet;
+    use crate::cards::game_variants::schedule::{ScheduledIncome, ScriptedEvent};
     use crate::cards::properties::cost_modifier::tests::FakeCostModifier;
     use crate::cards::properties::cost_modifier::CostModifier;
+    use crate::cards::properties::decision::{Consequence, DecisionOption};
     use crate::cards::properties::effect::Effect;
     use crate::cards::properties::effect_description::tests::FakeEffectDescription;
+    use crate::cards::properties::duration::Duration;
     use crate::cards::properties::fix_cost::FixCost;
+    use crate::cards::properties::incident_impact::IncidentImpact;
+    use crate::cards::properties::target::Target;
+    use crate::cards::properties::target_taxonomy::TargetTaxonomy;
     use crate::cards::types::attack::tests::FakeAttackCard;
     use crate::cards::types::attack::AttackCard;
     use crate::cards::types::card_model::Card;
@@ -425,7 +783,12 @@ mod tests {
     use crate::world::board::Board;
     use crate::world::deck::{CardRc, Deck};
     use crate::world::game::{Game, GameActionResult, GameInitSettings, GameStatus};
+    use crate::world::incident::Incident;
+    use crate::world::market::Market;
+    use crate::world::objective::{Objective, ObjectiveState};
+    use crate::world::part_of_hundred::PartOfHundred;
     use crate::world::reputation::Reputation;
+    use crate::world::reputation_income::{IncomeBand, ReputationIncome};
     use crate::world::resource_fix_multiplier::ResourceFixMultiplier;
     use crate::world::resources::Resources;
 
@@ -493,17 +856,34 @@ mod tests {
             deck: test_deck.start_deck.clone(),
             status: GameStatus::Start(Board {
                 current_resources: Resources::new(0),
+                current_people_hours: Resources::new(3),
                 current_reputation: Reputation::new(50),
                 drawn_card: None,
                 open_cards: HashMap::new(),
                 cards_to_use: HashSet::new(),
                 active_incidents: Vec::new(),
+                resolved_incidents: Vec::new(),
+                triggered_follow_ups: HashSet::new(),
+                fixes_in_progress: HashMap::new(),
+                bought_controls: HashSet::new(),
+                hand: HashMap::new(),
                 cost_modifier: None,
                 turns_remaining: test_deck.start_deck.total,
+                current_round: 0,
+                fixed_oopsies: 0,
+                reputation_history: Vec::new(),
             }),
             action_status: GameActionResult::Success,
             resource_gain: Resources::new(10),
+            people_hours_gain: Resources::new(2),
+            reputation_income: ReputationIncome::default(),
             fix_multiplier: ResourceFixMultiplier::new(2),
+            target_taxonomy: TargetTaxonomy::default(),
+            market: Market::new(vec![], 0),
+            hand_size: 0,
+            rules: RuleSet::default(),
+            income_schedule: vec![],
+            scripted_events: vec![],
         };
 
         let sut = Game::create(
@@ -513,6 +893,10 @@ mod tests {
                 resource_gain: Resources::new(10),
                 fix_multiplier: ResourceFixMultiplier::new(2),
                 reputation: Reputation::start_value(),
+                market_size: 0,
+                hand_size: 0,
+                people_hours: Resources::new(3),
+                people_hours_gain: Resources::new(2),
             }
         );
 
@@ -676,6 +1060,311 @@ mod tests {
         assert!(!game_after_deactivate.is_card_activated(card_id))
     }
 
+    #[test]
+    fn drawn_lucky_card_is_kept_in_hand_until_played() {
+        let test_deck = TestDeck::init_test_deck();
+        let game = Game::create(
+            test_deck.start_deck,
+            GameInitSettings {
+                hand_size: 1,
+                ..GameInitSettings::default()
+            },
+        )
+        .next_round();
+        let board = get_board_from_in_progress(&game);
+        assert!(board.open_cards.is_empty());
+        let card_id = *board.hand.keys().next().unwrap();
+
+        let played = game.play_card_from_hand(&card_id);
+
+        let board_after_play = get_board_from_in_progress(&played);
+        assert!(board_after_play.hand.is_empty());
+        assert!(board_after_play.open_cards.contains_key(&card_id));
+    }
+
+    #[test]
+    fn buy_market_card_places_card_on_board_and_removes_offer() {
+        let test_deck = TestDeck::init_test_deck();
+        let pool: Vec<CardRc> = vec![Card::from(LuckyCard {
+            price: Some(Resources::new(5)),
+            ..FakeLuckyCard.fake()
+        })
+        .into()];
+        let game = Game::create(
+            test_deck.start_deck,
+            GameInitSettings {
+                resources: Resources::new(20),
+                market_size: 1,
+                ..GameInitSettings::default()
+            },
+        )
+        .set_market_pool(pool)
+        .next_round();
+        let offer_id = *game.market.offers.keys().next().unwrap();
+        let open_cards_before = get_board_from_in_progress(&game).open_cards.len();
+
+        let result = game.buy_market_card(&offer_id);
+
+        assert_eq!(
+            result.action_status,
+            GameActionResult::CardBought(Resources::new(5))
+        );
+        assert!(result.market.offers.is_empty());
+        let board = get_board_from_in_progress(&result);
+        assert_eq!(board.open_cards.len(), open_cards_before + 1);
+        assert_eq!(board.current_resources, Resources::new(20));
+    }
+
+    #[test]
+    fn buy_market_card_without_enough_resources_keeps_game_and_offer() {
+        let pool: Vec<CardRc> = vec![Card::from(LuckyCard {
+            price: Some(Resources::new(50)),
+            ..FakeLuckyCard.fake()
+        })
+        .into()];
+        let game = Game::create(
+            TestDeck::init_test_deck().start_deck,
+            GameInitSettings {
+                resources: Resources::new(20),
+                market_size: 1,
+                ..GameInitSettings::default()
+            },
+        )
+        .set_market_pool(pool)
+        .next_round();
+        let offer_id = *game.market.offers.keys().next().unwrap();
+
+        let result = game.buy_market_card(&offer_id);
+
+        assert_eq!(result.action_status, GameActionResult::NotEnoughResources);
+        assert_eq!(result.status, game.status);
+        assert_eq!(result.market, game.market);
+    }
+
+    #[test]
+    fn choose_option_applies_consequences_of_decision_event() {
+        let decision = Card::from(EventCard {
+            options: vec![
+                DecisionOption::new("Patch now", vec![Consequence::PayResources(Resources::new(4))]),
+                DecisionOption::new("Wait", vec![]),
+            ],
+            ..FakeEventCard.fake()
+        });
+        let cards: Vec<CardRc> = vec![decision.into(), Card::from(FakeOopsieCard.fake::<OopsieCard>()).into()];
+        let game = Game::create(
+            Deck::new(cards),
+            GameInitSettings {
+                resources: Resources::new(10),
+                resource_gain: Resources::new(0),
+                ..GameInitSettings::default()
+            },
+        )
+        .next_round();
+        let card_id = *get_board_from_in_progress(&game).open_cards.keys().next().unwrap();
+
+        let result = game.choose_option(&card_id, 0);
+
+        assert_eq!(
+            result.action_status,
+            GameActionResult::OptionChosen("Patch now".to_string())
+        );
+        let board = get_board_from_in_progress(&result);
+        assert_eq!(board.current_resources, Resources::new(6));
+        assert!(board.open_cards.is_empty());
+    }
+
+    #[test]
+    fn choose_option_which_fails_keeps_game_status() {
+        let game = Game::create(
+            TestDeck::init_test_deck().start_deck,
+            GameInitSettings::default(),
+        );
+
+        let result = game.choose_option(&Uuid::new_v4(), 0);
+
+        assert_eq!(result.action_status, GameActionResult::InvalidAction);
+        assert!(matches!(result.status, GameStatus::Start(_)));
+        assert_eq!(result.status, game.status);
+    }
+
+    #[test]
+    fn next_round_adds_people_hours_gain() {
+        let game = Game::create(
+            TestDeck::init_test_deck().start_deck,
+            GameInitSettings {
+                people_hours: Resources::new(3),
+                people_hours_gain: Resources::new(2),
+                ..GameInitSettings::default()
+            },
+        );
+
+        let after_two_rounds = game.next_round().set_people_hours_gain(Resources::new(4)).next_round();
+
+        let board = get_board_from_in_progress(&after_two_rounds);
+        assert_eq!(board.current_people_hours, Resources::new(9));
+    }
+
+    #[test]
+    fn next_round_scales_resource_gain_by_reputation() {
+        let game = Game::create(
+            TestDeck::init_test_deck().start_deck,
+            GameInitSettings {
+                resource_gain: Resources::new(10),
+                reputation: Reputation::new(20),
+                ..GameInitSettings::default()
+            },
+        )
+        .set_reputation_income(ReputationIncome::new(vec![IncomeBand::new(30, 80)]));
+
+        assert_eq!(game.current_income(), Resources::new(8));
+        let board = get_board_from_in_progress(&game.next_round());
+        assert_eq!(board.current_resources, Resources::new(8));
+    }
+
+    #[test]
+    fn incidents_cost_their_part_of_the_scaled_income() {
+        let game = Game::create(
+            TestDeck::init_test_deck().start_deck,
+            GameInitSettings {
+                resource_gain: Resources::new(10),
+                reputation: Reputation::new(20),
+                ..GameInitSettings::default()
+            },
+        )
+        .set_reputation_income(ReputationIncome::new(vec![IncomeBand::new(30, 80)]))
+        .next_round();
+        let attack_id = Uuid::new_v4();
+        let oopsie_id = Uuid::new_v4();
+        let attack = Card::from(AttackCard {
+            effect: Effect::Incident(
+                FakeEffectDescription.fake(),
+                vec![Target::new("network")],
+                IncidentImpact::PartOfRevenue(PartOfHundred::new(50)),
+            ),
+            duration: Duration::Rounds(5),
+            ..FakeAttackCard.fake()
+        });
+        let oopsie = Card::from(OopsieCard {
+            effect: Effect::AttackSurface(FakeEffectDescription.fake(), vec![Target::new("network")]),
+            ..FakeOopsieCard.fake()
+        });
+        let board = get_board_from_in_progress(&game);
+        let mut open_cards = board.open_cards.clone();
+        open_cards.insert(attack_id, Rc::new(attack));
+        open_cards.insert(oopsie_id, Rc::new(oopsie));
+        let game = Game {
+            status: GameStatus::InProgress(Board {
+                open_cards,
+                active_incidents: vec![Incident::start(
+                    attack_id,
+                    "attack",
+                    oopsie_id,
+                    "oopsie",
+                    board.current_round,
+                    Resources::new(0),
+                )],
+                ..board
+            }),
+            ..game
+        };
+
+        let board = get_board_from_in_progress(&game.next_round());
+
+        let incident = board
+            .active_incidents
+            .iter()
+            .find(|incident| incident.attack_card_id == attack_id)
+            .unwrap();
+        assert_eq!(incident.accrued_cost, Resources::new(4));
+    }
+
+    #[test]
+    fn next_round_applies_income_schedule_and_scripted_events() {
+        let game = Game::create(
+            TestDeck::init_test_deck().start_deck,
+            GameInitSettings {
+                resource_gain: Resources::new(10),
+                ..GameInitSettings::default()
+            },
+        )
+        .set_income_schedule(vec![ScheduledIncome {
+            round: 2,
+            resource_gain: Resources::new(3),
+        }])
+        .set_scripted_events(vec![ScriptedEvent {
+            round: 1,
+            card: Card::from(FakeEventCard.fake::<EventCard>()),
+        }]);
+
+        let round_1 = game.next_round();
+        assert_eq!(get_board_from_in_progress(&round_1).open_cards.len(), 2);
+
+        let round_2 = round_1.next_round();
+        assert_eq!(round_2.resource_gain, Resources::new(3));
+        assert_eq!(
+            get_board_from_in_progress(&round_2).current_resources,
+            Resources::new(13)
+        );
+    }
+
+    #[test]
+    fn scripted_attack_opens_incident_in_its_round() {
+        let oopsie = Card::from(OopsieCard {
+            effect: Effect::AttackSurface(FakeEffectDescription.fake(), vec![Target::new("network")]),
+            ..FakeOopsieCard.fake()
+        });
+        let attack = Card::from(AttackCard {
+            effect: Effect::Incident(
+                FakeEffectDescription.fake(),
+                vec![Target::new("network")],
+                IncidentImpact::Fixed(Resources::new(2)),
+            ),
+            duration: Duration::Rounds(1),
+            ..FakeAttackCard.fake()
+        });
+        let game = Game::create(
+            Deck::new(vec![oopsie.into(), Card::from(FakeEventCard.fake::<EventCard>()).into()]),
+            GameInitSettings::default(),
+        )
+        .set_scripted_events(vec![ScriptedEvent {
+            round: 1,
+            card: attack,
+        }]);
+
+        let board = get_board_from_in_progress(&game.next_round());
+
+        assert_eq!(board.active_incidents.len(), 1);
+    }
+
+    #[test]
+    fn next_round_records_reputation_for_objectives() {
+        let game = Game::create(
+            TestDeck::init_test_deck().start_deck,
+            GameInitSettings {
+                reputation: Reputation::new(60),
+                ..GameInitSettings::default()
+            },
+        );
+        let objective = Objective::ReputationAbove {
+            reputation: Reputation::new(50),
+            rounds: 2,
+        };
+
+        assert_eq!(
+            game.evaluate_objective(&objective).state,
+            ObjectiveState::Open
+        );
+        let after_two_rounds = game.next_round().next_round();
+        assert_eq!(
+            get_board_from_in_progress(&after_two_rounds).reputation_history,
+            vec![Reputation::new(60), Reputation::new(60)]
+        );
+        assert_eq!(
+            after_two_rounds.evaluate_objective(&objective).state,
+            ObjectiveState::Achieved
+        );
+    }
+
     fn get_board_from_in_progress(game: &Game) -> Board {
         match &game.status {
             GameStatus::InProgress(b) => b.clone(),
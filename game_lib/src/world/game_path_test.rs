@@ -27,6 +27,8 @@ mod path_tests {
         use super::*;
         use crate::cards::types::card_model::CardTrait;
         use crate::world::game::GameInitSettings;
+        use crate::world::incident::IncidentResolution;
+        use crate::world::resource_fix_multiplier::ResourceFixMultiplier;
         use std::rc::Rc;
         use uuid::Uuid;
 
@@ -109,6 +111,11 @@ mod path_tests {
             let closed_attack = active_incident.close_card(attack_card_id);
             let board_after_closed_attack = get_board_from_game(&closed_attack);
             assert_eq!(board_after_closed_attack.active_incidents.len(), 0, "Attack is over, no incident expected");
+            assert_eq!(board_after_closed_attack.resolved_incidents.len(), 1);
+            assert_eq!(
+                board_after_closed_attack.resolved_incidents[0].resolution,
+                Some(IncidentResolution::ForceClosed)
+            );
         }
 
         #[test]
@@ -124,6 +131,26 @@ mod path_tests {
             let closed_oopsie = active_incident.close_card(oopsie_card_id);
             let board_after_closed_oopsie = get_board_from_game(&closed_oopsie);
             assert_eq!(board_after_closed_oopsie.active_incidents.len(), 0, "Oopsie is fixed, no incident expected");
+            assert_eq!(board_after_closed_oopsie.resolved_incidents.len(), 1);
+            assert_eq!(
+                board_after_closed_oopsie.resolved_incidents[0].resolution,
+                Some(IncidentResolution::OopsieFixed)
+            );
+        }
+
+        #[test]
+        fn incident_keeps_start_round_while_active() {
+            let start_game = create_game();
+
+            let active_incident = start_game.next_round().next_round();
+            let incident_in_first_round = get_board_from_game(&active_incident).active_incidents[0].clone();
+            assert_eq!(incident_in_first_round.started_in_round, 2);
+            assert_eq!(incident_in_first_round.accrued_cost, Resources::new(10) * ResourceFixMultiplier::default());
+
+            let next_round = active_incident.next_round();
+            let board_in_next_round = get_board_from_game(&next_round);
+            assert_eq!(board_in_next_round.active_incidents, vec![incident_in_first_round.clone()]);
+            assert_eq!(board_in_next_round.active_incidents[0].duration(board_in_next_round.current_round), 2);
         }
 
         fn find_card_id_by_title<'a>(board: &'a Board, title: &str) -> &'a Uuid {
@@ -132,6 +159,157 @@ mod path_tests {
 
     }
 
+    mod multi_round_fixes {
+        use super::*;
+        use crate::cards::properties::fix_cost::FixCost;
+        use crate::cards::types::card_model::CardTrait;
+        use crate::world::game::{GameActionResult, GameInitSettings};
+        use crate::world::incident::IncidentResolution;
+        use std::rc::Rc;
+
+        fn create_game() -> Game {
+            let oopsie_card = OopsieCard {
+                title: Title::new("Oopsie card"),
+                effect: Effect::AttackSurface(
+                    EffectDescription::new("Attack surface"),
+                    vec![Target::new("network")],
+                ),
+                fix_cost: FixCost::new(10, 10).unwrap(),
+                fix_rounds: Some(2),
+                ..FakeOopsieCard.fake()
+            };
+            let attack_card = AttackCard {
+                effect: Effect::Incident(
+                    EffectDescription::new("Attack"),
+                    vec![Target::new("network")],
+                    IncidentImpact::Fixed(Resources::new(1)),
+                ),
+                duration: Duration::new(Some(5)),
+                ..FakeAttackCard.fake()
+            };
+
+            let deck = Deck::new(vec![
+                Rc::new(Card::from(oopsie_card)),
+                Rc::new(Card::from(attack_card)),
+                Rc::new(Card::from(FakeEventCard.fake::<EventCard>())),
+                Rc::new(Card::from(FakeEventCard.fake::<EventCard>())),
+            ]);
+
+            let init_settings = GameInitSettings {
+                resources: Resources::new(100),
+                ..GameInitSettings::default()
+            };
+            Game::create(deck, init_settings)
+        }
+
+        #[test]
+        fn attack_surface_stays_exposed_until_fix_is_completed() {
+            let active_incident = create_game().next_round().next_round();
+            let board_with_incident = get_board_from_game(&active_incident);
+            let oopsie_id = *board_with_incident
+                .open_cards
+                .iter()
+                .find(|(_, card)| card.title().value() == "Oopsie card")
+                .unwrap()
+                .0;
+
+            let fix_started = active_incident.close_card(&oopsie_id);
+            assert_eq!(fix_started.action_status, GameActionResult::FixStarted(Resources::new(5)));
+            let board_with_fix = get_board_from_game(&fix_started);
+            assert!(board_with_fix.open_cards.contains_key(&oopsie_id));
+            assert_eq!(board_with_fix.active_incidents.len(), 1);
+
+            let fix_completed = fix_started.next_round();
+            let board_after_fix = get_board_from_game(&fix_completed);
+            assert!(!board_after_fix.open_cards.contains_key(&oopsie_id));
+            assert!(board_after_fix.fixes_in_progress.is_empty());
+            assert!(board_after_fix.active_incidents.is_empty());
+            assert_eq!(
+                board_after_fix.resolved_incidents[0].resolution,
+                Some(IncidentResolution::OopsieFixed)
+            );
+        }
+    }
+
+    mod attack_chains {
+        use super::*;
+        use crate::cards::properties::follow_up_attack::{FollowUpAttack, FollowUpTiming};
+        use crate::cards::types::card_model::CardTrait;
+        use crate::world::game::GameInitSettings;
+        use std::rc::Rc;
+
+        fn create_attack(title: &str, follow_ups: Vec<FollowUpAttack>) -> AttackCard {
+            AttackCard {
+                title: Title::new(title),
+                effect: Effect::Incident(
+                    EffectDescription::new("Attack"),
+                    vec![Target::new("network")],
+                    IncidentImpact::Fixed(Resources::new(1)),
+                ),
+                duration: Duration::new(Some(5)),
+                follow_ups,
+                ..FakeAttackCard.fake()
+            }
+        }
+
+        /*
+        Creates a game with an Oopsie, an attack with a chain of follow-ups and two event cards.
+        phishing -> credential theft (next round) -> ransomware (immediately)
+         */
+        fn create_game() -> Game {
+            let oopsie_card = OopsieCard {
+                effect: Effect::AttackSurface(
+                    EffectDescription::new("Attack surface"),
+                    vec![Target::new("network")],
+                ),
+                ..FakeOopsieCard.fake()
+            };
+            let ransomware = create_attack("ransomware", vec![]);
+            let credential_theft = create_attack(
+                "credential theft",
+                vec![FollowUpAttack::new(ransomware, FollowUpTiming::Immediately)],
+            );
+            let phishing = create_attack(
+                "phishing",
+                vec![FollowUpAttack::new(credential_theft, FollowUpTiming::InRounds(1))],
+            );
+
+            let deck = Deck::new(vec![
+                Rc::new(Card::from(oopsie_card)),
+                Rc::new(Card::from(phishing)),
+                Rc::new(Card::from(FakeEventCard.fake::<EventCard>())),
+                Rc::new(Card::from(FakeEventCard.fake::<EventCard>())),
+            ]);
+
+            Game::create(deck, GameInitSettings::default())
+        }
+
+        fn open_card_titles(board: &Board) -> Vec<String> {
+            let mut titles: Vec<String> = board
+                .open_cards
+                .values()
+                .map(|card| card.title().value().to_string())
+                .collect();
+            titles.sort();
+            titles
+        }
+
+        #[test]
+        fn follow_ups_are_drawn_and_placed_when_incidents_happen() {
+            let attack_drawn = create_game().next_round().next_round();
+            let board_with_attack = get_board_from_game(&attack_drawn);
+            assert_eq!(board_with_attack.active_incidents.len(), 1);
+            assert_eq!(board_with_attack.turns_remaining, 3, "follow-up is added to the deck");
+
+            let follow_up_drawn = attack_drawn.next_round();
+            let board_with_follow_ups = get_board_from_game(&follow_up_drawn);
+            let titles = open_card_titles(&board_with_follow_ups);
+            assert!(titles.contains(&"credential theft".to_string()));
+            assert!(titles.contains(&"ransomware".to_string()));
+            assert_eq!(board_with_follow_ups.active_incidents.len(), 3);
+        }
+    }
+
     fn get_board_from_game(game: &Game) -> Board {
         match &game.status {
             GameStatus::InProgress(b) | GameStatus::Start(b) | GameStatus::Finished(b) => b.clone(),
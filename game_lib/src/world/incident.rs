@@ -0,0 +1,180 @@
+use uuid::Uuid;
+
+use crate::world::part_of_hundred::PartOfHundred;
+use crate::world::resources::Resources;
+
+/*
+An incident happens when an attack hits an attack surface opened by an oopsie, e.g. both share a
+target. Incidents are tracked on the board over their whole lifecycle: when they started, how long they
+lasted, what they cost and how they were resolved. Like the board, an incident is never mutated in
+place, every update returns a new incident.
+ */
+#[derive(Debug, Clone, PartialEq, PartialOrd, Ord, Eq)]
+pub struct Incident {
+    pub attack_card_id: Uuid,
+    pub attack_title: String,
+    pub oopsie_card_id: Uuid,
+    pub oopsie_title: String,
+    pub started_in_round: usize,
+    pub ended_in_round: Option<usize>,
+    pub accrued_cost: Resources,
+    pub resolution: Option<IncidentResolution>,
+    /// Part of the costs a bought control takes off this incident.
+    pub mitigation: Option<PartOfHundred>,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, PartialOrd, Ord, Eq)]
+pub enum IncidentResolution {
+    /// The attack ran out of rounds.
+    AttackExpired,
+    /// The oopsie was fixed and the attack surface is closed.
+    OopsieFixed,
+    /// The attack was closed manually before it ran out of rounds.
+    ForceClosed,
+}
+
+impl Incident {
+    pub fn start(
+        attack_card_id: Uuid,
+        attack_title: &str,
+        oopsie_card_id: Uuid,
+        oopsie_title: &str,
+        round: usize,
+        initial_cost: Resources,
+    ) -> Self {
+        Incident {
+            attack_card_id,
+            attack_title: attack_title.to_string(),
+            oopsie_card_id,
+            oopsie_title: oopsie_title.to_string(),
+            started_in_round: round,
+            ended_in_round: None,
+            accrued_cost: initial_cost,
+            resolution: None,
+            mitigation: None,
+        }
+    }
+
+    /// Marks the incident as mitigated by a control, the accrued costs are reduced accordingly.
+    pub fn with_mitigation(&self, reduction: PartOfHundred) -> Self {
+        Incident {
+            accrued_cost: Self::reduce(&self.accrued_cost, &reduction),
+            mitigation: Some(reduction),
+            ..self.clone()
+        }
+    }
+
+    /// Returns the part of the costs which is left after the mitigation of the incident.
+    pub fn mitigate(&self, cost: &Resources) -> Resources {
+        match &self.mitigation {
+            Some(reduction) => Self::reduce(cost, reduction),
+            None => *cost,
+        }
+    }
+
+    /// Returns true if the incident involves the card with the given id, either as attack or as oopsie.
+    pub fn involves(&self, card_id: &Uuid) -> bool {
+        &self.attack_card_id == card_id || &self.oopsie_card_id == card_id
+    }
+
+    /// Returns true if the incident is caused by the given attack and oopsie card.
+    pub fn is_caused_by(&self, attack_card_id: &Uuid, oopsie_card_id: &Uuid) -> bool {
+        &self.attack_card_id == attack_card_id && &self.oopsie_card_id == oopsie_card_id
+    }
+
+    pub fn is_resolved(&self) -> bool {
+        self.resolution.is_some()
+    }
+
+    /// Number of rounds the incident has been going on. An incident started in the current round
+    /// lasted for one round.
+    pub fn duration(&self, current_round: usize) -> usize {
+        let last_round = self.ended_in_round.unwrap_or(current_round);
+        last_round.saturating_sub(self.started_in_round) + 1
+    }
+
+    pub fn accrue(&self, cost: &Resources) -> Self {
+        Incident {
+            accrued_cost: &self.accrued_cost + cost,
+            ..self.clone()
+        }
+    }
+
+    pub fn resolve(&self, round: usize, resolution: IncidentResolution) -> Self {
+        Incident {
+            ended_in_round: Some(round),
+            resolution: Some(resolution),
+            ..self.clone()
+        }
+    }
+
+    fn reduce(cost: &Resources, reduction: &PartOfHundred) -> Resources {
+        *cost - reduction.of(cost)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn create_incident(round: usize) -> Incident {
+        Incident::start(
+            Uuid::new_v4(),
+            "attack",
+            Uuid::new_v4(),
+            "oopsie",
+            round,
+            Resources::new(5),
+        )
+    }
+
+    #[test]
+    fn started_incident_is_not_resolved() {
+        let incident = create_incident(3);
+
+        assert!(!incident.is_resolved());
+        assert_eq!(incident.ended_in_round, None);
+        assert_eq!(incident.accrued_cost, Resources::new(5));
+    }
+
+    #[test]
+    fn duration_of_active_incident_counts_current_round() {
+        let incident = create_incident(3);
+
+        assert_eq!(incident.duration(3), 1);
+        assert_eq!(incident.duration(5), 3);
+    }
+
+    #[test]
+    fn duration_of_resolved_incident_stops_at_resolution() {
+        let incident = create_incident(3).resolve(4, IncidentResolution::OopsieFixed);
+
+        assert_eq!(incident.duration(10), 2);
+        assert_eq!(incident.resolution, Some(IncidentResolution::OopsieFixed));
+        assert!(incident.is_resolved());
+    }
+
+    #[test]
+    fn accrue_adds_to_accrued_cost() {
+        let incident = create_incident(1).accrue(&Resources::new(3));
+
+        assert_eq!(incident.accrued_cost, Resources::new(8));
+    }
+
+    #[test]
+    fn mitigated_incident_costs_less() {
+        let incident = create_incident(1).with_mitigation(PartOfHundred::new(40));
+
+        assert_eq!(incident.accrued_cost, Resources::new(3));
+        assert_eq!(incident.mitigate(&Resources::new(10)), Resources::new(6));
+    }
+
+    #[test]
+    fn involves_attack_and_oopsie_card() {
+        let incident = create_incident(1);
+
+        assert!(incident.involves(&incident.attack_card_id));
+        assert!(incident.involves(&incident.oopsie_card_id));
+        assert!(!incident.involves(&Uuid::new_v4()));
+    }
+}
@@ -0,0 +1,157 @@
+use std::collections::HashMap;
+
+use rand::seq::SliceRandom;
+use rand::thread_rng;
+use uuid::Uuid;
+
+use crate::cards::types::card_model::Card;
+use crate::world::deck::{CardRc, DeckRepository};
+use crate::world::resources::Resources;
+
+/*
+The market offers cards which can be bought with resources in addition to the cards drawn from the deck.
+Every round a new offer is drawn from the pool of purchasable cards of the card repository. Only cards
+with a price can be bought: controls cost their `cost`, lucky cards their optional `price`. Like the
+board, the market is never mutated in place.
+ */
+#[derive(Debug, Clone, PartialEq)]
+pub struct MarketOffer {
+    pub card: CardRc,
+    pub price: Resources,
+}
+
+#[derive(Debug, Clone, PartialEq, Default)]
+pub struct Market {
+    pool: Vec<CardRc>,
+    pub offers: HashMap<Uuid, MarketOffer>,
+    /// Number of cards offered each round, 0 disables the market.
+    pub size: usize,
+}
+
+impl Market {
+    /// Creates a market without offers. Cards of the pool without a price are ignored.
+    pub fn new(pool: Vec<CardRc>, size: usize) -> Self {
+        Market {
+            pool: pool
+                .into_iter()
+                .filter(|card| price_of(card).is_some())
+                .collect(),
+            offers: HashMap::new(),
+            size,
+        }
+    }
+
+    /// Replaces the current offers with a random selection of the pool.
+    pub(crate) fn refresh(&self) -> Self {
+        let offers = self
+            .pool
+            .choose_multiple(&mut thread_rng(), self.size)
+            .filter_map(|card| {
+                price_of(card).map(|price| {
+                    (
+                        Uuid::new_v4(),
+                        MarketOffer {
+                            card: card.clone(),
+                            price,
+                        },
+                    )
+                })
+            })
+            .collect();
+        Market {
+            offers,
+            ..self.clone()
+        }
+    }
+
+    /// Removes an offer from the market, e.g. because it was bought.
+    pub(crate) fn take_offer(&self, offer_id: &Uuid) -> Option<(Market, MarketOffer)> {
+        let offer = self.offers.get(offer_id)?.clone();
+        let mut offers = self.offers.clone();
+        offers.remove(offer_id);
+        Some((
+            Market {
+                offers,
+                ..self.clone()
+            },
+            offer,
+        ))
+    }
+}
+
+/// Price of a card in the market, `None` if the card can not be bought.
+pub fn price_of(card: &Card) -> Option<Resources> {
+    match card {
+        Card::Control(control) => Some(control.cost),
+        Card::Lucky(lucky) => lucky.price,
+        Card::Event(_) | Card::Attack(_) | Card::Oopsie(_) | Card::Evaluation(_) => None,
+    }
+}
+
+/// Collects all cards of the repository which can be bought in the market.
+pub fn create_market_pool<T: DeckRepository>(access: &T) -> Vec<CardRc> {
+    access
+        .get_control_cards()
+        .into_iter()
+        .chain(access.get_lucky_cards())
+        .filter(|card| price_of(card).is_some())
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use std::rc::Rc;
+
+    use fake::Fake;
+
+    use crate::cards::types::control::tests::FakeControlCard;
+    use crate::cards::types::control::ControlCard;
+    use crate::cards::types::event::tests::FakeEventCard;
+    use crate::cards::types::event::EventCard;
+    use crate::cards::types::lucky::tests::FakeLuckyCard;
+    use crate::cards::types::lucky::LuckyCard;
+
+    use super::*;
+
+    fn priced_lucky_card(price: Option<usize>) -> CardRc {
+        Rc::new(Card::from(LuckyCard {
+            price: price.map(Resources::new),
+            ..FakeLuckyCard.fake()
+        }))
+    }
+
+    #[test]
+    fn market_ignores_cards_without_price() {
+        let pool = vec![
+            priced_lucky_card(Some(3)),
+            priced_lucky_card(None),
+            Rc::new(Card::from(FakeEventCard.fake::<EventCard>())),
+            Rc::new(Card::from(FakeControlCard.fake::<ControlCard>())),
+        ];
+
+        let market = Market::new(pool, 10).refresh();
+
+        assert_eq!(market.offers.len(), 2);
+    }
+
+    #[test]
+    fn refresh_offers_at_most_size_cards() {
+        let pool = (1..6).map(|price| priced_lucky_card(Some(price))).collect();
+
+        let market = Market::new(pool, 3).refresh();
+
+        assert_eq!(market.offers.len(), 3);
+    }
+
+    #[test]
+    fn take_offer_removes_offer_from_market() {
+        let market = Market::new(vec![priced_lucky_card(Some(4))], 1).refresh();
+        let offer_id = *market.offers.keys().next().unwrap();
+
+        let (new_market, offer) = market.take_offer(&offer_id).unwrap();
+
+        assert_eq!(offer.price, Resources::new(4));
+        assert!(new_market.offers.is_empty());
+        assert!(new_market.take_offer(&offer_id).is_none());
+    }
+}
@@ -1,8 +1,15 @@
 pub mod board;
+pub mod card_query;
 pub mod deck;
+pub mod fix_progress;
+pub mod incident;
+pub mod market;
 pub mod resources;
 pub mod reputation;
+pub mod reputation_income;
+pub mod objective;
 pub mod result;
+pub mod target_coverage;
 
 mod actions;
 pub mod game;
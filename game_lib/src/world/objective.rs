@@ -0,0 +1,287 @@
+use std::fmt::{Display, Formatter};
+
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+
+use crate::cards::properties::effect::Effect;
+use crate::cards::properties::target::Target;
+use crate::cards::properties::target_taxonomy::TargetTaxonomy;
+use crate::cards::types::card_model::{Card, CardTrait};
+use crate::world::board::Board;
+use crate::world::reputation::Reputation;
+
+/// Additional goals of a scenario next to the minimum resources and reputation at the end of the game.
+#[derive(Serialize, Deserialize, JsonSchema, Debug, Clone, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub enum Objective {
+    /// No more than the given number of incidents happen during the whole game.
+    MaxIncidents(usize),
+    /// Every oopsie with the given target is fixed at the end of the game.
+    FixAllOopsiesWithTarget(Target),
+    /// The reputation stays above the given value for a number of rounds in a row.
+    #[serde(rename_all = "camelCase")]
+    ReputationAbove { reputation: Reputation, rounds: usize },
+    /// At least the given number of oopsies are fixed.
+    FixOopsies(usize),
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum ObjectiveState {
+    Open,
+    Achieved,
+    Failed,
+}
+
+/// State of an objective together with a short text about how far the players got.
+#[derive(Debug, Clone, PartialEq)]
+pub struct ObjectiveProgress {
+    pub state: ObjectiveState,
+    pub progress: String,
+}
+
+impl ObjectiveProgress {
+    fn new(state: ObjectiveState, progress: String) -> Self {
+        ObjectiveProgress { state, progress }
+    }
+}
+
+impl Objective {
+    /// Evaluates the objective on the board. While the game is running only objectives which can not
+    /// change anymore are achieved or failed, at the end of the game every objective is decided.
+    pub fn evaluate(
+        &self,
+        board: &Board,
+        taxonomy: &TargetTaxonomy,
+        finished: bool,
+    ) -> ObjectiveProgress {
+        match self {
+            Objective::MaxIncidents(max) => {
+                let incidents = board.active_incidents.len() + board.resolved_incidents.len();
+                let state = if incidents > *max {
+                    ObjectiveState::Failed
+                } else if finished {
+                    ObjectiveState::Achieved
+                } else {
+                    ObjectiveState::Open
+                };
+                ObjectiveProgress::new(state, format!("{} of max. {} incidents", incidents, max))
+            }
+            Objective::FixAllOopsiesWithTarget(target) => {
+                let open = open_oopsies_with_target(board, taxonomy, target);
+                let state = match (finished, open) {
+                    (false, _) => ObjectiveState::Open,
+                    (true, 0) => ObjectiveState::Achieved,
+                    (true, _) => ObjectiveState::Failed,
+                };
+                ObjectiveProgress::new(state, format!("{} still open", open))
+            }
+            Objective::ReputationAbove { reputation, rounds } => {
+                let (best, current) = reputation_streaks(board, reputation, finished);
+                let state = if best >= *rounds {
+                    ObjectiveState::Achieved
+                } else if finished || current + board.turns_remaining < *rounds {
+                    ObjectiveState::Failed
+                } else {
+                    ObjectiveState::Open
+                };
+                ObjectiveProgress::new(state, format!("{} of {} rounds", best.min(*rounds), rounds))
+            }
+            Objective::FixOopsies(count) => {
+                let state = if board.fixed_oopsies >= *count {
+                    ObjectiveState::Achieved
+                } else if finished {
+                    ObjectiveState::Failed
+                } else {
+                    ObjectiveState::Open
+                };
+                ObjectiveProgress::new(state, format!("{} of {} fixed", board.fixed_oopsies, count))
+            }
+        }
+    }
+}
+
+fn open_oopsies_with_target(board: &Board, taxonomy: &TargetTaxonomy, target: &Target) -> usize {
+    board
+        .open_cards
+        .values()
+        .filter(|card| matches!(***card, Card::Oopsie(_)))
+        .filter(|card| match card.effect() {
+            Effect::AttackSurface(_, targets) => {
+                taxonomy.any_matches(targets, std::slice::from_ref(target))
+            }
+            _ => false,
+        })
+        .count()
+}
+
+/// The longest and the current streak of rounds with a reputation above the given value. The current
+/// round counts only once the game is finished.
+fn reputation_streaks(board: &Board, reputation: &Reputation, finished: bool) -> (usize, usize) {
+    let last_round = if finished {
+        Some(board.current_reputation)
+    } else {
+        None
+    };
+    board
+        .reputation_history
+        .iter()
+        .chain(last_round.iter())
+        .fold((0, 0), |(best, current), value| {
+            if value > reputation {
+                (best.max(current + 1), current + 1)
+            } else {
+                (best, 0)
+            }
+        })
+}
+
+impl Display for Objective {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Objective::MaxIncidents(max) => write!(f, "No more than {} incidents", max),
+            Objective::FixAllOopsiesWithTarget(target) => {
+                write!(f, "Fix all oopsies targeting {}", target.value())
+            }
+            Objective::ReputationAbove { reputation, rounds } => write!(
+                f,
+                "Reputation above {} for {} rounds",
+                reputation.value(),
+                rounds
+            ),
+            Objective::FixOopsies(count) => write!(f, "Fix at least {} oopsies", count),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::HashMap;
+
+    use rstest::rstest;
+    use uuid::Uuid;
+
+    use crate::cards::types::oopsie::tests::oopsie_targeting;
+    use crate::world::incident::Incident;
+    use crate::world::resources::Resources;
+
+    use super::*;
+
+    fn incident() -> Incident {
+        Incident::start(Uuid::new_v4(), "a", Uuid::new_v4(), "o", 1, Resources::new(1))
+    }
+
+    #[rstest]
+    #[case::below_limit(1, false, ObjectiveState::Open)]
+    #[case::above_limit(3, false, ObjectiveState::Failed)]
+    #[case::finished_below_limit(2, true, ObjectiveState::Achieved)]
+    fn max_incidents_counts_active_and_resolved_incidents(
+        #[case] resolved: usize,
+        #[case] finished: bool,
+        #[case] expected: ObjectiveState,
+    ) {
+        let board = Board {
+            active_incidents: vec![incident()],
+            resolved_incidents: (0..resolved).map(|_| incident()).collect(),
+            ..Board::empty()
+        };
+
+        let progress =
+            Objective::MaxIncidents(3).evaluate(&board, &TargetTaxonomy::default(), finished);
+
+        assert_eq!(progress.state, expected);
+    }
+
+    #[rstest]
+    #[case::running("database", false, ObjectiveState::Open)]
+    #[case::open_oopsie_with_target("database", true, ObjectiveState::Failed)]
+    #[case::only_other_targets_open("frontend", true, ObjectiveState::Achieved)]
+    fn fix_all_oopsies_with_target_checks_open_oopsies(
+        #[case] target: &str,
+        #[case] finished: bool,
+        #[case] expected: ObjectiveState,
+    ) {
+        let board = Board {
+            open_cards: HashMap::from([(Uuid::new_v4(), oopsie_targeting("database"))]),
+            ..Board::empty()
+        };
+
+        let progress = Objective::FixAllOopsiesWithTarget(Target::new(target)).evaluate(
+            &board,
+            &TargetTaxonomy::default(),
+            finished,
+        );
+
+        assert_eq!(progress.state, expected);
+    }
+
+    #[rstest]
+    #[case::streak_reached(vec![60, 60, 60], 1, ObjectiveState::Achieved)]
+    #[case::streak_still_possible(vec![60, 40, 60], 2, ObjectiveState::Open)]
+    #[case::streak_not_possible_anymore(vec![60, 40, 60], 1, ObjectiveState::Failed)]
+    fn reputation_above_needs_rounds_in_a_row(
+        #[case] history: Vec<u8>,
+        #[case] turns_remaining: usize,
+        #[case] expected: ObjectiveState,
+    ) {
+        let board = Board {
+            reputation_history: history.into_iter().map(Reputation::new).collect(),
+            turns_remaining,
+            ..Board::empty()
+        };
+        let objective = Objective::ReputationAbove {
+            reputation: Reputation::new(50),
+            rounds: 3,
+        };
+
+        let progress = objective.evaluate(&board, &TargetTaxonomy::default(), false);
+
+        assert_eq!(progress.state, expected);
+    }
+
+    #[rstest]
+    #[case::enough_fixed(2, false, ObjectiveState::Achieved)]
+    #[case::running(1, false, ObjectiveState::Open)]
+    #[case::finished_with_too_few(1, true, ObjectiveState::Failed)]
+    fn fix_oopsies_counts_fixed_oopsies(
+        #[case] fixed_oopsies: usize,
+        #[case] finished: bool,
+        #[case] expected: ObjectiveState,
+    ) {
+        let board = Board {
+            fixed_oopsies,
+            ..Board::empty()
+        };
+
+        let progress =
+            Objective::FixOopsies(2).evaluate(&board, &TargetTaxonomy::default(), finished);
+
+        assert_eq!(progress.state, expected);
+        assert_eq!(progress.progress, format!("{} of 2 fixed", fixed_oopsies));
+    }
+
+    #[test]
+    fn deserializes_objectives() {
+        let json = r#"
+        [
+            { "maxIncidents": 3 },
+            { "fixAllOopsiesWithTarget": "database" },
+            { "reputationAbove": { "reputation": 40, "rounds": 5 } },
+            { "fixOopsies": 2 }
+        ]"#;
+
+        let objectives: Vec<Objective> = serde_json::from_str(json).unwrap();
+
+        assert_eq!(
+            objectives,
+            vec![
+                Objective::MaxIncidents(3),
+                Objective::FixAllOopsiesWithTarget(Target::new("database")),
+                Objective::ReputationAbove {
+                    reputation: Reputation::new(40),
+                    rounds: 5
+                },
+                Objective::FixOopsies(2),
+            ]
+        );
+    }
+}
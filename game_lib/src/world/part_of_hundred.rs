@@ -1,8 +1,11 @@
+use std::borrow::Cow;
 use std::fmt::Display;
+use schemars::{json_schema, JsonSchema, Schema, SchemaGenerator};
 use serde::{Deserialize, Deserializer, Serialize, Serializer};
 use crate::cards::serialization::helper::{Number, NumberVisitor};
+use crate::world::resources::Resources;
 
-#[derive(Clone, Debug, PartialEq)]
+#[derive(Clone, Debug, PartialEq, Eq, PartialOrd, Ord)]
 pub struct PartOfHundred {
     pub value: u8,
 }
@@ -21,22 +24,29 @@ impl PartOfHundred {
         PartOfHundred { value }
     }
 
-    fn guard(value: i64) {
+    /// Calculates this part of the given resources, rounding down.
+    pub fn of(&self, resources: &Resources) -> Resources {
+        Resources::new(resources.value() * self.value as usize / 100)
+    }
+
+    fn guard(value: i64) -> Result<Self, String> {
         if (value > 100) || (value < 0) {
-            panic!("Value must be between 0 and 100");
+            return Err(format!("value must be between 0 and 100, was {}", value));
         }
+        Ok(PartOfHundred::new(value as u8))
     }
 }
 
 impl Number for PartOfHundred {
-    fn from_i64(value: i64) -> Self {
-        Self::guard(value);
-        PartOfHundred::new(value as u8)
+    fn from_i64(value: i64) -> Result<Self, String> {
+        Self::guard(value)
     }
 
-    fn from_u64(value: u64) -> Self {
-        Self::guard(value as i64);
-        PartOfHundred::new(value as u8)
+    fn from_u64(value: u64) -> Result<Self, String> {
+        match i64::try_from(value) {
+            Ok(value) => Self::guard(value),
+            Err(_) => Err(format!("value must be between 0 and 100, was {}", value)),
+        }
     }
 }
 
@@ -49,6 +59,20 @@ impl<'de> Deserialize<'de> for PartOfHundred {
     }
 }
 
+impl JsonSchema for PartOfHundred {
+    fn schema_name() -> Cow<'static, str> {
+        "PartOfHundred".into()
+    }
+
+    fn json_schema(_: &mut SchemaGenerator) -> Schema {
+        json_schema!({
+            "type": "integer",
+            "minimum": 0,
+            "maximum": 100
+        })
+    }
+}
+
 impl Serialize for PartOfHundred {
     fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
     where
@@ -99,6 +123,34 @@ mod tests {
         sut.value == value.prop
     }
 
+    #[test]
+    fn part_of_resources_rounds_down() {
+        let sut = PartOfHundred::new(25);
+        assert_eq!(sut.of(&Resources::new(10)), Resources::new(2));
+    }
+
+    #[test]
+    fn deserializing_values_out_of_range_returns_error() {
+        let too_high = serde_json::from_str::<PartOfHundred>("120");
+        let negative = serde_json::from_str::<PartOfHundred>("-1");
+
+        assert!(too_high
+            .unwrap_err()
+            .to_string()
+            .contains("value must be between 0 and 100, was 120"));
+        assert!(negative.is_err());
+    }
+
+    #[test]
+    fn deserializing_values_above_i64_returns_error() {
+        let result = serde_json::from_str::<PartOfHundred>("18446744073709551615");
+
+        assert!(result
+            .unwrap_err()
+            .to_string()
+            .contains("was 18446744073709551615"));
+    }
+
     #[quickcheck]
     fn create_part_of_hundred_with_values_grater_than_100_fails(value: InvalidValues) -> bool {
         let result = panic::catch_unwind(|| {
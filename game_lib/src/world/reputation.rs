@@ -1,5 +1,7 @@
+use std::borrow::Cow;
 use std::fmt::Display;
 use std::ops::{Add, Sub};
+use schemars::{json_schema, JsonSchema, Schema, SchemaGenerator};
 use serde::{Deserialize, Deserializer, Serialize, Serializer};
 use crate::cards::serialization::helper::Number;
 
@@ -65,13 +67,30 @@ impl<'de> Deserialize<'de> for Reputation {
     }
 }
 
+impl JsonSchema for Reputation {
+    fn schema_name() -> Cow<'static, str> {
+        "Reputation".into()
+    }
+
+    fn json_schema(_: &mut SchemaGenerator) -> Schema {
+        json_schema!({
+            "type": "integer",
+            "minimum": 0,
+            "maximum": MAX_VALUE
+        })
+    }
+}
+
 impl Number for Reputation {
-    fn from_i64(_value: i64) -> Self {
-        panic!("Must be positive value")
+    fn from_i64(value: i64) -> Result<Self, String> {
+        Err(format!("reputation must not be negative, was {}", value))
     }
 
-    fn from_u64(value: u64) -> Self {
-        Reputation::new(value as u8)
+    fn from_u64(value: u64) -> Result<Self, String> {
+        if value > MAX_VALUE as u64 {
+            return Err(format!("reputation must be between 0 and {}, was {}", MAX_VALUE, value));
+        }
+        Ok(Reputation::new(value as u8))
     }
 }
 
@@ -99,6 +118,13 @@ mod tests {
         assert_eq!(rep.value(), &100);
     }
 
+    #[test]
+    pub fn deserialize_rejects_values_out_of_range() {
+        assert_eq!(serde_json::from_str::<Reputation>("100").unwrap(), Reputation::new(100));
+        assert!(serde_json::from_str::<Reputation>("300").is_err());
+        assert!(serde_json::from_str::<Reputation>("-5").is_err());
+    }
+
     #[test]
     pub fn add_10_to_20_should_return_30() {
         let input = Reputation::new(20);
@@ -0,0 +1,101 @@
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+
+use crate::world::part_of_hundred::PartOfHundred;
+use crate::world::reputation::Reputation;
+use crate::world::resources::Resources;
+
+/*
+Optional rule which scales the resource gain by the current reputation. A company with a bad reputation
+loses customers and therefore income. Every band applies below a reputation, the band with the lowest
+limit matching the current reputation wins. Without matching band the full resource gain is paid out.
+ */
+#[derive(Serialize, Deserialize, JsonSchema, Debug, Clone, PartialEq, Default)]
+#[serde(transparent)]
+pub struct ReputationIncome {
+    pub bands: Vec<IncomeBand>,
+}
+
+/// Below the given reputation only `income` percent of the resource gain are paid out.
+#[derive(Serialize, Deserialize, JsonSchema, Debug, Clone, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub struct IncomeBand {
+    pub below: Reputation,
+    pub income: PartOfHundred,
+}
+
+impl IncomeBand {
+    pub fn new(below: u8, income: u8) -> Self {
+        IncomeBand {
+            below: Reputation::new(below),
+            income: PartOfHundred::new(income),
+        }
+    }
+}
+
+impl ReputationIncome {
+    pub fn new(bands: Vec<IncomeBand>) -> Self {
+        ReputationIncome { bands }
+    }
+
+    pub fn is_disabled(&self) -> bool {
+        self.bands.is_empty()
+    }
+
+    /// The band which applies for the given reputation, `None` if the full gain is paid out.
+    pub fn band_for(&self, reputation: &Reputation) -> Option<&IncomeBand> {
+        self.bands
+            .iter()
+            .filter(|band| reputation < &band.below)
+            .min_by_key(|band| *band.below.value())
+    }
+
+    /// Scales the resource gain according to the band of the given reputation.
+    pub fn scale(&self, resource_gain: &Resources, reputation: &Reputation) -> Resources {
+        match self.band_for(reputation) {
+            Some(band) => band.income.of(resource_gain),
+            None => *resource_gain,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use rstest::rstest;
+
+    use super::*;
+
+    fn income_bands() -> ReputationIncome {
+        ReputationIncome::new(vec![IncomeBand::new(30, 80), IncomeBand::new(10, 50)])
+    }
+
+    #[rstest]
+    #[case::good_reputation(50, 10)]
+    #[case::at_band_limit(30, 10)]
+    #[case::bad_reputation(29, 8)]
+    #[case::lowest_band_wins(5, 5)]
+    fn scale_applies_band_of_reputation(#[case] reputation: u8, #[case] expected: usize) {
+        let income = income_bands().scale(&Resources::new(10), &Reputation::new(reputation));
+
+        assert_eq!(income, Resources::new(expected));
+    }
+
+    #[test]
+    fn without_bands_full_gain_is_paid_out() {
+        let income = ReputationIncome::default();
+
+        assert!(income.is_disabled());
+        assert_eq!(
+            income.scale(&Resources::new(10), &Reputation::new(0)),
+            Resources::new(10)
+        );
+    }
+
+    #[test]
+    fn deserializes_list_of_bands() {
+        let income: ReputationIncome =
+            serde_json::from_str(r#"[{ "below": 30, "income": 80 }]"#).unwrap();
+
+        assert_eq!(income, ReputationIncome::new(vec![IncomeBand::new(30, 80)]));
+    }
+}
@@ -1,5 +1,7 @@
+use std::borrow::Cow;
 use crate::cards::serialization::helper::Number;
 use log::warn;
+use schemars::{json_schema, JsonSchema, Schema, SchemaGenerator};
 use serde::{Deserialize, Deserializer, Serialize, Serializer};
 
 #[derive(Clone, Debug, PartialOrd, PartialEq, Copy)]
@@ -37,13 +39,29 @@ impl<'de> Deserialize<'de> for ResourceFixMultiplier {
     }
 }
 
+impl JsonSchema for ResourceFixMultiplier {
+    fn schema_name() -> Cow<'static, str> {
+        "ResourceFixMultiplier".into()
+    }
+
+    fn json_schema(_: &mut SchemaGenerator) -> Schema {
+        json_schema!({
+            "type": "integer",
+            "minimum": 1
+        })
+    }
+}
+
 impl Number for ResourceFixMultiplier {
-    fn from_i64(_value: i64) -> Self {
-        panic!("Must be positive value")
+    fn from_i64(value: i64) -> Result<Self, String> {
+        Err(format!("multiplier must not be negative, was {}", value))
     }
 
-    fn from_u64(value: u64) -> Self {
-        ResourceFixMultiplier::new(value as usize)
+    fn from_u64(value: u64) -> Result<Self, String> {
+        if value < 1 {
+            return Err(format!("multiplier must be at least 1, was {}", value));
+        }
+        Ok(ResourceFixMultiplier(value as usize))
     }
 }
 
@@ -52,3 +70,18 @@ impl Default for ResourceFixMultiplier {
         ResourceFixMultiplier(1)
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    pub fn deserialize_rejects_values_below_1() {
+        assert_eq!(serde_json::from_str::<ResourceFixMultiplier>("2").unwrap(), ResourceFixMultiplier(2));
+        assert_eq!(
+            serde_json::from_str::<ResourceFixMultiplier>("0").unwrap_err().to_string(),
+            "multiplier must be at least 1, was 0 at line 1 column 1"
+        );
+        assert!(serde_json::from_str::<ResourceFixMultiplier>("-1").is_err());
+    }
+}
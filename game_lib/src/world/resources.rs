@@ -1,12 +1,14 @@
+use std::borrow::Cow;
 use std::fmt::{Display, Formatter};
 use std::ops::{Add, Mul, Sub};
 
+use schemars::{json_schema, JsonSchema, Schema, SchemaGenerator};
 use serde::{Deserialize, Deserializer, Serialize, Serializer};
 
 use crate::cards::serialization::helper::Number;
 use crate::world::resource_fix_multiplier::ResourceFixMultiplier;
 
-#[derive(Clone, Debug, PartialOrd, PartialEq, Default, Copy)]
+#[derive(Clone, Debug, PartialOrd, Ord, PartialEq, Eq, Default, Copy)]
 pub struct Resources(usize);
 
 impl Resources {
@@ -76,13 +78,26 @@ impl<'de> Deserialize<'de> for Resources {
     }
 }
 
+impl JsonSchema for Resources {
+    fn schema_name() -> Cow<'static, str> {
+        "Resources".into()
+    }
+
+    fn json_schema(_: &mut SchemaGenerator) -> Schema {
+        json_schema!({
+            "type": "integer",
+            "minimum": 0
+        })
+    }
+}
+
 impl Number for Resources {
-    fn from_i64(_value: i64) -> Self {
-        panic!("Must be positive value")
+    fn from_i64(value: i64) -> Result<Self, String> {
+        Err(format!("resources must not be negative, was {}", value))
     }
 
-    fn from_u64(value: u64) -> Self {
-        Resources::new(value as usize)
+    fn from_u64(value: u64) -> Result<Self, String> {
+        Ok(Resources::new(value as usize))
     }
 }
 
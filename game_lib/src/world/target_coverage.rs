@@ -0,0 +1,102 @@
+use crate::cards::properties::target::Target;
+use crate::cards::properties::target_taxonomy::TargetTaxonomy;
+use crate::cards::types::card_model::{Card, CardTrait};
+use crate::world::deck::CardRc;
+
+/// Number of cards of each type which affect a target or a target it contains: attacks which cause
+/// incidents, oopsies which open an attack surface and controls which protect it.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct TargetCoverage {
+    pub target: Target,
+    pub attacks: usize,
+    pub oopsies: usize,
+    pub controls: usize,
+}
+
+impl TargetCoverage {
+    /// An attack on the target never causes an incident if no oopsie opens it.
+    pub fn has_gap(&self) -> bool {
+        self.attacks > 0 && self.oopsies == 0
+    }
+}
+
+/// Coverage of every target of the taxonomy and of every unknown target a card uses, sorted by target.
+pub fn target_coverage(cards: &[CardRc], taxonomy: &TargetTaxonomy) -> Vec<TargetCoverage> {
+    let mut targets = taxonomy.all_targets();
+    for card in cards {
+        targets.extend(taxonomy.unknown_targets(card.effect().targets()));
+    }
+    targets.sort();
+    targets.dedup();
+
+    targets
+        .into_iter()
+        .map(|target| {
+            let count = |matches_type: fn(&Card) -> bool| {
+                cards
+                    .iter()
+                    .filter(|card| matches_type(card))
+                    .filter(|card| {
+                        card.effect()
+                            .targets()
+                            .iter()
+                            .any(|t| taxonomy.matches(t, &target))
+                    })
+                    .count()
+            };
+            TargetCoverage {
+                attacks: count(|card| matches!(card, Card::Attack(_))),
+                oopsies: count(|card| matches!(card, Card::Oopsie(_))),
+                controls: count(|card| matches!(card, Card::Control(_))),
+                target,
+            }
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::BTreeMap;
+
+    use crate::cards::types::attack::tests::attack_targeting;
+    use crate::cards::types::oopsie::tests::oopsie_targeting;
+
+    use super::*;
+
+    fn coverage_of<'a>(coverage: &'a [TargetCoverage], target: &str) -> &'a TargetCoverage {
+        coverage
+            .iter()
+            .find(|c| c.target.value() == target)
+            .unwrap()
+    }
+
+    #[test]
+    fn counts_cards_on_the_target_and_its_group() {
+        let taxonomy = TargetTaxonomy::new(BTreeMap::from([(
+            Target::new("data"),
+            vec![Target::new("database"), Target::new("backup")],
+        )]));
+        let cards = vec![oopsie_targeting("database"), attack_targeting("data"), attack_targeting("backup")];
+
+        let coverage = target_coverage(&cards, &taxonomy);
+
+        assert_eq!(
+            coverage_of(&coverage, "data"),
+            &TargetCoverage {
+                target: Target::new("data"),
+                attacks: 2,
+                oopsies: 1,
+                controls: 0,
+            }
+        );
+        assert!(!coverage_of(&coverage, "database").has_gap());
+        assert!(coverage_of(&coverage, "backup").has_gap());
+    }
+
+    #[test]
+    fn lists_unknown_targets_of_cards() {
+        let coverage = target_coverage(&[oopsie_targeting("mainframe")], &TargetTaxonomy::default());
+
+        assert_eq!(coverage_of(&coverage, "mainframe").oopsies, 1);
+    }
+}
@@ -1,15 +1,23 @@
+use game_lib::cards::game_variants::card_selection::{CardSelection, SelectedCards};
 use game_lib::file::repository::DeckLoader;
 use game_lib::world::deck::{Deck, DeckComposition, DeckPreparation, PreparedDeck};
 
 use crate::config::config::Config;
 
 
-pub fn create_deck(deck_composition: &DeckComposition, grace_period: u8, config: &Config) -> Deck {
-
-    let prepared_deck = PreparedDeck::prepare(
-        deck_composition,
+pub fn create_deck(
+    deck_composition: &DeckComposition,
+    grace_period: u8,
+    card_selection: &CardSelection,
+    config: &Config,
+) -> Deck {
+    let access = SelectedCards::new(
         DeckLoader::create(config.game_path.as_str()),
+        card_selection.clone(),
     );
+    let required_cards = access.required_cards();
+
+    let prepared_deck = PreparedDeck::prepare(deck_composition, access).include(&required_cards);
 
     prepared_deck.shuffle(grace_period as usize)
-}
\ No newline at end of file
+}
@@ -1,9 +1,13 @@
 use super::{AppEvent, GameGoals, GameViewState, SecCardGameApp};
 use crate::init_view::state::InitViewState;
 use egui::Context;
+use game_lib::cards::game_variants::rule_set::RuleSet;
 use game_lib::cards::game_variants::scenario::Scenario;
-use game_lib::world::deck::Deck;
+use game_lib::cards::properties::target_taxonomy::TargetTaxonomy;
+use game_lib::file::repository::DeckLoader;
+use game_lib::world::deck::{CardRc, Deck, TargetTaxonomyRepository};
 use game_lib::world::game::{Game, GameInitSettings};
+use game_lib::world::market::create_market_pool;
 use game_setup::config::config::Config;
 use game_setup::creation::create::create_deck;
 use std::rc::Rc;
@@ -25,6 +29,9 @@ impl SecCardGameApp {
                 GameInitSettings::default(),
                 GameGoals::default(),
                 None,
+                RuleSet::default(),
+                DeckLoader::create(&config.game_path).get_target_taxonomy(),
+                create_market_pool(&DeckLoader::create(&config.game_path)),
             ),
             last_event: None,
             config,
@@ -41,8 +48,21 @@ impl SecCardGameApp {
         settings: GameInitSettings,
         goals: GameGoals,
         scenario: Option<Rc<Scenario>>,
+        rule_set: RuleSet,
+        target_taxonomy: TargetTaxonomy,
+        market_pool: Vec<CardRc>,
     ) -> Box<GameViewState> {
-        let game = Game::create(deck, settings);
+        let game = Game::create(deck, settings)
+            .set_target_taxonomy(target_taxonomy)
+            .set_market_pool(market_pool)
+            .set_rule_set(rule_set);
+        let game = match &scenario {
+            Some(s) => game
+                .set_reputation_income(s.reputation_income.clone())
+                .set_income_schedule(s.income_schedule.clone())
+                .set_scripted_events(s.scripted_events.clone()),
+            None => game,
+        };
         Box::new(GameViewState::new(game, goals, scenario.clone()))
     }
 }
@@ -64,12 +84,25 @@ impl SecCardGameApp {
         if let Some(app_event) = &self.last_event {
             match app_event {
                 AppEvent::StartGame(data) => {
-                    let deck = create_deck(&data.deck_composition, data.grace_rounds, &self.config);
+                    let card_selection = data
+                        .scenario
+                        .as_ref()
+                        .map(|s| s.cards.clone())
+                        .unwrap_or_default();
+                    let deck = create_deck(
+                        &data.deck_composition,
+                        data.grace_rounds,
+                        &card_selection,
+                        &self.config,
+                    );
                     self.active_view = Self::create_game_view_state(
                         deck,
                         data.game_init_settings,
                         data.game_goals,
                         data.scenario.clone(),
+                        data.rule_set.clone(),
+                        DeckLoader::create(&self.config.game_path).get_target_taxonomy(),
+                        create_market_pool(&DeckLoader::create(&self.config.game_path)),
                     );
                 }
                 AppEvent::NewGame => {
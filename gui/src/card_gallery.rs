@@ -0,0 +1,368 @@
+use std::rc::Rc;
+
+use game_lib::cards::game_variants::scenario::Scenario;
+use game_lib::cards::properties::target::Target;
+use game_lib::cards::properties::target_taxonomy::TargetTaxonomy;
+use game_lib::cards::properties::title::Title;
+use game_lib::cards::types::card_model::{Card, CardCategory, CardTrait};
+use game_lib::file::repository::StoredCard;
+use game_lib::world::deck::CardRc;
+use game_lib::world::target_coverage::{target_coverage, TargetCoverage};
+
+use crate::card_sheet::{card_to_html, escape};
+
+/*
+A static HTML site to browse the card repository: an overview, one page per card type with a filter
+for targets, a page with all scenarios and the target coverage matrix. The cards look like the
+printed cards of `card_sheet`. The pages only link to each other, so the site can be opened from disk
+or published e.g. with GitHub pages.
+ */
+
+const STYLE: &str = r#"body { font-family: sans-serif; margin: 1em 2em; }
+nav a { margin-right: 1em; }
+.cards { display: flex; flex-wrap: wrap; gap: 1em; }
+.entry { width: 63mm; }
+.entry[hidden] { display: none; }
+.entry .id { font-family: monospace; color: #606060; }
+.card { border: 0.5mm solid; box-sizing: border-box; min-height: 88mm; font-size: 2.8mm; }
+.card h1 { margin: 0; padding: 1mm 3mm; font-size: 4mm; }
+.card p, .card dl { margin: 3mm; }
+.card dt { font-weight: bold; float: left; width: 17mm; }
+.card dd { margin-left: 17mm; }
+table { border-collapse: collapse; }
+th, td { border: 1px solid #c0c0c0; padding: 0.3em 0.8em; text-align: left; }
+td.count { text-align: right; }
+tr.gap { background: #ffe0e0; }
+.scenario { border-top: 1px solid #c0c0c0; }
+"#;
+
+const FILTER_SCRIPT: &str = r#"<script>
+const filter = document.getElementById('target-filter');
+function applyFilter() {
+  document.querySelectorAll('.entry').forEach(entry => {
+    entry.hidden = filter.value !== '' && !entry.dataset.targets.split('|').includes(filter.value);
+  });
+}
+filter.addEventListener('change', applyFilter);
+filter.value = new URLSearchParams(window.location.search).get('target') || '';
+applyFilter();
+</script>"#;
+
+/// A page of the gallery, the file name is relative to the output directory.
+pub struct GalleryPage {
+    pub file_name: String,
+    pub content: String,
+}
+
+struct CategoryPage {
+    file_name: &'static str,
+    heading: &'static str,
+    category: CardCategory,
+}
+
+const CATEGORY_PAGES: [CategoryPage; 5] = [
+    CategoryPage {
+        file_name: "events.html",
+        heading: "Events",
+        category: Card::EVENT_CARD,
+    },
+    CategoryPage {
+        file_name: "attacks.html",
+        heading: "Attacks",
+        category: Card::ATTACK_CARD,
+    },
+    CategoryPage {
+        file_name: "oopsies.html",
+        heading: "Oopsies",
+        category: Card::OOPSIE_CARD,
+    },
+    CategoryPage {
+        file_name: "lucky.html",
+        heading: "Lucky cards",
+        category: Card::LUCKY_CARD,
+    },
+    CategoryPage {
+        file_name: "controls.html",
+        heading: "Controls",
+        category: Card::CONTROL_CARD,
+    },
+];
+
+/// Renders all pages of the gallery including the stylesheet.
+pub fn gallery_pages(
+    cards: &[StoredCard],
+    scenarios: &[Rc<Scenario>],
+    taxonomy: &TargetTaxonomy,
+) -> Vec<GalleryPage> {
+    let all_cards: Vec<CardRc> = cards.iter().map(|stored| stored.card.clone()).collect();
+    let coverage = target_coverage(&all_cards, taxonomy);
+    let targets: Vec<Target> = coverage.iter().map(|c| c.target.clone()).collect();
+
+    let mut pages = vec![
+        GalleryPage {
+            file_name: "style.css".to_string(),
+            content: STYLE.to_string(),
+        },
+        page("index.html", "Card gallery", index(cards, scenarios)),
+    ];
+    for category_page in &CATEGORY_PAGES {
+        let cards: Vec<&StoredCard> = cards
+            .iter()
+            .filter(|stored| stored.card.category() == &category_page.category)
+            .collect();
+        pages.push(page(
+            category_page.file_name,
+            category_page.heading,
+            category(&cards, &targets, taxonomy),
+        ));
+    }
+    pages.push(page(
+        "scenarios.html",
+        "Scenarios",
+        scenario_list(scenarios),
+    ));
+    pages.push(page(
+        "coverage.html",
+        "Target coverage",
+        coverage_matrix(&coverage),
+    ));
+    pages
+}
+
+fn page(file_name: &str, heading: &str, body: String) -> GalleryPage {
+    let links: Vec<String> = [("index.html", "Overview")]
+        .into_iter()
+        .chain(CATEGORY_PAGES.iter().map(|p| (p.file_name, p.heading)))
+        .chain([
+            ("scenarios.html", "Scenarios"),
+            ("coverage.html", "Target coverage"),
+        ])
+        .map(|(file, title)| format!("<a href=\"{}\">{}</a>", file, title))
+        .collect();
+    GalleryPage {
+        file_name: file_name.to_string(),
+        content: format!(
+            "<!DOCTYPE html>\n<html>\n<head>\n<meta charset=\"utf-8\">\n<title>{heading}</title>\n<link rel=\"stylesheet\" href=\"style.css\">\n</head>\n<body>\n<nav>{links}</nav>\n<h1>{heading}</h1>\n{body}\n</body>\n</html>\n",
+            heading = heading,
+            links = links.join(""),
+            body = body,
+        ),
+    }
+}
+
+fn index(cards: &[StoredCard], scenarios: &[Rc<Scenario>]) -> String {
+    let rows: Vec<String> = CATEGORY_PAGES
+        .iter()
+        .map(|p| {
+            let count = cards
+                .iter()
+                .filter(|stored| stored.card.category() == &p.category)
+                .count();
+            format!(
+                "<tr><td><a href=\"{}\">{}</a></td><td class=\"count\">{}</td></tr>",
+                p.file_name, p.heading, count
+            )
+        })
+        .chain(std::iter::once(format!(
+            "<tr><td><a href=\"scenarios.html\">Scenarios</a></td><td class=\"count\">{}</td></tr>",
+            scenarios.len()
+        )))
+        .collect();
+    format!("<table>\n{}\n</table>", rows.join("\n"))
+}
+
+fn category(cards: &[&StoredCard], targets: &[Target], taxonomy: &TargetTaxonomy) -> String {
+    let entries: Vec<String> = cards
+        .iter()
+        .map(|stored| {
+            // a card shows up for its targets and for every group or part of them
+            let matching: Vec<&str> = targets
+                .iter()
+                .filter(|target| {
+                    stored
+                        .card
+                        .effect()
+                        .targets()
+                        .iter()
+                        .any(|t| taxonomy.matches(t, target))
+                })
+                .map(|target| target.value())
+                .collect();
+            format!(
+                "<div class=\"entry\" data-targets=\"{}\">\n<span class=\"id\">{}</span>\n{}\n</div>",
+                escape(&matching.join("|")),
+                escape(&stored.id),
+                card_to_html(stored.card.clone())
+            )
+        })
+        .collect();
+    if entries.is_empty() {
+        return "<p>No cards yet.</p>".to_string();
+    }
+
+    let has_targets = cards
+        .iter()
+        .any(|stored| !stored.card.effect().targets().is_empty());
+    let filter = if has_targets {
+        let options: Vec<String> = targets
+            .iter()
+            .map(|target| format!("<option>{}</option>", escape(target.value())))
+            .collect();
+        format!(
+            "<p><label>Target <select id=\"target-filter\"><option value=\"\">all</option>{}</select></label></p>\n",
+            options.join("")
+        )
+    } else {
+        String::new()
+    };
+    let script = if has_targets { FILTER_SCRIPT } else { "" };
+    format!(
+        "{}<div class=\"cards\">\n{}\n</div>\n{}",
+        filter,
+        entries.join("\n"),
+        script
+    )
+}
+
+fn scenario_list(scenarios: &[Rc<Scenario>]) -> String {
+    if scenarios.is_empty() {
+        return "<p>No scenarios yet.</p>".to_string();
+    }
+    let sections: Vec<String> = scenarios
+        .iter()
+        .map(|scenario| scenario_to_html(scenario))
+        .collect();
+    sections.join("\n")
+}
+
+fn scenario_to_html(scenario: &Scenario) -> String {
+    let preset = &scenario.preset;
+    let goal = &scenario.goal;
+    let mut details = vec![
+        (
+            "Start",
+            format!(
+                "{} resources, {} people-hours, {} reputation",
+                preset.resources.value(),
+                preset.people_hours.value(),
+                preset.reputation.value()
+            ),
+        ),
+        (
+            "Per round",
+            format!(
+                "{} resources, {} people-hours, fix multiplier {}",
+                preset.resource_gain.value(),
+                preset.people_hours_gain.value(),
+                preset.multiplier.value()
+            ),
+        ),
+        (
+            "Goal",
+            format!(
+                "at least {} resources and {} reputation",
+                goal.minimum_resources.value(),
+                goal.minimum_reputation.value()
+            ),
+        ),
+    ];
+    for objective in &goal.objectives {
+        details.push(("Objective", objective.to_string()));
+    }
+    if let Some(rule_set) = &scenario.rule_set {
+        details.push(("Rule set", rule_set.clone()));
+    }
+    if let Some(deck) = &scenario.deck {
+        details.push((
+            "Deck",
+            format!(
+                "{} events, {} attacks, {} oopsies, {} lucky cards, {} controls, {} evaluations",
+                deck.events, deck.attacks, deck.oopsies, deck.lucky, deck.controls, deck.evaluation
+            ),
+        ));
+    }
+    if let Some(grace_rounds) = scenario.grace_rounds {
+        details.push(("Grace rounds", grace_rounds.to_string()));
+    }
+    let titles = |titles: &[Title]| {
+        titles
+            .iter()
+            .map(|title| title.value().to_string())
+            .collect::<Vec<_>>()
+            .join(", ")
+    };
+    if !scenario.cards.required.is_empty() {
+        details.push(("Required", titles(&scenario.cards.required)));
+    }
+    if !scenario.cards.forbidden.is_empty() {
+        details.push(("Forbidden", titles(&scenario.cards.forbidden)));
+    }
+    for income in &scenario.income_schedule {
+        details.push((
+            "Income",
+            format!(
+                "{} resources per round from round {}",
+                income.resource_gain.value(),
+                income.round
+            ),
+        ));
+    }
+    for event in &scenario.scripted_events {
+        details.push((
+            "Scripted",
+            format!("{} in round {}", event.card.title().value(), event.round),
+        ));
+    }
+
+    let details: Vec<String> = details
+        .iter()
+        .map(|(topic, text)| format!("<tr><th>{}</th><td>{}</td></tr>", topic, escape(text)))
+        .collect();
+    format!(
+        "<section class=\"scenario\">\n<h2>{}</h2>\n<p>{}</p>\n<table>\n{}\n</table>\n</section>",
+        escape(scenario.title.value()),
+        escape(scenario.description.value()),
+        details.join("\n")
+    )
+}
+
+fn coverage_matrix(coverage: &[TargetCoverage]) -> String {
+    let rows: Vec<String> = coverage
+        .iter()
+        .map(|c| {
+            let target = escape(c.target.value());
+            let parameter = encode_parameter(c.target.value());
+            let cell = |file_name: &str, count: usize| {
+                format!(
+                    "<td class=\"count\"><a href=\"{}?target={}\">{}</a></td>",
+                    file_name, parameter, count
+                )
+            };
+            format!(
+                "<tr{}><th>{}</th>{}{}{}</tr>",
+                if c.has_gap() { " class=\"gap\"" } else { "" },
+                target,
+                cell("attacks.html", c.attacks),
+                cell("oopsies.html", c.oopsies),
+                cell("controls.html", c.controls)
+            )
+        })
+        .collect();
+    format!(
+        "<p>Cards which affect a target or a part of it. Attacks on highlighted targets never cause an incident, no oopsie opens them.</p>\n<table>\n<tr><th>Target</th><th>Attacks</th><th>Oopsies</th><th>Controls</th></tr>\n{}\n</table>",
+        rows.join("\n")
+    )
+}
+
+/// Percent-encodes everything but letters, digits and `-_.~` for a query parameter.
+fn encode_parameter(value: &str) -> String {
+    value
+        .bytes()
+        .map(|byte| match byte {
+            b'a'..=b'z' | b'A'..=b'Z' | b'0'..=b'9' | b'-' | b'_' | b'.' | b'~' => {
+                (byte as char).to_string()
+            }
+            _ => format!("%{:02X}", byte),
+        })
+        .collect()
+}
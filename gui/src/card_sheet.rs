@@ -0,0 +1,203 @@
+use eframe::epaint::Color32;
+use game_lib::world::deck::CardRc;
+
+use crate::card_text::printable_content;
+use crate::game_view::card_window::card_view_model::CardContent;
+
+/*
+Print-ready sheets of cards. A sheet is an A4 page with three rows of three cards in poker size
+(63 x 88 mm). The header of a card is filled with the colour the card window uses in dark mode, the
+frame uses the colour of light mode, so printed cards look like the cards in the game.
+ */
+
+const SHEET_WIDTH: f32 = 210.0;
+const SHEET_HEIGHT: f32 = 297.0;
+const CARD_WIDTH: f32 = 63.0;
+const CARD_HEIGHT: f32 = 88.0;
+const COLUMNS: usize = 3;
+const ROWS: usize = 3;
+pub const CARDS_PER_SHEET: usize = COLUMNS * ROWS;
+
+const MARGIN_X: f32 = (SHEET_WIDTH - COLUMNS as f32 * CARD_WIDTH) / 2.0;
+const MARGIN_Y: f32 = (SHEET_HEIGHT - ROWS as f32 * CARD_HEIGHT) / 2.0;
+const HEADER_HEIGHT: f32 = 10.0;
+const PADDING: f32 = 3.0;
+const LINE_HEIGHT: f32 = 3.2;
+const FONT_SIZE: f32 = 2.6;
+/// Characters which fit into one line of a card at the font size above.
+const LINE_LENGTH: usize = 40;
+
+/// Renders the cards into a single HTML document, each sheet is printed on its own page.
+pub fn cards_to_html(cards: &[CardRc]) -> String {
+    let sheets: Vec<String> = cards
+        .chunks(CARDS_PER_SHEET)
+        .map(|sheet| {
+            let cards: Vec<String> = sheet.iter().map(|card| card_to_html(card.clone())).collect();
+            format!("<section class=\"sheet\">\n{}\n</section>", cards.join("\n"))
+        })
+        .collect();
+    format!(
+        r#"<!DOCTYPE html>
+<html>
+<head>
+<meta charset="utf-8">
+<title>Cards</title>
+<style>
+@page {{ size: A4; margin: 0; }}
+body {{ margin: 0; font-family: sans-serif; font-size: {font}mm; }}
+.sheet {{ width: {sheet_w}mm; height: {sheet_h}mm; padding: {margin_y}mm {margin_x}mm; box-sizing: border-box;
+  display: grid; grid-template-columns: repeat({columns}, {card_w}mm); grid-auto-rows: {card_h}mm; break-after: page; }}
+.card {{ border: 0.5mm solid; box-sizing: border-box; overflow: hidden; }}
+.card h1 {{ margin: 0; padding: 0 {padding}mm; height: {header}mm; line-height: {header}mm; font-size: 4mm;
+  white-space: nowrap; overflow: hidden; text-overflow: ellipsis; }}
+.card p, .card dl {{ margin: {padding}mm; }}
+.card dt {{ font-weight: bold; float: left; width: 17mm; }}
+.card dd {{ margin-left: 17mm; }}
+</style>
+</head>
+<body>
+{sheets}
+</body>
+</html>
+"#,
+        font = FONT_SIZE,
+        sheet_w = SHEET_WIDTH,
+        sheet_h = SHEET_HEIGHT,
+        margin_x = MARGIN_X,
+        margin_y = MARGIN_Y,
+        columns = COLUMNS,
+        card_w = CARD_WIDTH,
+        card_h = CARD_HEIGHT,
+        padding = PADDING,
+        header = HEADER_HEIGHT,
+        sheets = sheets.join("\n"),
+    )
+}
+
+pub(crate) fn card_to_html(card: CardRc) -> String {
+    let content = printable_content(card);
+    let details: Vec<String> = details(&content)
+        .iter()
+        .map(|(topic, text)| format!("<dt>{}</dt><dd>{}</dd>", escape(topic.trim()), escape(text)))
+        .collect();
+    format!(
+        "<div class=\"card\" style=\"border-color: {frame}\">\n<h1 style=\"background: {header}; color: #fff\">{title}</h1>\n<p>{description}</p>\n<dl>{details}</dl>\n</div>",
+        frame = hex(content.light_color),
+        header = hex(content.dark_color),
+        title = escape(&content.label),
+        description = escape(&content.description),
+        details = details.join(""),
+    )
+}
+
+/// Renders the cards into one SVG document per sheet.
+pub fn cards_to_svg(cards: &[CardRc]) -> Vec<String> {
+    cards
+        .chunks(CARDS_PER_SHEET)
+        .map(|sheet| {
+            let cards: Vec<String> = sheet
+                .iter()
+                .enumerate()
+                .map(|(index, card)| {
+                    let x = MARGIN_X + (index % COLUMNS) as f32 * CARD_WIDTH;
+                    let y = MARGIN_Y + (index / COLUMNS) as f32 * CARD_HEIGHT;
+                    card_to_svg(card.clone(), x, y)
+                })
+                .collect();
+            format!(
+                "<svg xmlns=\"http://www.w3.org/2000/svg\" width=\"{w}mm\" height=\"{h}mm\" viewBox=\"0 0 {w} {h}\" font-family=\"sans-serif\" font-size=\"{font}\">\n{cards}\n</svg>\n",
+                w = SHEET_WIDTH,
+                h = SHEET_HEIGHT,
+                font = FONT_SIZE,
+                cards = cards.join("\n"),
+            )
+        })
+        .collect()
+}
+
+fn card_to_svg(card: CardRc, x: f32, y: f32) -> String {
+    let content = printable_content(card);
+    let mut lines: Vec<(bool, String)> = wrap(&content.description, LINE_LENGTH)
+        .into_iter()
+        .map(|line| (false, line))
+        .collect();
+    lines.push((false, String::new()));
+    for (topic, text) in details(&content) {
+        lines.push((true, topic.trim().to_string()));
+        lines.extend(wrap(&text, LINE_LENGTH).into_iter().map(|line| (false, line)));
+    }
+
+    let max_lines = ((CARD_HEIGHT - HEADER_HEIGHT - 2.0 * PADDING) / LINE_HEIGHT) as usize;
+    let text: Vec<String> = lines
+        .iter()
+        .take(max_lines)
+        .enumerate()
+        .map(|(index, (bold, line))| {
+            format!(
+                "<text x=\"{}\" y=\"{}\"{}>{}</text>",
+                x + PADDING,
+                y + HEADER_HEIGHT + PADDING + (index + 1) as f32 * LINE_HEIGHT,
+                if *bold { " font-weight=\"bold\"" } else { "" },
+                escape(line)
+            )
+        })
+        .collect();
+    format!(
+        "<g>\n<rect x=\"{x}\" y=\"{y}\" width=\"{w}\" height=\"{h}\" fill=\"white\" stroke=\"{frame}\" stroke-width=\"0.5\"/>\n<rect x=\"{x}\" y=\"{y}\" width=\"{w}\" height=\"{header_h}\" fill=\"{header}\" stroke=\"{frame}\" stroke-width=\"0.5\"/>\n<text x=\"{title_x}\" y=\"{title_y}\" font-size=\"4\" font-weight=\"bold\" fill=\"#fff\">{title}</text>\n{text}\n</g>",
+        x = x,
+        y = y,
+        w = CARD_WIDTH,
+        h = CARD_HEIGHT,
+        header_h = HEADER_HEIGHT,
+        frame = hex(content.light_color),
+        header = hex(content.dark_color),
+        title_x = x + PADDING,
+        title_y = y + HEADER_HEIGHT / 2.0 + 1.5,
+        title = escape(&content.label),
+        text = text.join("\n"),
+    )
+}
+
+/// Everything the card window shows below the description which can be printed. Buttons become lines
+/// with the price or the options to choose from.
+fn details(content: &CardContent) -> Vec<(&'static str, String)> {
+    let mut details = content.effect_explanations();
+    details.extend(content.cost_explanations());
+    if let Some(price) = &content.buy_price {
+        details.push(("Price:    ", format!("{} resources", price.value())));
+    }
+    for option in &content.options {
+        details.push(("Option:   ", option.clone()));
+    }
+    details
+}
+
+fn wrap(text: &str, max_length: usize) -> Vec<String> {
+    let mut lines = vec![];
+    let mut line = String::new();
+    for word in text.split_whitespace() {
+        if !line.is_empty() && line.chars().count() + 1 + word.chars().count() > max_length {
+            lines.push(line);
+            line = String::new();
+        }
+        if !line.is_empty() {
+            line.push(' ');
+        }
+        line.push_str(word);
+    }
+    if !line.is_empty() {
+        lines.push(line);
+    }
+    lines
+}
+
+fn hex(color: Color32) -> String {
+    format!("#{:02x}{:02x}{:02x}", color.r(), color.g(), color.b())
+}
+
+pub(crate) fn escape(text: &str) -> String {
+    text.replace('&', "&amp;")
+        .replace('<', "&lt;")
+        .replace('>', "&gt;")
+        .replace('"', "&quot;")
+}
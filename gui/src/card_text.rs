@@ -0,0 +1,38 @@
+use game_lib::world::deck::CardRc;
+use game_lib::world::resource_fix_multiplier::ResourceFixMultiplier;
+use uuid::Uuid;
+
+use crate::game_view::card_window::card_view_model::CardContent;
+
+/// Renders a card as plain text with the same content as the card window of the game, so cards can be
+/// looked at on the command line.
+pub fn card_to_text(card: CardRc) -> String {
+    let content = printable_content(card);
+    let mut lines = vec![content.label.clone(), content.description.clone(), String::new()];
+    let explanations = content
+        .effect_explanations()
+        .into_iter()
+        .chain(content.cost_explanations());
+    for (topic, text) in explanations {
+        lines.push(format!("{} {}", topic, text));
+    }
+    if let Some(price) = &content.buy_price {
+        lines.push(format!("Buy:       {} resources", price.value()));
+    }
+    for option in &content.options {
+        lines.push(format!("Option:    {}", option));
+    }
+    lines.join("\n")
+}
+
+/// Content of the card window for a card outside of a game, i.e. without fix progress or active state.
+pub(crate) fn printable_content(card: CardRc) -> CardContent {
+    CardContent::from_card(
+        &Uuid::nil(),
+        card,
+        false,
+        None,
+        false,
+        ResourceFixMultiplier::default(),
+    )
+}
@@ -3,11 +3,16 @@ use uuid::Uuid;
 #[derive(Debug, Clone)]
 pub(crate) enum Command {
     SetResourceGain(usize),
+    SetPeopleHoursGain(usize),
     PayResources(usize),
     SetMultiplier(isize),
     CloseCard(Uuid),
     DeactivateCard(Uuid),
     ActivateCard(Uuid),
+    BuyControl(Uuid),
+    BuyMarketCard(Uuid),
+    PlayCard(Uuid),
+    ChooseOption(Uuid, usize),
     IncreaseReputation(u8),
     DecreaseReputation(u8),
 }
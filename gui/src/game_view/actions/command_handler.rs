@@ -34,11 +34,18 @@ impl GameViewState {
 
         match msg {
             Command::SetResourceGain(res) => self.handle_set_resource_gain(res.clone()),
+            Command::SetPeopleHoursGain(hours) => self.handle_set_people_hours_gain(*hours),
             Command::PayResources(res) => self.handle_pay_resources(res.clone()),
             Command::SetMultiplier(m) => self.handle_set_multiplier(m.clone()),
             Command::CloseCard(card_id) => self.handle_card_closed(card_id.clone()),
             Command::DeactivateCard(card_id) => self.handle_deactivate_card(card_id.clone()),
             Command::ActivateCard(card_id) => self.handle_activate_card(card_id.clone()),
+            Command::BuyControl(card_id) => self.handle_buy_control(*card_id),
+            Command::BuyMarketCard(offer_id) => self.handle_buy_market_card(*offer_id),
+            Command::PlayCard(card_id) => self.handle_play_card(*card_id),
+            Command::ChooseOption(card_id, option) => {
+                self.handle_choose_option(*card_id, *option)
+            }
             Command::IncreaseReputation(rep) => self.handle_increase_reputation(rep.clone()),
             Command::DecreaseReputation(rep) => self.handle_decrease_reputation(rep.clone()),
         }
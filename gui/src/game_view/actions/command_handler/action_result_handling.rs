@@ -10,12 +10,35 @@ impl GameViewState {
                 self.input.message =
                     Message::Success(format!("Fixed for {} resources.", res.value()));
             }
+            GameActionResult::FixStarted(res) => {
+                self.input.message = Message::Success(format!(
+                    "Fix started, paid {} resources for the first round.",
+                    res.value()
+                ));
+            }
             GameActionResult::FixFailed(res) => {
                 self.input.message = Message::Failure(format!(
                     "Fix failed! It would have needed {} resources.",
                     res.value()
                 ));
             }
+            GameActionResult::NotEnoughPeopleHours(hours) => {
+                self.input.message = Message::Warning(format!(
+                    "Not enough people-hours! The fix needs {}.",
+                    hours.value()
+                ));
+            }
+            GameActionResult::ControlBought(res) => {
+                self.input.message =
+                    Message::Success(format!("Control bought for {} resources.", res.value()));
+            }
+            GameActionResult::CardBought(res) => {
+                self.input.message =
+                    Message::Success(format!("Card bought for {} resources.", res.value()));
+            }
+            GameActionResult::OptionChosen(label) => {
+                self.input.message = Message::Success(format!("Chose: {}", label));
+            }
             GameActionResult::AttackForceClosed => {
                 self.input.message = Message::Warning("Attack forced to be over".to_string());
             }
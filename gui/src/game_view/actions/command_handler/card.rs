@@ -18,4 +18,19 @@ impl GameViewState {
         let new_game_state = self.game.deactivate_lucky_card(&card_id);
         self.game = new_game_state;
     }
+
+    pub(crate) fn handle_buy_control(&mut self, card_id: Uuid) {
+        let new_game_state = self.game.buy_control(&card_id);
+        self.game = new_game_state;
+    }
+
+    pub(crate) fn handle_play_card(&mut self, card_id: Uuid) {
+        let new_game_state = self.game.play_card_from_hand(&card_id);
+        self.game = new_game_state;
+    }
+
+    pub(crate) fn handle_choose_option(&mut self, card_id: Uuid, option: usize) {
+        let new_game_state = self.game.choose_option(&card_id, option);
+        self.game = new_game_state;
+    }
 }
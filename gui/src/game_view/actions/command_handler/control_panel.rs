@@ -4,6 +4,7 @@ use game_lib::world::reputation::Reputation;
 use game_lib::world::resource_fix_multiplier::ResourceFixMultiplier;
 use game_lib::world::resources::Resources;
 use crate::game_view::state::{GameViewState, Message};
+use uuid::Uuid;
 
 impl GameViewState {
     pub(crate) fn handle_pay_resources(&mut self, res: usize) {
@@ -24,6 +25,11 @@ impl GameViewState {
         self.input.next_res = res.to_string();
     }
 
+    pub(crate) fn handle_set_people_hours_gain(&mut self, hours: usize) {
+        self.game = self.game.set_people_hours_gain(Resources::new(hours));
+        self.input.next_people_hours = hours.to_string();
+    }
+
     pub(crate) fn handle_increase_reputation(&mut self, rep: u8) {
         self.game = self.game.increase_reputation(&Reputation::new(rep));
     }
@@ -32,4 +38,8 @@ impl GameViewState {
         self.game = self.game.decrease_reputation(&Reputation::new(rep));
     }
 
+
+    pub(crate) fn handle_buy_market_card(&mut self, offer_id: Uuid) {
+        self.game = self.game.buy_market_card(&offer_id);
+    }
 }
@@ -2,18 +2,23 @@ use std::ops::Add;
 
 use eframe::epaint::Color32;
 use game_lib::cards::properties::effect::Effect;
+use game_lib::cards::properties::escalation::Escalation;
 use game_lib::cards::properties::fix_cost::FixCost;
+use game_lib::cards::properties::follow_up_attack::{FollowUpAttack, FollowUpTiming};
 use game_lib::cards::properties::cost_modifier::CostModifier;
 use game_lib::cards::properties::incident_impact::IncidentImpact;
 use game_lib::cards::properties::target::Target;
 use game_lib::cards::types::attack::AttackCard;
 use game_lib::cards::types::card_model::{Card, CardTrait};
+use game_lib::cards::types::control::ControlCard;
 use game_lib::cards::types::evaluation::EvaluationCard;
 use game_lib::cards::types::event::EventCard;
 use game_lib::cards::types::lucky::LuckyCard;
 use game_lib::cards::types::oopsie::OopsieCard;
 use game_lib::world::deck::CardRc;
+use game_lib::world::fix_progress::FixProgress;
 use game_lib::world::resource_fix_multiplier::ResourceFixMultiplier;
+use game_lib::world::resources::Resources;
 use uuid::Uuid;
 
 #[derive(Debug)]
@@ -28,6 +33,15 @@ pub struct CardContent {
     pub costs: Option<FixCost>,
     pub incident_impact: Option<IncidentImpact>,
     pub duration: Option<usize>,
+    pub follow_ups: Option<Vec<String>>,
+    pub escalation: Option<String>,
+    pub fix_rounds: Option<usize>,
+    pub fix_progress: Option<FixProgress>,
+    pub protection: Option<String>,
+    /// Price of a control card which is not bought yet.
+    pub buy_price: Option<Resources>,
+    /// Options of a decision event, each one is shown as a button.
+    pub options: Vec<String>,
     pub can_be_activated: bool,
     pub can_be_closed: bool,
     pub card_marker: CardMarker,
@@ -78,6 +92,22 @@ impl CardContent {
             costs: actual_costs,
             incident_impact: actual_impact,
             duration,
+            follow_ups: Self::card_to_follow_ups(&card),
+            escalation: Self::card_to_escalation(&card),
+            fix_rounds: match &card {
+                Card::Oopsie(oopsie) => oopsie.fix_rounds.filter(|rounds| *rounds > 1),
+                _ => None,
+            },
+            fix_progress: None,
+            protection: match card.effect() {
+                Effect::Protection(_, _, mitigation) => Some(mitigation.to_string()),
+                _ => None,
+            },
+            buy_price: None,
+            options: match &card {
+                Card::Event(event) => event.options.iter().map(|o| o.to_string()).collect(),
+                _ => vec![],
+            },
             can_be_activated: Self::can_effect_be_activated(&card.effect()),
             can_be_closed,
             card_marker: CardMarker::None,
@@ -89,6 +119,8 @@ impl CardContent {
         id: &Uuid,
         card: CardRc,
         is_active: bool,
+        fix_progress: Option<FixProgress>,
+        is_bought: bool,
         multiplier: ResourceFixMultiplier,
     ) -> CardContent {
         let mut card_view_model = match &*card {
@@ -97,6 +129,7 @@ impl CardContent {
             Card::Oopsie(c) => Self::oopsie_card_content(id, c.clone(), multiplier),
             Card::Lucky(c) => Self::lucky_card_content(id, c.clone(), multiplier),
             Card::Evaluation(c) => Self::evaluation_card_content(id, c.clone(), multiplier),
+            Card::Control(c) => Self::control_card_content(id, c.clone(), is_bought, multiplier),
         };
 
         card_view_model.card_marker = if is_active {
@@ -105,9 +138,74 @@ impl CardContent {
             CardMarker::None
         };
 
+        // an oopsie which is being fixed can not be fixed again
+        if fix_progress.is_some() {
+            card_view_model.can_be_closed = false;
+        }
+        card_view_model.fix_progress = fix_progress;
+
         card_view_model
     }
 
+    /// Topics and texts about the effect of the card as shown in the card window: action, duration,
+    /// targets and follow-ups.
+    pub fn effect_explanations(&self) -> Vec<(&'static str, String)> {
+        let mut explanations = vec![("Action:   ", self.action.clone())];
+        if let Some(duration) = self.duration {
+            explanations.push(("Duration: ", format!("{} rounds", duration)));
+        }
+        if let Some(targets) = &self.targets {
+            explanations.push(("Targets:  ", targets.join(", ")));
+        }
+        for follow_up in self.follow_ups.iter().flatten() {
+            explanations.push(("Follow-up:", follow_up.clone()));
+        }
+        explanations
+    }
+
+    /// Topics and texts about the costs of the card as shown in the card window: fix costs or incident
+    /// impact, escalation and protection.
+    pub fn cost_explanations(&self) -> Vec<(&'static str, String)> {
+        let mut explanations = vec![];
+        if let Some(cost) = &self.costs {
+            let content = match self.fix_rounds {
+                Some(rounds) => format!(
+                    "{} to {} resources over {} rounds",
+                    cost.min.value(),
+                    cost.max.value(),
+                    rounds
+                ),
+                None => format!("{} to {} resources", cost.min.value(), cost.max.value()),
+            };
+            let content = match cost.people_hours {
+                Some(hours) => format!("{} + {} people-hours", content, hours.value()),
+                None => content,
+            };
+            explanations.push(("Fix:      ", content));
+        }
+        if let Some(impact) = &self.incident_impact {
+            let content = match impact {
+                IncidentImpact::PartOfRevenue(poh) => {
+                    format!("Pay {} of your revenue during this incident", poh)
+                }
+                IncidentImpact::Fixed(f) => format!("Pay {} resources immediately", f.value()),
+            };
+            explanations.push(("Impact:   ", content));
+        }
+        if let Some(escalation) = &self.escalation {
+            explanations.push(("Escalate: ", escalation.clone()));
+        }
+        if let Some(protection) = &self.protection {
+            let content = if self.buy_price.is_some() {
+                format!("{} once bought", protection)
+            } else {
+                protection.clone()
+            };
+            explanations.push(("Protects: ", content));
+        }
+        explanations
+    }
+
     fn event_card_content(
         id: &Uuid,
         card: EventCard,
@@ -135,6 +233,7 @@ impl CardContent {
             | Effect::Incident(_, _, _)
             | Effect::OnNextFix(_, _)
             | Effect::Other(_)
+            | Effect::Protection(_, _, _)
             | Effect::NOP => false,
             Effect::OnUsingForFix(_, _) => true,
         }
@@ -144,10 +243,61 @@ impl CardContent {
         match action {
             Effect::Incident(_, t, _) => Some(Self::targets_to_strings(t)),
             Effect::AttackSurface(_, t) => Some(Self::targets_to_strings(t)),
+            Effect::Protection(_, t, _) => Some(Self::targets_to_strings(t)),
+            _ => None,
+        }
+    }
+
+    fn card_to_follow_ups(card: &Card) -> Option<Vec<String>> {
+        match card {
+            Card::Attack(attack) if !attack.follow_ups.is_empty() => Some(
+                attack
+                    .follow_ups
+                    .iter()
+                    .map(Self::follow_up_chain_to_text)
+                    .collect(),
+            ),
             _ => None,
         }
     }
 
+    fn card_to_escalation(card: &Card) -> Option<String> {
+        let attack = match card {
+            Card::Attack(attack) => attack,
+            _ => return None,
+        };
+        let unit = match &attack.effect {
+            Effect::Incident(_, _, IncidentImpact::PartOfRevenue(_)) => "%",
+            _ => " resources",
+        };
+        match attack.escalation {
+            Escalation::None => None,
+            Escalation::Linear(increase) => Some(format!("+{}{} every round", increase, unit)),
+            Escalation::Stepped { every, increase } => {
+                Some(format!("+{}{} every {} rounds", increase, unit, every))
+            }
+        }
+    }
+
+    /// Describes a follow-up and the follow-ups it triggers itself, e.g.
+    /// "Credential theft (in 2 rounds) → Ransomware (immediately)".
+    fn follow_up_chain_to_text(follow_up: &FollowUpAttack) -> String {
+        let timing = match follow_up.timing {
+            FollowUpTiming::Immediately => "immediately".to_string(),
+            FollowUpTiming::InRounds(1) => "next round".to_string(),
+            FollowUpTiming::InRounds(rounds) => format!("in {} rounds", rounds),
+        };
+        let text = format!("{} ({})", follow_up.attack.title.value(), timing);
+        match follow_up.attack.follow_ups.as_slice() {
+            [] => text,
+            nested => {
+                let nested_text: Vec<String> =
+                    nested.iter().map(Self::follow_up_chain_to_text).collect();
+                format!("{} → {}", text, nested_text.join(", "))
+            }
+        }
+    }
+
     fn effect_to_text(action: &Effect, multiplier: &ResourceFixMultiplier) -> String {
         match action {
             Effect::Immediate(d) | Effect::Other(d) => d.value().to_string(),
@@ -155,7 +305,9 @@ impl CardContent {
             Effect::OnUsingForFix(_d, m) => {
                 Self::modifier_to_text(m, multiplier).add(" on use for a fix.")
             }
-            Effect::Incident(d, _, _) | Effect::AttackSurface(d, _) => d.value().to_string(),
+            Effect::Incident(d, _, _)
+            | Effect::AttackSurface(d, _)
+            | Effect::Protection(d, _, _) => d.value().to_string(),
             Effect::NOP => "".to_string(),
         }
     }
@@ -190,6 +342,7 @@ impl CardContent {
             | Effect::OnNextFix(_, _)
             | Effect::OnUsingForFix(_, _)
             | Effect::Other(_)
+            | Effect::Protection(_, _, _)
             | Effect::NOP => None
         };
         Self::new(
@@ -265,4 +418,27 @@ impl CardContent {
             "X".to_string(),     
         )
     }
+
+    fn control_card_content(
+        id: &Uuid,
+        card: ControlCard,
+        is_bought: bool,
+        multiplier: ResourceFixMultiplier,
+    ) -> CardContent {
+        let price = card.cost * multiplier;
+        let mut content = Self::new(
+            *id,
+            Color32::from_rgb(0, 170, 170),
+            Color32::from_rgb(0, 90, 90),
+            Card::Control(card),
+            None,
+            None,
+            None,
+            true,
+            multiplier,
+            "X".to_string(),
+        );
+        content.buy_price = if is_bought { None } else { Some(price) };
+        content
+    }
 }
@@ -1,8 +1,7 @@
 use crate::game_view::actions::command::Command;
 use crate::game_view::card_window::card_view_model::{CardContent, CardMarker};
 use eframe::epaint::FontFamily;
-use egui::{Context, Label, Pos2, RichText, Ui, Vec2, WidgetText, Window};
-use game_lib::cards::properties::incident_impact::IncidentImpact;
+use egui::{Context, Label, Pos2, ProgressBar, RichText, Ui, Vec2, WidgetText, Window};
 use rand::Rng;
 
 pub struct CardWindow<'a> {
@@ -57,19 +56,18 @@ where
 
         ui.add_space(5.0);
 
-        add_action(&card, ui);
-        add_duration(&card, ui);
-        add_targets(&card, ui);
+        add_explanations(card.effect_explanations(), ui);
 
         ui.add_space(2.0);
 
-        add_fix_costs(&card, ui);
-        // this is either fix costs or incident impact
-        add_incident_impact(&card, ui);
+        add_explanations(card.cost_explanations(), ui);
+        add_fix_progress(&card, ui);
 
         ui.add_space(1.0);
 
         add_activation_button(cmd_callback, card, ui);
+        add_buy_button(cmd_callback, card, ui);
+        add_option_buttons(cmd_callback, card, ui);
     });
 }
 
@@ -91,53 +89,45 @@ where
     }
 }
 
-fn add_incident_impact(card: &&CardContent, ui: &mut Ui) {
-    match &card.incident_impact {
-        None => {}
-        Some(impact) => {
-            let content = match impact {
-                IncidentImpact::PartOfRevenue(poh) => {
-                    format!("Pay {} of your revenue during this incident", poh)
-                }
-                IncidentImpact::Fixed(f) => format!("Pay {} resources immediately", f.value()),
-            };
-            add_explanation("Impact:   ", content.as_str(), ui);
+fn add_buy_button<F>(cmd_callback: &mut F, card: &CardContent, ui: &mut Ui)
+where
+    F: FnMut(Command),
+{
+    if let Some(price) = &card.buy_price {
+        if ui.button(format!("Buy ({})", price.value())).clicked() {
+            cmd_callback(Command::BuyControl(card.id));
         }
     }
 }
 
-fn add_fix_costs(card: &&CardContent, ui: &mut Ui) {
-    match &card.costs {
-        None => {}
-        Some(cost) => {
-            let content = format!("{} to {} resources", cost.min.value(), cost.max.value());
-            add_explanation("Fix:      ", content.as_str(), ui);
+fn add_option_buttons<F>(cmd_callback: &mut F, card: &CardContent, ui: &mut Ui)
+where
+    F: FnMut(Command),
+{
+    for (index, option) in card.options.iter().enumerate() {
+        if ui.button(option).clicked() {
+            cmd_callback(Command::ChooseOption(card.id, index));
         }
-    };
-}
-
-fn add_action(card: &&CardContent, ui: &mut Ui) {
-    add_explanation("Action:   ", &card.action.as_str(), ui);
+    }
 }
 
-fn add_duration(card: &&CardContent, ui: &mut Ui) {
-    match &card.duration {
-        None => {}
-        Some(duration) => {
-            let content = format!("{} rounds", duration);
-            add_explanation("Duration: ", content.as_str(), ui);
-        }
+fn add_fix_progress(card: &&CardContent, ui: &mut Ui) {
+    if let Some(progress) = &card.fix_progress {
+        let done = progress.rounds_done as f32 / progress.rounds_total as f32;
+        let text = format!(
+            "Fixing: {}/{} rounds, {} of {} paid",
+            progress.rounds_done,
+            progress.rounds_total,
+            progress.paid.value(),
+            progress.total_cost.value()
+        );
+        ui.add(ProgressBar::new(done).text(text));
     }
 }
 
-fn add_targets(card: &&CardContent, ui: &mut Ui) {
-    match &card.targets {
-        None => {}
-
-        Some(targets) => {
-            let content = targets.join(", ");
-            add_explanation("Targets:  ", content.as_str(), ui);
-        }
+fn add_explanations(explanations: Vec<(&'static str, String)>, ui: &mut Ui) {
+    for (topic, content) in explanations {
+        add_explanation(topic, content.as_str(), ui);
     }
 }
 
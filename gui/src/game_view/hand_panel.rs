@@ -0,0 +1,58 @@
+use crate::game_view::actions::command::Command;
+use crate::game_view::card_window::card_view_model::CardContent;
+use crate::GameViewState;
+use egui::{Context, RichText, Ui};
+use game_lib::world::game::GameStatus;
+
+/// The hand is shown below the board. Its cards are only known to the players and have no effect
+/// until they are played.
+impl GameViewState {
+    pub(crate) fn create_hand_panel(&mut self, ctx: &Context) {
+        if self.game.hand_size == 0 {
+            return;
+        }
+        egui::TopBottomPanel::bottom("hand_panel")
+            .resizable(false)
+            .show(ctx, |ui| {
+                ui.add_space(5.0);
+                self.draw_hand(ui);
+                ui.add_space(5.0);
+            });
+    }
+
+    fn draw_hand(&mut self, ui: &mut Ui) {
+        let (hand, can_play) = match &self.game.status {
+            GameStatus::Start(board) | GameStatus::InProgress(board) => (board.hand.clone(), true),
+            GameStatus::Finished(board) => (board.hand.clone(), false),
+        };
+        ui.label(RichText::new(format!("Hand ({}/{})", hand.len(), self.game.hand_size)).strong());
+
+        let mut cards: Vec<CardContent> = hand
+            .into_iter()
+            .map(|(id, card)| {
+                CardContent::from_card(&id, card, false, None, false, self.game.fix_multiplier)
+            })
+            .collect();
+        cards.sort_by(|a, b| a.label.cmp(&b.label));
+
+        ui.horizontal_wrapped(|ui| {
+            for card in cards {
+                ui.group(|ui| {
+                    ui.vertical(|ui| {
+                        let header_color = if ui.visuals().dark_mode {
+                            card.dark_color
+                        } else {
+                            card.light_color
+                        };
+                        ui.label(RichText::new(&card.label).color(header_color).strong())
+                            .on_hover_text(&card.description);
+                        ui.label(&card.action);
+                        if can_play && ui.button("Play").clicked() {
+                            self.command = Some(Command::PlayCard(card.id));
+                        }
+                    });
+                });
+            }
+        });
+    }
+}
@@ -2,4 +2,5 @@ pub(crate) mod card_window;
 pub(crate) mod actions;
 pub(crate) mod state;
 mod side_panel;
+mod hand_panel;
 mod components;
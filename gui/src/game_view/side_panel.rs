@@ -1,7 +1,10 @@
 use crate::GameViewState;
+use eframe::epaint::Color32;
 use egui::{Context, RichText, Ui};
+use game_lib::world::objective::ObjectiveState;
 
 mod game_status_display;
+mod market_control;
 mod next_round_control;
 mod reputation_control;
 mod resource_control;
@@ -29,6 +32,10 @@ impl GameViewState {
 
                 self.reputation_control(ui);
 
+                ui.add_space(15.0);
+
+                self.market_control(ui);
+
                 ui.add_space(30.0);
 
                 self.tweak_control(ui);
@@ -47,6 +54,15 @@ impl GameViewState {
                 "Min Reputation: {}",
                 self.game_goals.min_reputation
             ));
+            if let Some(scenario) = &self.scenario {
+                for objective in &scenario.goal.objectives {
+                    let progress = self.game.evaluate_objective(objective);
+                    ui.label(
+                        RichText::new(format!("{}: {}", objective, progress.progress))
+                            .color(objective_color(progress.state)),
+                    );
+                }
+            }
         });
     }
 
@@ -60,3 +76,11 @@ impl GameViewState {
         }
     }
 }
+
+fn objective_color(state: ObjectiveState) -> Color32 {
+    match state {
+        ObjectiveState::Open => Color32::GRAY,
+        ObjectiveState::Achieved => Color32::GREEN,
+        ObjectiveState::Failed => Color32::RED,
+    }
+}
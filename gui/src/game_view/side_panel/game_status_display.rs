@@ -2,6 +2,7 @@ use eframe::epaint::Color32;
 use egui::{RichText, Ui};
 use crate::game_view::state::{GameViewState, Message};
 use game_lib::world::game::GameStatus;
+use game_lib::world::incident::{Incident, IncidentResolution};
 
 
 impl GameViewState {
@@ -11,6 +12,8 @@ impl GameViewState {
         self.display_message(ui);
         ui.add_space(20.0);
         self.display_incidents(ui);
+        ui.add_space(20.0);
+        self.display_incident_history(ui);
     }
 
     fn display_cards_remaining(&mut self, ui: &mut Ui) {
@@ -22,9 +25,11 @@ impl GameViewState {
     }
 
     fn display_incidents(&mut self, ui: &mut Ui) {
-        let incidents = match &self.game.status {
-            GameStatus::InProgress(board) => board.active_incidents.clone(),
-            _ => Vec::new(),
+        let (incidents, current_round) = match &self.game.status {
+            GameStatus::InProgress(board) | GameStatus::Finished(board) => {
+                (board.active_incidents.clone(), board.current_round)
+            }
+            _ => (Vec::new(), 0),
         };
 
         if incidents.is_empty() {
@@ -37,9 +42,36 @@ impl GameViewState {
                 "{} -> {}",
                 incident.attack_title, incident.oopsie_title
             ));
+            ui.label(describe_incident(&incident, current_round));
         }
     }
 
+    fn display_incident_history(&mut self, ui: &mut Ui) {
+        let (incidents, current_round) = match &self.game.status {
+            GameStatus::InProgress(board) | GameStatus::Finished(board) => {
+                (board.resolved_incidents.clone(), board.current_round)
+            }
+            _ => (Vec::new(), 0),
+        };
+
+        if incidents.is_empty() {
+            return;
+        }
+        ui.collapsing(format!("Incident history ({})", incidents.len()), |ui| {
+            for incident in incidents {
+                ui.label(format!(
+                    "{} -> {}",
+                    incident.attack_title, incident.oopsie_title
+                ));
+                ui.label(format!(
+                    "{}, {}",
+                    describe_resolution(incident.resolution),
+                    describe_incident(&incident, current_round)
+                ));
+            }
+        });
+    }
+
     fn display_message(&mut self, ui: &mut Ui) {
         match &self.input.message {
             Message::Success(m) => create_message(m, Color32::GREEN, ui),
@@ -50,6 +82,24 @@ impl GameViewState {
     }
 }
 
+fn describe_incident(incident: &Incident, current_round: usize) -> String {
+    format!(
+        "since round {}, {} round(s), cost {}",
+        incident.started_in_round,
+        incident.duration(current_round),
+        incident.accrued_cost.value()
+    )
+}
+
+fn describe_resolution(resolution: Option<IncidentResolution>) -> &'static str {
+    match resolution {
+        Some(IncidentResolution::AttackExpired) => "Attack expired",
+        Some(IncidentResolution::OopsieFixed) => "Oopsie fixed",
+        Some(IncidentResolution::ForceClosed) => "Force closed",
+        None => "Unresolved",
+    }
+}
+
 fn create_message(message: &String, color: Color32, ui: &mut Ui) {
     ui.label(RichText::new(message).color(color));
 }
@@ -0,0 +1,37 @@
+use crate::game_view::actions::command::Command;
+use crate::GameViewState;
+use egui::{RichText, Ui};
+use game_lib::cards::types::card_model::CardTrait;
+use game_lib::world::game::GameStatus;
+
+impl GameViewState {
+    pub(crate) fn market_control(&mut self, ui: &mut Ui) {
+        if self.game.market.size == 0 {
+            return;
+        }
+        ui.label(RichText::new("Market").strong());
+        ui.add_space(5.0);
+
+        if let GameStatus::Finished(_) = self.game.status {
+            ui.label("Closed");
+            return;
+        }
+        if self.game.market.offers.is_empty() {
+            ui.label("Nothing on offer");
+            return;
+        }
+
+        let mut offers: Vec<_> = self.game.market.offers.iter().collect();
+        offers.sort_by_key(|(_, offer)| offer.card.title().value().to_string());
+        for (offer_id, offer) in offers {
+            let price = offer.price * self.game.fix_multiplier;
+            ui.horizontal(|ui| {
+                ui.label(offer.card.title().value())
+                    .on_hover_text(offer.card.description().value());
+                if ui.button(format!("Buy ({})", price.value())).clicked() {
+                    self.command = Some(Command::BuyMarketCard(*offer_id));
+                }
+            });
+        }
+    }
+}
@@ -6,6 +6,23 @@ use crate::game_view::actions::command::Command;
 use crate::game_view::state::{GameViewState, Message};
 
 impl GameViewState {
+    fn income_label(&self) -> String {
+        let reputation = match &self.game.status {
+            GameStatus::Start(b) | GameStatus::InProgress(b) | GameStatus::Finished(b) => {
+                b.current_reputation
+            }
+        };
+        match self.game.reputation_income.band_for(&reputation) {
+            Some(band) => format!(
+                "Income: {} ({} below {} reputation)",
+                self.game.current_income().value(),
+                band.income,
+                band.below.value()
+            ),
+            None => format!("Income: {} (full)", self.game.current_income().value()),
+        }
+    }
+
     pub(crate) fn next_round_controls(&mut self, ui: &mut Ui) {
         ui.add_space(5.0);
         ui.label("Next round");
@@ -21,6 +38,18 @@ impl GameViewState {
             },
         );
 
+        ui.label("Gain people-hours ");
+        self.numeric_enter_component(
+            ui,
+            |game| &mut game.input.next_people_hours,
+            "Set",
+            Command::SetPeopleHoursGain,
+        );
+
+        if !self.game.reputation_income.is_disabled() {
+            ui.label(self.income_label());
+        }
+
         ui.add_space(5.0);
         match &self.game.status {
             GameStatus::Finished(_) => {
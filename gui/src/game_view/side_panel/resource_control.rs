@@ -24,6 +24,11 @@ impl GameViewState {
         let cloned_board = board.clone();
         let available = create_resource_label(&cloned_board, "available");
         ui.label(available);
+        let people_hours = create_people_hours_label(
+            &cloned_board,
+            format!("available (+{} per round)", self.game.people_hours_gain.value()).as_str(),
+        );
+        ui.label(people_hours);
 
         let modifier = create_fix_modifier_label(
             self.game.get_current_fix_modifier(),
@@ -43,15 +48,26 @@ impl GameViewState {
 fn resource_control_game_ended(ui: &mut Ui, board: &Board) {
     let available = create_resource_label(board, "left");
     ui.label(available);
+    let people_hours = create_people_hours_label(board, "left");
+    ui.label(people_hours);
 }
 
 // helper function to create rich text for resource amount,
 // will be used in match arms for GameStatus
 fn create_resource_label(board: &Board, postfix: &str) -> RichText {
-    let resource_str = format!("{} {}", board.current_resources.value(), postfix);
+    let resource_str = format!("{} budget {}", board.current_resources.value(), postfix);
     RichText::new(resource_str).strong()
 }
 
+fn create_people_hours_label(board: &Board, postfix: &str) -> RichText {
+    let people_hours_str = format!(
+        "{} people-hours {}",
+        board.current_people_hours.value(),
+        postfix
+    );
+    RichText::new(people_hours_str).strong()
+}
+
 // function to create fix modifier label
 fn create_fix_modifier_label(
     fix_modifier: Option<CostModifier>,
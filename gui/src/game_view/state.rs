@@ -23,6 +23,7 @@ pub(super) enum Message {
 
 pub(super) struct Input {
     pub(super) next_res: String,
+    pub(super) next_people_hours: String,
     pub(super) pay_res: String,
     pub(super) inc_reputation: String,
     pub(super) dec_reputation: String,
@@ -42,6 +43,7 @@ impl ViewState for GameViewState {
     fn draw_ui(&mut self, _app_event_callback: &mut dyn FnMut(AppEvent), ctx: &Context) {
         self.process_command();
         self.create_side_panel(ctx);
+        self.create_hand_panel(ctx);
         egui::CentralPanel::default().show(ctx, |ui| {
             // The central panel the region left after adding TopPanel's and SidePanel's
             self.update_cards(ctx, ui);
@@ -52,11 +54,13 @@ impl ViewState for GameViewState {
 impl GameViewState {
     pub fn new(game: Game, game_goals: GameGoals, scenario: Option<Rc<Scenario>>) -> Self {
         let initial_gain = game.resource_gain.value().clone();
+        let initial_people_hours_gain = *game.people_hours_gain.value();
         let initial_multiplier = game.fix_multiplier.value().clone();
         GameViewState {
             game,
             input: Input {
                 next_res: initial_gain.to_string(),
+                next_people_hours: initial_people_hours_gain.to_string(),
                 dec_reputation: "0".to_string(),
                 inc_reputation: "0".to_string(),
                 pay_res: "0".to_string(),
@@ -85,6 +89,8 @@ impl GameViewState {
                 &card.0,
                 card.1.clone(),
                 self.game.is_card_activated(&card.0),
+                board.fixes_in_progress.get(&card.0).cloned(),
+                board.bought_controls.contains(&card.0),
                 self.game.fix_multiplier.clone(),
             );
             let mut set_command = |cmd| self.command = Some(cmd);
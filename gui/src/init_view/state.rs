@@ -1,10 +1,11 @@
 use crate::components::label_with_input::{LabelWithInputComponent, LabelWithInputLayoutOptions};
 use crate::{AppEvent, GameGoals, StartGameData, ViewState};
 use eframe::emath::Align;
-use egui::{ComboBox, Context, Layout, RichText, ScrollArea, Ui, Vec2};
+use egui::{Color32, ComboBox, Context, Layout, RichText, ScrollArea, Ui, Vec2};
+use game_lib::cards::game_variants::rule_set::RuleSet;
 use game_lib::cards::game_variants::scenario::Scenario;
 use game_lib::cards::properties::description::Description;
-use game_lib::file::repository::DeckLoader;
+use game_lib::file::repository::{DeckLoader, LoadReport};
 use game_lib::world::deck::{DeckComposition, GameVariantsRepository};
 use game_lib::world::game::GameInitSettings;
 use game_lib::world::reputation::Reputation;
@@ -17,7 +18,14 @@ pub struct InitViewState {
     deck_settings: DeckSettings,
     game_preset: GamePreset,
     scenario_settings: ScenarioSettings,
+    rule_settings: RuleSettings,
     game_goals: GameGoalsControls,
+    load_report: LoadReport,
+}
+
+struct RuleSettings {
+    rule_set_value: String,
+    rule_sets: Vec<Rc<RuleSet>>,
 }
 
 struct DeckSettings {
@@ -25,6 +33,7 @@ struct DeckSettings {
     attack_card_count: LabelWithInputComponent,
     oopsie_card_count: LabelWithInputComponent,
     lucky_card_count: LabelWithInputComponent,
+    control_card_count: LabelWithInputComponent,
     evaluation_card_count: LabelWithInputComponent,
     grace_rounds: LabelWithInputComponent,
 }
@@ -45,6 +54,10 @@ struct GamePreset {
     initial_reputation: LabelWithInputComponent,
     initial_resource_gain: LabelWithInputComponent,
     initial_fix_multiplier: LabelWithInputComponent,
+    market_size: LabelWithInputComponent,
+    hand_size: LabelWithInputComponent,
+    initial_people_hours: LabelWithInputComponent,
+    initial_people_hours_gain: LabelWithInputComponent,
 }
 
 impl Default for GamePreset {
@@ -75,6 +88,26 @@ impl Default for GamePreset {
                 description: Some("The multiplier for fixing cards.".to_string()),
                 value: default.fix_multiplier.value().to_string(),
             },
+            market_size: LabelWithInputComponent {
+                label: "Market offers".to_string(),
+                description: Some("Number of cards which can be bought each round. 0 disables the market.".to_string()),
+                value: default.market_size.to_string(),
+            },
+            hand_size: LabelWithInputComponent {
+                label: "Hand size".to_string(),
+                description: Some("Number of lucky cards the players can keep hidden in their hand. 0 places them on the board.".to_string()),
+                value: default.hand_size.to_string(),
+            },
+            initial_people_hours: LabelWithInputComponent {
+                label: "Initial people-hours".to_string(),
+                description: Some("The staff time you start the game with.".to_string()),
+                value: default.people_hours.value().to_string(),
+            },
+            initial_people_hours_gain: LabelWithInputComponent {
+                label: "Initial people-hours gain".to_string(),
+                description: Some("The people-hours you gain per turn.".to_string()),
+                value: default.people_hours_gain.value().to_string(),
+            },
         }
     }
 }
@@ -102,6 +135,11 @@ impl Default for DeckSettings {
                 description: None,
                 value: "5".to_string(),
             },
+            control_card_count: LabelWithInputComponent {
+                label: "Number of control cards".to_string(),
+                description: Some("Controls have to be bought and protect their targets against attacks.".to_string()),
+                value: "0".to_string(),
+            },
             evaluation_card_count: LabelWithInputComponent {
                 label: "Experimental: Evaluation cards".to_string(),
                 description: Some("The deck will be split into n + 1 parts and all parts except the first will contain an evaluation card. 0 disables them.".to_string()),
@@ -116,6 +154,22 @@ impl Default for DeckSettings {
     }
 }
 
+impl DeckSettings {
+    fn update_from_scenario(&mut self, scenario: &Scenario) {
+        if let Some(deck) = &scenario.deck {
+            self.event_card_count.update(deck.events.to_string());
+            self.attack_card_count.update(deck.attacks.to_string());
+            self.oopsie_card_count.update(deck.oopsies.to_string());
+            self.lucky_card_count.update(deck.lucky.to_string());
+            self.control_card_count.update(deck.controls.to_string());
+            self.evaluation_card_count.update(deck.evaluation.to_string());
+        }
+        if let Some(grace_rounds) = scenario.grace_rounds {
+            self.grace_rounds.update(grace_rounds.to_string());
+        }
+    }
+}
+
 impl Default for GameGoalsControls {
     fn default() -> Self {
         GameGoalsControls {
@@ -155,6 +209,10 @@ impl Into<GameInitSettings> for &GamePreset {
             resources: Resources::new((&self.initial_resources).into()),
             fix_multiplier: ResourceFixMultiplier::new((&self.initial_fix_multiplier).into()),
             reputation: Reputation::new(reputation.min(100).into()),
+            market_size: (&self.market_size).into(),
+            hand_size: (&self.hand_size).into(),
+            people_hours: Resources::new((&self.initial_people_hours).into()),
+            people_hours_gain: Resources::new((&self.initial_people_hours_gain).into()),
         }
     }
 }
@@ -171,15 +229,20 @@ impl Default for ScenarioSettings {
 
 impl InitViewState {
     pub fn new(config: &Config) -> Self {
-        let scenarios = DeckLoader::create(&config.game_path).get_scenarios();
+        let repository = DeckLoader::create(&config.game_path);
         InitViewState {
             scenario_settings: ScenarioSettings {
-                scenarios,
+                scenarios: repository.get_scenarios(),
                 ..ScenarioSettings::default()
             },
+            rule_settings: RuleSettings {
+                rule_set_value: RuleSet::DEFAULT_NAME.to_string(),
+                rule_sets: repository.get_rule_sets(),
+            },
             deck_settings: DeckSettings::default(),
             game_preset: GamePreset::default(),
             game_goals: GameGoalsControls::default(),
+            load_report: repository.load_report(),
         }
     }
 }
@@ -213,6 +276,9 @@ impl InitViewState {
         self.deck_settings
             .lucky_card_count
             .draw_component(0, ui, control_layout_options);
+        self.deck_settings
+            .control_card_count
+            .draw_component(0, ui, control_layout_options);
         self.deck_settings
             .grace_rounds
             .draw_component(0, ui, control_layout_options);
@@ -289,12 +355,23 @@ impl InitViewState {
                 self.game_preset
                     .initial_resource_gain
                     .update(scenario.preset.resource_gain.value().to_string());
+                self.game_preset
+                    .initial_people_hours
+                    .update(scenario.preset.people_hours.value().to_string());
+                self.game_preset
+                    .initial_people_hours_gain
+                    .update(scenario.preset.people_hours_gain.value().to_string());
                 self.game_preset
                     .initial_reputation
                     .update(scenario.preset.reputation.value().to_string());
                 self.game_preset
                     .initial_fix_multiplier
                     .update(scenario.preset.multiplier.value().to_string());
+                self.deck_settings.update_from_scenario(scenario);
+                self.rule_settings.rule_set_value = scenario
+                    .rule_set
+                    .clone()
+                    .unwrap_or(RuleSet::DEFAULT_NAME.to_string());
                 self.game_goals
                     .min_resources
                     .update(scenario.goal.minimum_resources.value().to_string());
@@ -311,12 +388,20 @@ impl InitViewState {
                 self.game_preset
                     .initial_resource_gain
                     .update(default_preset.initial_resource_gain.value);
+                self.game_preset
+                    .initial_people_hours
+                    .update(default_preset.initial_people_hours.value);
+                self.game_preset
+                    .initial_people_hours_gain
+                    .update(default_preset.initial_people_hours_gain.value);
                 self.game_preset
                     .initial_fix_multiplier
                     .update(default_preset.initial_fix_multiplier.value);
                 self.game_preset
                     .initial_reputation
                     .update(default_preset.initial_reputation.value);
+                self.rule_settings.rule_set_value = RuleSet::DEFAULT_NAME.to_string();
+                self.deck_settings = DeckSettings::default();
                 self.game_goals
                     .min_resources
                     .update(default_goals.min_resources.value);
@@ -342,12 +427,50 @@ impl InitViewState {
         self.game_preset
             .initial_resource_gain
             .draw_component(0, ui, control_layout_options);
+        self.game_preset
+            .initial_people_hours
+            .draw_component(0, ui, control_layout_options);
+        self.game_preset
+            .initial_people_hours_gain
+            .draw_component(0, ui, control_layout_options);
         self.game_preset
             .initial_reputation
             .draw_component(0, ui, control_layout_options);
         self.game_preset
             .initial_fix_multiplier
             .draw_component(0, ui, control_layout_options);
+        self.game_preset
+            .market_size
+            .draw_component(0, ui, control_layout_options);
+        self.game_preset
+            .hand_size
+            .draw_component(0, ui, control_layout_options);
+    }
+
+    fn draw_rule_set_selection(&mut self, ui: &mut Ui) {
+        ui.label(RichText::new("Rules").strong());
+        let mut rule_set_values = vec![RuleSet::DEFAULT_NAME];
+        rule_set_values.append(
+            &mut self
+                .rule_settings
+                .rule_sets
+                .iter()
+                .map(|rules| rules.name.as_str())
+                .collect::<Vec<&str>>(),
+        );
+
+        ComboBox::new("rule_sets", "Select Rules")
+            .width(ui.available_width())
+            .selected_text(self.rule_settings.rule_set_value.clone())
+            .show_ui(ui, |ui| {
+                for name in rule_set_values {
+                    ui.selectable_value(
+                        &mut self.rule_settings.rule_set_value,
+                        name.to_string(),
+                        name,
+                    );
+                }
+            });
     }
 
     fn draw_game_goals(&mut self, ui: &mut Ui) {
@@ -367,6 +490,29 @@ impl InitViewState {
             .draw_component(0, ui, control_layout_options);
     }
 
+    fn draw_load_report(&self, ui: &mut Ui) {
+        if self.load_report.is_empty() {
+            return;
+        }
+        ui.label(
+            RichText::new(format!(
+                "{} files were skipped while loading",
+                self.load_report.skipped.len()
+            ))
+            .strong()
+            .color(Color32::YELLOW),
+        );
+        ScrollArea::vertical()
+            .id_source("load_report")
+            .max_height(100.0)
+            .auto_shrink([false, true])
+            .show(ui, |ui| {
+                for error in &self.load_report.skipped {
+                    ui.label(RichText::new(error.to_string()).color(Color32::YELLOW));
+                }
+            });
+    }
+
     fn draw_start_button(&mut self, app_event_callback: &mut dyn FnMut(AppEvent), ui: &mut Ui) {
         if ui.button("Start Game").clicked() {
             let deck_composition = DeckComposition {
@@ -374,6 +520,7 @@ impl InitViewState {
                 attacks: (&self.deck_settings.attack_card_count).into(),
                 oopsies: (&self.deck_settings.oopsie_card_count).into(),
                 lucky: (&self.deck_settings.lucky_card_count).into(),
+                controls: (&self.deck_settings.control_card_count).into(),
                 evaluation: (&self.deck_settings.evaluation_card_count).into(),
             };
             let grace_rounds = (&self.deck_settings.grace_rounds).into();
@@ -385,6 +532,10 @@ impl InitViewState {
                 game_init_settings,
                 game_goals: (&self.game_goals).into(),
                 scenario: self.scenario_settings.current_scenario.clone(),
+                rule_set: RuleSet::select(
+                    &self.rule_settings.rule_sets,
+                    &self.rule_settings.rule_set_value,
+                ),
             };
 
             app_event_callback(AppEvent::start_game(start_game_data))
@@ -405,6 +556,8 @@ impl InitViewState {
                             self.draw_game_deck_settings(ui);
                             ui.set_width(Self::LEFT_COL_WIDTH);
                             self.draw_start_button(app_event_callback, ui);
+                            ui.add_space(Self::DEFAULT_SPACE_Y);
+                            self.draw_load_report(ui);
                         },
                     );
 
@@ -415,6 +568,8 @@ impl InitViewState {
                             ui.set_width(Self::RIGHT_COL_WIDTH);
                             self.draw_scenario_selection(ui);
                             ui.add_space(Self::DEFAULT_SPACE_Y);
+                            self.draw_rule_set_selection(ui);
+                            ui.add_space(Self::DEFAULT_SPACE_Y);
                             self.draw_game_preset(ui);
                             ui.add_space(Self::DEFAULT_SPACE_Y);
                             self.draw_game_goals(ui);
@@ -441,9 +596,11 @@ impl InitViewState {
 
                     self.draw_game_deck_settings(ui);
                     self.draw_scenario_selection(ui);
+                    self.draw_rule_set_selection(ui);
                     self.draw_game_preset(ui);
                     self.draw_game_goals(ui);
                     self.draw_start_button(app_event_callback, ui);
+                    self.draw_load_report(ui);
                 },
             );
         });
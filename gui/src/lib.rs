@@ -1,5 +1,6 @@
 use crate::game_view::state::GameViewState;
 use egui::Context;
+use game_lib::cards::game_variants::rule_set::RuleSet;
 use game_lib::cards::game_variants::scenario::Scenario;
 use game_lib::world::deck::DeckComposition;
 use game_lib::world::game::GameInitSettings;
@@ -7,6 +8,9 @@ use game_setup::config::config::Config;
 use std::rc::Rc;
 
 mod app;
+pub mod card_gallery;
+pub mod card_sheet;
+pub mod card_text;
 mod components;
 mod game_view;
 mod init_view;
@@ -23,6 +27,7 @@ pub(crate) struct StartGameData {
     pub game_init_settings: GameInitSettings,
     pub game_goals: GameGoals,
     pub scenario: Option<Rc<Scenario>>,
+    pub rule_set: RuleSet,
 }
 
 #[derive(Debug, Clone, Copy)]
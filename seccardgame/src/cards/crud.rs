@@ -1,28 +1,44 @@
+use std::fs;
+use std::path::Path;
+use std::rc::Rc;
+
 use dialoguer::{Confirm, Editor, Select};
 use game_lib::cards::properties::incident_impact::{IncidentImpact};
 use game_lib::cards::properties::description::Description;
 use game_lib::cards::properties::duration::Duration;
 use game_lib::cards::properties::effect::Effect;
 use game_lib::cards::properties::effect_description::EffectDescription;
+use game_lib::cards::properties::escalation::Escalation;
 use game_lib::cards::properties::fix_cost::FixCost;
+use game_lib::cards::properties::mitigation::Mitigation;
 use game_lib::cards::properties::cost_modifier::CostModifier;
+use game_lib::cards::properties::decision::{Consequence, DecisionOption};
 use game_lib::cards::properties::target::Target;
+use game_lib::cards::properties::target_taxonomy::TargetTaxonomy;
 use game_lib::cards::properties::title::Title;
 use game_lib::cards::types::attack::AttackCard;
-use game_lib::cards::types::card_model::{Card, CardCategory};
+use game_lib::cards::types::card_model::{Card, CardCategory, CardTrait};
+use game_lib::cards::types::control::ControlCard;
 use game_lib::cards::types::event::EventCard;
 use game_lib::cards::types::lucky::LuckyCard;
 use game_lib::cards::types::oopsie::OopsieCard;
+use game_lib::file::cards::{delete_card_file, update_card_file};
+use game_lib::file::lint::lint_card;
+use game_lib::file::repository::{CardFile, DeckLoader};
+use game_lib::world::deck::TargetTaxonomyRepository;
+use game_lib::world::reputation::Reputation;
 use game_lib::world::resources::Resources;
-use log::error;
+use log::{error, warn};
 use game_lib::world::part_of_hundred::PartOfHundred;
 use game_setup::config::config::Config;
+use game_ui::card_text::card_to_text;
+use crate::cards::normalized_title;
 use crate::cards::stats::print_stats;
 use crate::cli::cli_result::ErrorKind::FileSystemError;
 use crate::cli::cli_result::{CliError, CliResult, ErrorKind};
 use crate::cli::prompts::prompt;
 
-fn write_card_to_file(card: &Card, cfg: &Config) -> CliResult<()> {
+fn write_card_to_file(card: &Card, cfg: &Config, taxonomy: &TargetTaxonomy) -> CliResult<()> {
     let mut card_to_save: Card = card.clone();
     if Confirm::new()
         .with_prompt("Do you want to edit this card?")
@@ -51,6 +67,14 @@ fn write_card_to_file(card: &Card, cfg: &Config) -> CliResult<()> {
             serde_json::to_string_pretty(&card_to_save).unwrap()
         );
     }
+    let unknown_targets = taxonomy.unknown_targets(card_to_save.effect().targets());
+    if !unknown_targets.is_empty() {
+        let names: Vec<&str> = unknown_targets.iter().map(|t| t.value()).collect();
+        warn!(
+            "The card uses targets which are not part of the taxonomy: {}",
+            names.join(", ")
+        );
+    }
     if Confirm::new()
         .with_prompt("Do you confirm these details?")
         .interact()
@@ -76,6 +100,7 @@ fn deserialize_editor_content(content: String, original_card: &Card) -> serde_js
         Card::Attack(_) => serde_json::from_str(content.as_str()).map(|c| Card::Attack(c)),
         Card::Oopsie(_) => serde_json::from_str(content.as_str()).map(|c| Card::Oopsie(c)),
         Card::Lucky(_) => serde_json::from_str(content.as_str()).map(|c| Card::Lucky(c)),
+        Card::Control(_) => serde_json::from_str(content.as_str()).map(Card::Control),
         Card::Evaluation(_) => panic!("Cannot deserialize evaluation card"),
     }
 }
@@ -88,6 +113,8 @@ pub fn create(cfg: &Config) -> CliResult<()> {
         .filter(|i| i.ne(&&&Card::EVALUATION))
         .collect();
 
+    let taxonomy = DeckLoader::create(cfg.game_path.as_str()).get_target_taxonomy();
+
     let card_type_index = Select::new()
         .with_prompt("Select a card type to create")
         .items(&creatable_card_types)
@@ -97,9 +124,10 @@ pub fn create(cfg: &Config) -> CliResult<()> {
 
     let card = match *creatable_card_types[card_type_index] {
         &Card::EVENT_CARD => create_event_card(),
-        &Card::ATTACK_CARD => create_attack_card(),
+        &Card::ATTACK_CARD => create_attack_card(&taxonomy),
         &Card::LUCKY_CARD => create_lucky_card(),
-        &Card::OOPSIE_CARD => create_oopsie_card(),
+        &Card::OOPSIE_CARD => create_oopsie_card(&taxonomy),
+        &Card::CONTROL_CARD => create_control_card(&taxonomy),
         _ => {
             return Err(CliError::new(
                 ErrorKind::CardError,
@@ -109,7 +137,153 @@ pub fn create(cfg: &Config) -> CliResult<()> {
         }
     };
 
-    write_card_to_file(&card, cfg)
+    write_card_to_file(&card, cfg, &taxonomy)
+}
+
+/// Opens a stored card in the editor. The edited card is validated like `cards validate` does and only
+/// saved if it is valid and its title is not used by another card, a new title renames the file but
+/// keeps its number. Files which can not be loaded can be edited too, so they can be repaired.
+pub fn edit(cfg: &Config, id: Option<&String>) -> CliResult<()> {
+    let loader = DeckLoader::create(cfg.game_path.as_str());
+    let file = select_card_file(&loader, id)?;
+    let taxonomy = loader.get_target_taxonomy();
+    let directory = file
+        .path
+        .parent()
+        .and_then(|p| p.file_name())
+        .and_then(|d| d.to_str())
+        .unwrap_or_default()
+        .to_string();
+
+    let mut content = fs::read_to_string(&file.path).map_err(|e| {
+        CliError::new(FileSystemError, "Could not read card", Some(e.to_string()))
+    })?;
+    loop {
+        let edited = match Editor::new().extension(".json").edit(content.as_str()) {
+            Ok(Some(edited)) => edited,
+            Ok(None) => {
+                println!("Card was not changed");
+                return Ok(());
+            }
+            Err(e) => {
+                return Err(CliError::new(
+                    ErrorKind::CardError,
+                    "Could not edit card",
+                    Some(e.to_string()),
+                ))
+            }
+        };
+        match lint_card(edited.as_str(), directory.as_str(), &taxonomy) {
+            (Some(card), issues) if issues.is_empty() => match title_used_by(&loader, &card, &file.path) {
+                Some(other) => println!(
+                    "{}: title '{}' is already used by {}",
+                    file.path.display(),
+                    card.title().value(),
+                    other
+                ),
+                None => return save_edited_card(&card, &file.path),
+            },
+            (_, issues) => {
+                for issue in issues {
+                    println!("{}:{}: {}", file.path.display(), issue.line, issue.message);
+                }
+            }
+        }
+        if !Confirm::new()
+            .with_prompt("The card is invalid. Do you want to edit it again?")
+            .interact()
+            .unwrap()
+        {
+            println!("Cancelled!");
+            return Ok(());
+        }
+        content = edited;
+    }
+}
+
+/// Id of another stored card with the same title as the card.
+pub(crate) fn title_used_by(loader: &DeckLoader, card: &Card, path: &Path) -> Option<String> {
+    let title = normalized_title(card);
+    loader
+        .get_stored_cards()
+        .into_iter()
+        .find(|stored| stored.path != path && normalized_title(&stored.card) == title)
+        .map(|stored| stored.id)
+}
+
+fn save_edited_card(card: &Card, path: &Path) -> CliResult<()> {
+    println!("{}", card_to_text(Rc::new(card.clone())));
+    if !Confirm::new()
+        .with_prompt("Do you want to save these changes?")
+        .interact()
+        .unwrap()
+    {
+        println!("Cancelled!");
+        return Ok(());
+    }
+    let path = update_card_file(card, path).map_err(|e| {
+        CliError::new(FileSystemError, "Could not write to file!", Some(e.to_string()))
+    })?;
+    println!("Wrote to file {}", path.display());
+    Ok(())
+}
+
+/// Deletes a stored card. The following cards of its directory are renumbered, so their ids change.
+pub fn delete(cfg: &Config, id: Option<&String>) -> CliResult<()> {
+    let loader = DeckLoader::create(cfg.game_path.as_str());
+    let file = select_card_file(&loader, id)?;
+    match &file.card {
+        Ok(card) => println!("{}", card_to_text(card.clone())),
+        Err(err) => println!("{} can not be loaded: {}", file.path.display(), err),
+    }
+    if !Confirm::new()
+        .with_prompt("Do you really want to delete this card?")
+        .interact()
+        .unwrap()
+    {
+        println!("Cancelled!");
+        return Ok(());
+    }
+    let renamed = delete_card_file(&file.path).map_err(|e| {
+        CliError::new(FileSystemError, "Could not delete card", Some(e.to_string()))
+    })?;
+    println!("Deleted {}", file.path.display());
+    for (from, to) in renamed {
+        println!("Renamed {} to {}", from.display(), to.display());
+    }
+    Ok(())
+}
+
+/// The card file with the given id or, without id, the file the user selects from all card files.
+/// Files which can not be loaded are offered too.
+pub(crate) fn select_card_file(loader: &DeckLoader, id: Option<&String>) -> CliResult<CardFile> {
+    let mut files = loader.get_card_files();
+    if let Some(id) = id {
+        return files.into_iter().find(|file| &file.id == id).ok_or_else(|| {
+            CliError::new(
+                ErrorKind::CardError,
+                format!("Card '{}' not found", id).as_str(),
+                None,
+            )
+        });
+    }
+    if files.is_empty() {
+        return Err(CliError::new(ErrorKind::CardError, "No cards found", None));
+    }
+    let items: Vec<String> = files
+        .iter()
+        .map(|file| match &file.card {
+            Ok(card) => format!("{} {}", file.id, card.title().value()),
+            Err(_) => format!("{} (invalid) {}", file.id, file.path.display()),
+        })
+        .collect();
+    let selection = Select::new()
+        .with_prompt("Select a card")
+        .items(&items)
+        .default(0)
+        .interact()
+        .unwrap();
+    Ok(files.swap_remove(selection))
 }
 
 fn create_event_card() -> Card {
@@ -118,11 +292,13 @@ fn create_event_card() -> Card {
     let description: String = prompt("Card description", None);
 
     let effect = ask_for_modifying_effect();
+    let options = ask_for_decision_options();
 
     let card = EventCard {
         title: Title::from(title),
         description: Description::from(description),
         effect,
+        options,
     };
 
     println!("{}", serde_json::to_string_pretty(&card).unwrap());
@@ -164,24 +340,79 @@ fn ask_for_modifying_effect() -> Effect {
     }
 }
 
-fn create_attack_card() -> Card {
+fn ask_for_decision_options() -> Vec<DecisionOption> {
+    let mut options = Vec::new();
+    if !Confirm::new()
+        .with_prompt("Does this event let the players decide between options?")
+        .interact()
+        .unwrap()
+    {
+        return options;
+    }
+    loop {
+        let label: String = prompt("Option label", None);
+        let consequences = ask_for_consequences();
+        options.push(DecisionOption::new(label.as_str(), consequences));
+        if options.len() >= 2
+            && !Confirm::new()
+                .with_prompt("Add another option?")
+                .interact()
+                .unwrap()
+        {
+            return options;
+        }
+    }
+}
+
+fn ask_for_consequences() -> Vec<Consequence> {
+    let available_consequences = [
+        "Pay resources",
+        "Gain resources",
+        "Lose reputation",
+        "Gain reputation",
+        "Finished",
+    ];
+    let mut consequences = Vec::new();
+    loop {
+        let selection = Select::new()
+            .with_prompt("Add consequence (use Finished to end)")
+            .items(&available_consequences)
+            .default(0)
+            .interact()
+            .unwrap();
+        let consequence = match selection {
+            0 => Consequence::PayResources(Resources::new(prompt("Amount of resources", None))),
+            1 => Consequence::GainResources(Resources::new(prompt("Amount of resources", None))),
+            2 => Consequence::LoseReputation(Reputation::new(prompt("Reputation (int)", None))),
+            3 => Consequence::GainReputation(Reputation::new(prompt("Reputation (int)", None))),
+            _ => return consequences,
+        };
+        consequences.push(consequence);
+    }
+}
+
+fn create_attack_card(taxonomy: &TargetTaxonomy) -> Card {
     println!("Create a new Attack Card");
     let title: String = prompt("Card title", None);
     let description: String = prompt("Card description", None);
     let effect: String = prompt("Card Effect", None);
     let cost = ask_for_cost();
+    let escalation = ask_for_escalation();
     let duration: usize = prompt("Duration (rounds)", None);
 
-    let targets = ask_for_targets();
+    let targets = ask_for_targets(taxonomy);
 
-    let card = AttackCard::new(
-        Title::from(title),
-        Description::from(description),
-        targets.iter().map(|t| Target::from(t.clone())).collect(),
-        EffectDescription::from(effect),
-        cost,
-        Duration::Rounds(duration),
-    );
+    let card = AttackCard {
+        escalation,
+        ..AttackCard::new(
+            Title::from(title),
+            Description::from(description),
+            targets.iter().map(|t| Target::from(t.clone())).collect(),
+            EffectDescription::from(effect),
+            cost,
+            Duration::Rounds(duration),
+        )
+    };
 
     println!("{}", serde_json::to_string_pretty(&card).unwrap());
 
@@ -211,22 +442,34 @@ fn ask_for_cost() -> IncidentImpact {
     }
 }
 
-fn ask_for_targets() -> Vec<String> {
+fn ask_for_escalation() -> Escalation {
+    let available_escalations = ["No escalation", "Every round", "Every few rounds"];
+
+    let selection = Select::new()
+        .with_prompt("Select how the impact escalates while the incident is unresolved")
+        .items(&available_escalations)
+        .default(0)
+        .interact()
+        .unwrap();
+
+    match selection {
+        0 => Escalation::None,
+        1 => Escalation::Linear(prompt("Increase per round (int)", None)),
+        2 => {
+            let every = prompt("Escalate every n rounds (int)", None);
+            let increase = prompt("Increase per step (int)", None);
+            Escalation::Stepped { every, increase }
+        },
+        _ => panic!("Unknown escalation type")
+    }
+}
+
+fn ask_for_targets(taxonomy: &TargetTaxonomy) -> Vec<String> {
     println!("Add targets of this card, enter a blank target when finished");
     let mut targets: Vec<String> = Vec::new();
-    let available_targets = [
-        "backend",
-        "backup",
-        "database",
-        "frontend",
-        "hardware",
-        "infrastructure",
-        "mobile",
-        "network",
-        "social",
-        "supply chain",
-        "Finished",
-    ];
+    let known_targets = taxonomy.all_targets();
+    let mut available_targets: Vec<&str> = known_targets.iter().map(|t| t.value()).collect();
+    available_targets.push("Finished");
     loop {
         let selection = Select::new()
             .with_prompt("Add target (use Finished to end)")
@@ -248,11 +491,13 @@ fn create_lucky_card() -> Card {
     let title: String = prompt("Card title", None);
     let description: String = prompt("Card description", None);
     let effect = ask_for_modifying_effect();
+    let price: usize = prompt("Price in the market (0 if it can not be bought)", None);
 
     let card = LuckyCard {
         title: Title::from(title),
         description: Description::from(description),
         effect,
+        price: if price > 0 { Some(Resources::new(price)) } else { None },
     };
 
     println!("{}", serde_json::to_string_pretty(&card).unwrap());
@@ -260,12 +505,12 @@ fn create_lucky_card() -> Card {
     Card::Lucky(card)
 }
 
-fn create_oopsie_card() -> Card {
+fn create_oopsie_card(taxonomy: &TargetTaxonomy) -> Card {
     println!("Create a new Oopsie Card");
     let title: String = prompt("Card title", None);
     let description: String = prompt("Card description", None);
     let effect: String = prompt("Card Effect", None);
-    let targets = ask_for_targets();
+    let targets = ask_for_targets(taxonomy);
     let mut min_cost: usize;
     let mut max_cost: usize;
     loop {
@@ -276,18 +521,77 @@ fn create_oopsie_card() -> Card {
         }
         println!("Max cost must be greater or equal to min cost.")
     }
-    let card = OopsieCard::new(
+    let fix_rounds: usize = prompt("Rounds needed to fix (1 for an instant fix)", None);
+    let people_hours: usize = prompt("People-hours needed to fix (0 for none)", None);
+    let card = OopsieCard {
+        fix_rounds: if fix_rounds > 1 { Some(fix_rounds) } else { None },
+        ..OopsieCard::new(
+            Title::from(title),
+            Description::from(description),
+            targets.iter().map(|t| Target::from(t.clone())).collect(),
+            EffectDescription::from(effect),
+            FixCost {
+                min: Resources::new(min_cost),
+                max: Resources::new(max_cost),
+                people_hours: if people_hours > 0 {
+                    Some(Resources::new(people_hours))
+                } else {
+                    None
+                },
+            },
+        )
+    };
+
+    println!("{}", serde_json::to_string_pretty(&card).unwrap());
+
+    Card::Oopsie(card)
+}
+
+fn create_control_card(taxonomy: &TargetTaxonomy) -> Card {
+    println!("Create a new Control Card");
+    let title: String = prompt("Card title", None);
+    let description: String = prompt("Card description", None);
+    let effect: String = prompt("Card Effect", None);
+    let targets = ask_for_targets(taxonomy);
+    let mitigation = ask_for_mitigation();
+    let cost: usize = prompt("Costs to buy the control", None);
+
+    let card = ControlCard::new(
         Title::from(title),
         Description::from(description),
         targets.iter().map(|t| Target::from(t.clone())).collect(),
         EffectDescription::from(effect),
-        FixCost {
-            min: Resources::new(min_cost),
-            max: Resources::new(max_cost),
-        },
+        mitigation,
+        Resources::new(cost),
     );
 
     println!("{}", serde_json::to_string_pretty(&card).unwrap());
 
-    Card::Oopsie(card)
+    Card::Control(card)
+}
+
+fn ask_for_mitigation() -> Mitigation {
+    let available_mitigations = ["Block incidents", "Reduce incident impact"];
+
+    let selection = Select::new()
+        .with_prompt("Select how the control protects its targets")
+        .items(&available_mitigations)
+        .default(0)
+        .interact()
+        .unwrap();
+
+    match selection {
+        0 => Mitigation::Block,
+        1 => {
+            let percentage = prompt(
+                "Reduction of the impact (0-100, int)",
+                Some(Box::new(|value: &u8| match *value {
+                    0..=100 => Ok(()),
+                    _ => Err(format!("Reduction must be between 0 and 100, was {}", value)),
+                })),
+            );
+            Mitigation::Reduce(PartOfHundred::new(percentage))
+        },
+        _ => panic!("Unknown mitigation type")
+    }
 }
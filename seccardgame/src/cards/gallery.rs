@@ -0,0 +1,33 @@
+use std::fs;
+use std::path::Path;
+
+use log::info;
+
+use game_lib::file::repository::DeckLoader;
+use game_lib::world::deck::{GameVariantsRepository, TargetTaxonomyRepository};
+use game_setup::config::config::Config;
+use game_ui::card_gallery::gallery_pages;
+
+use crate::cli::cli_result::{CliError, CliResult};
+
+/// Writes the static gallery of all cards and scenarios of the repository into the directory.
+pub fn gallery(cfg: &Config, out_dir: &str) -> CliResult<()> {
+    let loader = DeckLoader::create(cfg.game_path.as_str());
+    let pages = gallery_pages(
+        &loader.get_stored_cards(),
+        &loader.get_scenarios(),
+        &loader.get_target_taxonomy(),
+    );
+
+    fs::create_dir_all(out_dir).map_err(|e| CliError::write_error(out_dir, e))?;
+    for page in &pages {
+        let path = Path::new(out_dir).join(&page.file_name);
+        fs::write(&path, &page.content).map_err(|e| CliError::write_error(&path, e))?;
+    }
+    info!(
+        "Gallery written to {}, open {}",
+        out_dir,
+        Path::new(out_dir).join("index.html").display()
+    );
+    Ok(())
+}
@@ -0,0 +1,116 @@
+use std::collections::HashSet;
+use std::fs::File;
+use std::io;
+
+use log::warn;
+
+use game_lib::cards::types::card_model::{Card, CardTrait};
+use game_lib::file::card_csv::{read_cards_csv, write_cards_csv, CardRow};
+use game_lib::file::cards::write_card_to_file;
+use game_lib::file::lint::lint_new_card;
+use game_lib::file::repository::DeckLoader;
+use game_lib::world::deck::TargetTaxonomyRepository;
+use game_setup::config::config::Config;
+
+use crate::cards::normalized_title;
+use crate::cli::cli_result::ErrorKind::FileSystemError;
+use crate::cli::cli_result::{CliError, CliResult, ErrorKind};
+
+/// Writes all cards of the repository as CSV into the file or to stdout.
+pub fn export(cfg: &Config, output: Option<&String>) -> CliResult<()> {
+    let stored_cards = DeckLoader::create(cfg.game_path.as_str()).get_stored_cards();
+    for stored in &stored_cards {
+        if CardRow::loses_details(&stored.card) {
+            warn!(
+                "{} has follow-ups, escalation or options which are not exported",
+                stored.id
+            );
+        }
+    }
+    let cards: Vec<&Card> = stored_cards.iter().map(|stored| stored.card.as_ref()).collect();
+    let result = match output {
+        Some(path) => File::create(path).and_then(|file| write_cards_csv(&cards, file)),
+        None => write_cards_csv(&cards, io::stdout()),
+    };
+    result.map_err(|e| CliError::new(FileSystemError, "Could not export cards", Some(e.to_string())))
+}
+
+/// Creates a card for every row of the CSV file. Rows which are no valid card or whose title is already
+/// used are rejected with the reason, cards are only created if no row is rejected. In a dry run
+/// nothing is written.
+pub fn import(cfg: &Config, path: &str, dry_run: bool) -> CliResult<()> {
+    let file = File::open(path).map_err(|e| {
+        CliError::new(
+            FileSystemError,
+            format!("Could not read {}", path).as_str(),
+            Some(e.to_string()),
+        )
+    })?;
+    let rows = read_cards_csv(file).map_err(|e| {
+        CliError::new(
+            ErrorKind::CardError,
+            format!("{} is no valid CSV file", path).as_str(),
+            Some(e.to_string()),
+        )
+    })?;
+    let loader = DeckLoader::create(cfg.game_path.as_str());
+    let taxonomy = loader.get_target_taxonomy();
+    let mut titles: HashSet<String> = loader
+        .get_stored_cards()
+        .iter()
+        .map(|stored| normalized_title(&stored.card))
+        .collect();
+
+    let mut valid_cards = vec![];
+    let mut rejected = 0;
+    for row in rows {
+        let problems: Vec<String> = match &row.card {
+            Ok(card) => {
+                let content = serde_json::to_string_pretty(card).unwrap();
+                let mut problems: Vec<String> = lint_new_card(content.as_str(), &taxonomy)
+                    .1
+                    .into_iter()
+                    .map(|issue| issue.message)
+                    .collect();
+                if !titles.insert(normalized_title(card)) {
+                    problems.push(format!("title '{}' is already used", card.title().value()));
+                }
+                problems
+            }
+            Err(e) => vec![e.clone()],
+        };
+        match row.card {
+            Ok(card) if problems.is_empty() => valid_cards.push((row.line, card)),
+            _ => {
+                rejected += 1;
+                println!("{}:{}: rejected: {}", path, row.line, problems.join(", "));
+            }
+        }
+    }
+    if rejected > 0 {
+        return Err(CliError::new(
+            ErrorKind::CardError,
+            format!("{} rows of {} are no valid cards, no card was created", rejected, path).as_str(),
+            None,
+        ));
+    }
+
+    for (line, card) in &valid_cards {
+        if dry_run {
+            println!(
+                "{}:{}: would create {} '{}'",
+                path,
+                line,
+                card.category(),
+                card.title().value()
+            );
+        } else {
+            write_card_to_file(card, Some(cfg.game_path.as_str())).map_err(|e| {
+                CliError::new(FileSystemError, "Could not write to file!", Some(e.to_string()))
+            })?;
+        }
+    }
+    let verb = if dry_run { "would be created" } else { "created" };
+    println!("{} cards {}", valid_cards.len(), verb);
+    Ok(())
+}
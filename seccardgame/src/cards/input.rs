@@ -0,0 +1,455 @@
+use std::collections::HashSet;
+use std::fs;
+use std::io;
+use std::io::Read;
+use std::path::Path;
+
+use clap::ArgMatches;
+use serde_json::Value;
+
+use game_lib::cards::properties::cost_modifier::CostModifier;
+use game_lib::cards::properties::description::Description;
+use game_lib::cards::properties::duration::Duration;
+use game_lib::cards::properties::effect::Effect;
+use game_lib::cards::properties::effect_description::EffectDescription;
+use game_lib::cards::properties::fix_cost::FixCost;
+use game_lib::cards::properties::incident_impact::IncidentImpact;
+use game_lib::cards::properties::mitigation::Mitigation;
+use game_lib::cards::properties::target::Target;
+use game_lib::cards::properties::target_taxonomy::TargetTaxonomy;
+use game_lib::cards::properties::title::Title;
+use game_lib::cards::types::attack::AttackCard;
+use game_lib::cards::types::card_model::{Card, CardTrait};
+use game_lib::cards::types::control::ControlCard;
+use game_lib::cards::types::event::EventCard;
+use game_lib::cards::types::lucky::LuckyCard;
+use game_lib::cards::types::oopsie::OopsieCard;
+use game_lib::file::cards::{update_card_file, write_card_to_file};
+use game_lib::file::lint::{lint_card, lint_new_card};
+use game_lib::file::repository::DeckLoader;
+use game_lib::world::deck::TargetTaxonomyRepository;
+use game_lib::world::part_of_hundred::PartOfHundred;
+use game_lib::world::resources::Resources;
+use game_setup::config::config::Config;
+
+use crate::cards::crud::{select_card_file, title_used_by};
+use crate::cards::normalized_title;
+use crate::cli::cli_result::ErrorKind::FileSystemError;
+use crate::cli::cli_result::{CliError, CliResult, ErrorKind};
+
+/*
+Creates and edits cards without prompts, either from a JSON or YAML document or from command line
+flags. A document may contain a single card or a list of cards. All cards are checked with the rules
+of `cards validate` first and are only written if every card is valid.
+ */
+
+/// Reads the cards from the file, `-` reads them from stdin.
+pub fn create_from_file(cfg: &Config, source: &str) -> CliResult<()> {
+    let (content, is_yaml) = read_document(source)?;
+    let contents = card_contents(content, is_yaml).map_err(|e| invalid_document(source, e))?;
+    write_valid_cards(cfg, source, contents, !is_yaml)
+}
+
+/// Replaces the stored card with the card of the file, `-` reads it from stdin. The card is checked
+/// like `cards edit` checks the card of the editor, a new title renames the file but keeps its number.
+pub fn edit_from_file(cfg: &Config, id: &str, source: &str) -> CliResult<()> {
+    let (content, is_yaml) = read_document(source)?;
+    let content = match card_contents(content, is_yaml).map_err(|e| invalid_document(source, e))? {
+        contents if contents.len() == 1 => contents.into_iter().next().unwrap(),
+        contents => {
+            return Err(CliError::new(
+                ErrorKind::CardError,
+                format!("{} must contain one card, found {}", source, contents.len()).as_str(),
+                None,
+            ))
+        }
+    };
+
+    let loader = DeckLoader::create(cfg.game_path.as_str());
+    let file = select_card_file(&loader, Some(&id.to_string()))?;
+    let directory = file
+        .path
+        .parent()
+        .and_then(|p| p.file_name())
+        .and_then(|d| d.to_str())
+        .unwrap_or_default()
+        .to_string();
+    let (card, issues) = lint_card(content.as_str(), directory.as_str(), &loader.get_target_taxonomy());
+    for issue in &issues {
+        match is_yaml {
+            true => println!("{}: {}", source, issue.message),
+            false => println!("{}:{}: {}", source, issue.line, issue.message),
+        }
+    }
+    let mut problems = issues.len();
+    let card = card.filter(|_| issues.is_empty());
+    if let Some(card) = &card {
+        if let Some(other) = title_used_by(&loader, card, &file.path) {
+            println!("{}: title '{}' is already used by {}", source, card.title().value(), other);
+            problems += 1;
+        }
+    }
+    match card {
+        Some(card) if problems == 0 => {
+            let path = update_card_file(&card, &file.path).map_err(|e| {
+                CliError::new(FileSystemError, "Could not write to file!", Some(e.to_string()))
+            })?;
+            println!("Wrote to file {}", path.display());
+            Ok(())
+        }
+        _ => Err(CliError::new(
+            ErrorKind::CardError,
+            format!("Found {} problems, {} was not changed", problems, file.id).as_str(),
+            None,
+        )),
+    }
+}
+
+/// Reads the document and tells if it is YAML. YAML is detected by the file extension, stdin is read
+/// as JSON if it starts with `{` or `[` and as YAML otherwise.
+fn read_document(source: &str) -> CliResult<(String, bool)> {
+    Ok(if source == "-" {
+        let mut content = String::new();
+        io::stdin().read_to_string(&mut content).map_err(|e| {
+            CliError::new(FileSystemError, "Could not read stdin", Some(e.to_string()))
+        })?;
+        let is_yaml = !content.trim_start().starts_with(['{', '[']);
+        (content, is_yaml)
+    } else {
+        let content = fs::read_to_string(source).map_err(|e| {
+            CliError::new(
+                FileSystemError,
+                format!("Could not read {}", source).as_str(),
+                Some(e.to_string()),
+            )
+        })?;
+        let is_yaml = Path::new(source)
+            .extension()
+            .is_some_and(|ext| ext == "yaml" || ext == "yml");
+        (content, is_yaml)
+    })
+}
+
+/// Splits the document into the JSON contents of its cards.
+fn card_contents(content: String, is_yaml: bool) -> Result<Vec<String>, String> {
+    let document: Value = if is_yaml {
+        serde_norway::from_str(content.as_str()).map_err(|e| e.to_string())?
+    } else {
+        serde_json::from_str(content.as_str()).map_err(|e| e.to_string())?
+    };
+    let cards = match document {
+        Value::Array(cards) => cards,
+        card => vec![card],
+    };
+    // line numbers only help if the card is checked in the form it was written in
+    Ok(match cards.as_slice() {
+        [_] if !is_yaml => vec![content],
+        _ => cards
+            .iter()
+            .map(|card| serde_json::to_string_pretty(card).unwrap())
+            .collect(),
+    })
+}
+
+fn invalid_document(source: &str, message: String) -> CliError {
+    CliError::new(
+        ErrorKind::CardError,
+        format!("{} contains no valid JSON or YAML", source).as_str(),
+        Some(message),
+    )
+}
+
+/// Creates a card from the flags of `cards create`, e.g. `--type attack --title DDoS --target network`.
+pub fn create_from_flags(cfg: &Config, matches: &ArgMatches) -> CliResult<()> {
+    let card = card_from_flags(matches)?;
+    let content = serde_json::to_string_pretty(&card).unwrap();
+    write_valid_cards(cfg, "flags", vec![content], false)
+}
+
+fn write_valid_cards(
+    cfg: &Config,
+    source: &str,
+    contents: Vec<String>,
+    with_lines: bool,
+) -> CliResult<()> {
+    let loader = DeckLoader::create(cfg.game_path.as_str());
+    let taxonomy = loader.get_target_taxonomy();
+    let titles: HashSet<String> = loader
+        .get_stored_cards()
+        .iter()
+        .map(|stored| normalized_title(&stored.card))
+        .collect();
+    let cards = validate_cards(source, &contents, with_lines, &taxonomy, titles)?;
+    for card in &cards {
+        write_card_to_file(card, Some(cfg.game_path.as_str())).map_err(|e| {
+            CliError::new(FileSystemError, "Could not write to file!", Some(e.to_string()))
+        })?;
+    }
+    Ok(())
+}
+
+/// Checks the cards like `cards validate` does. Titles must neither be used by another card of the
+/// batch nor by one of the given titles of stored cards.
+fn validate_cards(
+    source: &str,
+    contents: &[String],
+    with_lines: bool,
+    taxonomy: &TargetTaxonomy,
+    mut titles: HashSet<String>,
+) -> CliResult<Vec<Card>> {
+    let mut cards = vec![];
+    let mut problems = 0;
+    for (index, content) in contents.iter().enumerate() {
+        let location = match contents.len() {
+            1 => source.to_string(),
+            _ => format!("{}[{}]", source, index),
+        };
+        let (card, issues) = lint_new_card(content.as_str(), taxonomy);
+        for issue in &issues {
+            match with_lines {
+                true => println!("{}:{}: {}", location, issue.line, issue.message),
+                false => println!("{}: {}", location, issue.message),
+            }
+        }
+        problems += issues.len();
+        if let Some(card) = card.filter(|_| issues.is_empty()) {
+            if titles.insert(normalized_title(&card)) {
+                cards.push(card);
+            } else {
+                println!("{}: title '{}' is already used", location, card.title().value());
+                problems += 1;
+            }
+        }
+    }
+    if problems > 0 {
+        return Err(CliError::new(
+            ErrorKind::CardError,
+            format!("Found {} problems, no card was written", problems).as_str(),
+            None,
+        ));
+    }
+    Ok(cards)
+}
+
+fn card_from_flags(matches: &ArgMatches) -> CliResult<Card> {
+    let card_type = matches.get_one::<String>("type").unwrap();
+    let title = Title::from(string_flag(matches, "title"));
+    let description = Description::from(string_flag(matches, "description"));
+    let effect = EffectDescription::from(string_flag(matches, "effect"));
+    let targets: Vec<Target> = matches
+        .get_many::<String>("target")
+        .unwrap_or_default()
+        .map(|target| Target::from(target.clone()))
+        .collect();
+
+    let card = match card_type.to_lowercase().as_str() {
+        "event" => Card::Event(EventCard {
+            title,
+            description,
+            effect: modifying_effect(matches, effect),
+            options: vec![],
+        }),
+        "lucky" => Card::Lucky(LuckyCard {
+            title,
+            description,
+            effect: modifying_effect(matches, effect),
+            price: matches.get_one::<usize>("price").map(|p| Resources::new(*p)),
+        }),
+        "attack" => {
+            let impact = match (
+                matches.get_one::<u8>("revenue-share"),
+                matches.get_one::<usize>("damage"),
+            ) {
+                (Some(percentage), None) => IncidentImpact::PartOfRevenue(PartOfHundred::new(*percentage)),
+                (None, Some(resources)) => IncidentImpact::Fixed(Resources::new(*resources)),
+                _ => return Err(missing_flag("attack cards need either --revenue-share or --damage")),
+            };
+            let duration = *matches
+                .get_one::<usize>("duration")
+                .ok_or_else(|| missing_flag("attack cards need --duration"))?;
+            Card::Attack(AttackCard::new(
+                title,
+                description,
+                targets,
+                effect,
+                impact,
+                Duration::Rounds(duration),
+            ))
+        }
+        "oopsie" => {
+            let min = matches.get_one::<usize>("min-cost");
+            let max = matches.get_one::<usize>("max-cost");
+            let (min, max) = match (min, max) {
+                (Some(min), Some(max)) => (*min, *max),
+                _ => return Err(missing_flag("oopsie cards need --min-cost and --max-cost")),
+            };
+            let fix_rounds = matches.get_one::<usize>("fix-rounds").copied();
+            let people_hours = matches.get_one::<usize>("people-hours").copied();
+            Card::Oopsie(OopsieCard {
+                fix_rounds: fix_rounds.filter(|rounds| *rounds > 1),
+                ..OopsieCard::new(
+                    title,
+                    description,
+                    targets,
+                    effect,
+                    FixCost {
+                        min: Resources::new(min),
+                        max: Resources::new(max),
+                        people_hours: people_hours.filter(|h| *h > 0).map(Resources::new),
+                    },
+                )
+            })
+        }
+        "control" => {
+            let mitigation = match matches.get_one::<u8>("reduce") {
+                Some(percentage) => Mitigation::Reduce(PartOfHundred::new(*percentage)),
+                None => Mitigation::Block,
+            };
+            let cost = *matches
+                .get_one::<usize>("cost")
+                .ok_or_else(|| missing_flag("control cards need --cost"))?;
+            Card::Control(ControlCard::new(
+                title,
+                description,
+                targets,
+                effect,
+                mitigation,
+                Resources::new(cost),
+            ))
+        }
+        _ => {
+            return Err(CliError::new(
+                ErrorKind::CardError,
+                format!("Unknown card type '{}'", card_type).as_str(),
+                Some("use event, attack, oopsie, lucky or control".to_string()),
+            ))
+        }
+    };
+    Ok(card)
+}
+
+fn string_flag(matches: &ArgMatches, name: &str) -> String {
+    matches.get_one::<String>(name).cloned().unwrap_or_default()
+}
+
+/// Events and lucky cards alter the next fix or the fix they are used for if a modifier is given.
+fn modifying_effect(matches: &ArgMatches, description: EffectDescription) -> Effect {
+    let modifier = |amount: &isize| match *amount >= 0 {
+        true => CostModifier::Increase(Resources::new(amount.unsigned_abs())),
+        false => CostModifier::Decrease(Resources::new(amount.unsigned_abs())),
+    };
+    if let Some(amount) = matches.get_one::<isize>("next-fix") {
+        Effect::OnNextFix(description, modifier(amount))
+    } else if let Some(amount) = matches.get_one::<isize>("on-use") {
+        Effect::OnUsingForFix(description, modifier(amount))
+    } else {
+        Effect::Other(description)
+    }
+}
+
+fn missing_flag(message: &str) -> CliError {
+    CliError::new(ErrorKind::CardError, "Missing flag", Some(message.to_string()))
+}
+
+#[cfg(test)]
+mod tests {
+    use tempfile::tempdir;
+
+    use super::*;
+
+    const AUDIT: &str = r#"{ "type": "event", "title": "Audit", "description": "d", "effect": { "other": "x" } }"#;
+    const PARTY: &str = r#"{ "type": "event", "title": "Party", "description": "d", "effect": { "other": "x" } }"#;
+
+    fn titles(contents: &[String], stored_titles: &[&str]) -> CliResult<Vec<String>> {
+        let stored_titles = stored_titles.iter().map(|title| title.to_lowercase()).collect();
+        validate_cards("test", contents, true, &TargetTaxonomy::default(), stored_titles).map(|cards| {
+            cards
+                .iter()
+                .map(|card| card.title().value().to_string())
+                .collect()
+        })
+    }
+
+    #[test]
+    fn single_json_card_keeps_its_content() {
+        let contents = card_contents(AUDIT.to_string(), false).unwrap();
+
+        assert_eq!(contents, vec![AUDIT.to_string()]);
+        assert_eq!(titles(&contents, &[]).unwrap(), vec!["Audit"]);
+    }
+
+    #[test]
+    fn json_list_is_split_into_cards() {
+        let contents = card_contents(format!("[{}, {}]", AUDIT, PARTY), false).unwrap();
+
+        assert_eq!(titles(&contents, &[]).unwrap(), vec!["Audit", "Party"]);
+    }
+
+    #[test]
+    fn yaml_list_is_split_into_cards() {
+        let yaml = "
+- type: event
+  title: Audit
+  description: d
+  effect:
+    other: x
+- type: oopsie
+  title: Open port
+  description: d
+  effect:
+    attackSurface: [open, [database]]
+  fixCost: { min: 1, max: 3 }
+";
+        let contents = card_contents(yaml.to_string(), true).unwrap();
+
+        assert_eq!(titles(&contents, &[]).unwrap(), vec!["Audit", "Open port"]);
+    }
+
+    #[test]
+    fn invalid_documents_are_rejected() {
+        assert!(card_contents("{ \"type\": ".to_string(), false).is_err());
+        assert!(card_contents("- [unclosed".to_string(), true).is_err());
+    }
+
+    #[test]
+    fn invalid_cards_are_rejected() {
+        let contents = vec![
+            AUDIT.to_string(),
+            r#"{ "type": "event", "title": "", "description": "d", "effect": { "other": "x" } }"#.to_string(),
+        ];
+
+        assert!(titles(&contents, &[]).is_err());
+    }
+
+    #[test]
+    fn duplicate_titles_are_rejected() {
+        let batch = vec![AUDIT.to_string(), AUDIT.replace("Audit", "audit ")];
+
+        assert!(titles(&batch, &[]).is_err());
+        assert!(titles(&[PARTY.to_string()], &["party"]).is_err());
+        assert!(titles(&[PARTY.to_string()], &["Audit"]).is_ok());
+    }
+
+    #[test]
+    fn edit_from_file_replaces_the_card_if_its_title_is_free() {
+        let base = tempdir().unwrap();
+        let events = base.path().join("events");
+        fs::create_dir_all(&events).unwrap();
+        fs::write(events.join("0001-Audit.json"), AUDIT).unwrap();
+        fs::write(events.join("0002-Party.json"), PARTY).unwrap();
+        let cfg = Config {
+            game_path: base.path().to_str().unwrap().to_string(),
+        };
+        let source = base.path().join("card.yaml");
+        let source = source.to_str().unwrap();
+
+        fs::write(source, "type: event\ntitle: Audit\ndescription: d\neffect:\n  other: x\n").unwrap();
+        assert!(edit_from_file(&cfg, "events/0002", source).is_err());
+        assert!(events.join("0002-Party.json").exists());
+
+        fs::write(source, "type: event\ntitle: Fair\ndescription: d\neffect:\n  other: x\n").unwrap();
+        edit_from_file(&cfg, "events/0002", source).unwrap();
+        assert!(!events.join("0002-Party.json").exists());
+        assert!(fs::read_to_string(events.join("0002-Fair.json")).unwrap().contains("Fair"));
+    }
+}
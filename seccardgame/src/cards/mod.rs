@@ -1,2 +1,16 @@
+use game_lib::cards::types::card_model::{Card, CardTrait};
+
 pub mod crud;
+pub mod gallery;
+pub mod import_export;
+pub mod input;
+pub mod print;
+pub mod query;
+pub mod schema;
 pub mod stats;
+pub mod validate;
+
+/// Title of a card as it is compared to find cards with the same title.
+pub(crate) fn normalized_title(card: &Card) -> String {
+    card.title().value().trim().to_lowercase()
+}
@@ -0,0 +1,101 @@
+use std::fs;
+use std::path::{Path, PathBuf};
+use std::rc::Rc;
+
+use clap::ArgMatches;
+use log::info;
+
+use game_lib::cards::types::card_model::Card;
+use game_lib::file::format::{read_versioned, FileKind};
+use game_lib::file::repository::DeckLoader;
+use game_lib::world::deck::{CardRc, TargetTaxonomyRepository};
+use game_setup::config::config::Config;
+use game_ui::card_sheet::{cards_to_html, cards_to_svg};
+
+use crate::cli::cli_result::ErrorKind::FileSystemError;
+use crate::cli::cli_result::{CliError, CliResult, ErrorKind};
+use crate::cards::query::query_from_args;
+
+/// Renders the cards selected by the arguments of `cards print` as sheets. The cards are the cards of a
+/// deck prepared by `game create`, the cards with the given ids or all cards matching the filters.
+pub fn print(cfg: &Config, matches: &ArgMatches) -> CliResult<()> {
+    let cards = match (
+        matches.get_one::<String>("deck"),
+        matches.get_many::<String>("cards"),
+    ) {
+        (Some(deck), _) => read_deck(deck)?,
+        (None, Some(ids)) => find_cards(cfg, ids.map(|id| id.as_str()))?,
+        (None, None) => {
+            let query = query_from_args(matches)?;
+            let loader = DeckLoader::create(cfg.game_path.as_str());
+            query.find(&loader, &loader.get_target_taxonomy())
+        }
+    };
+    if cards.is_empty() {
+        return Err(CliError::new(ErrorKind::CardError, "No cards to print", None));
+    }
+
+    let output = matches.get_one::<String>("output").unwrap();
+    match matches.get_one::<String>("format").unwrap().as_str() {
+        "svg" => {
+            fs::create_dir_all(output).map_err(|e| CliError::write_error(output, e))?;
+            for (index, sheet) in cards_to_svg(&cards).iter().enumerate() {
+                let path = Path::new(output).join(format!("sheet-{:03}.svg", index + 1));
+                fs::write(&path, sheet).map_err(|e| CliError::write_error(&path, e))?;
+            }
+        }
+        _ => fs::write(output, cards_to_html(&cards)).map_err(|e| CliError::write_error(output, e))?,
+    }
+    info!("Printed {} cards to {}", cards.len(), output);
+    Ok(())
+}
+
+fn find_cards<'a>(cfg: &Config, ids: impl Iterator<Item = &'a str>) -> CliResult<Vec<CardRc>> {
+    let loader = DeckLoader::create(cfg.game_path.as_str());
+    ids.map(|id| {
+        loader.find_stored_card(id).map(|stored| stored.card).ok_or_else(|| {
+            CliError::new(
+                ErrorKind::CardError,
+                format!("Card '{}' not found", id).as_str(),
+                None,
+            )
+        })
+    })
+    .collect()
+}
+
+/// Reads the card files of a deck in the order they are drawn.
+fn read_deck(deck: &str) -> CliResult<Vec<CardRc>> {
+    let entries = fs::read_dir(deck).map_err(|e| {
+        CliError::new(
+            FileSystemError,
+            format!("Could not read deck {}", deck).as_str(),
+            Some(e.to_string()),
+        )
+    })?;
+    let mut paths: Vec<PathBuf> = entries
+        .filter_map(|entry| entry.ok().map(|entry| entry.path()))
+        .filter(|path| path.extension().is_some_and(|ext| ext == "json"))
+        .collect();
+    paths.sort();
+    let directory = Path::new(deck)
+        .file_name()
+        .and_then(|d| d.to_str())
+        .unwrap_or_default();
+    paths
+        .iter()
+        .map(|path| {
+            fs::read_to_string(path)
+                .map_err(|e| e.to_string())
+                .and_then(|content| read_versioned::<Card>(FileKind::Card, directory, &content))
+                .map(Rc::new)
+                .map_err(|e| {
+                    CliError::new(
+                        ErrorKind::CardError,
+                        format!("{} is no valid card", path.display()).as_str(),
+                        Some(e),
+                    )
+                })
+        })
+        .collect()
+}
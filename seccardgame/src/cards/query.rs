@@ -0,0 +1,118 @@
+use std::fs;
+use std::path::Path;
+use std::rc::Rc;
+
+use clap::ArgMatches;
+
+use game_lib::cards::properties::target::Target;
+use game_lib::cards::types::card_model::{Card, CardTrait};
+use game_lib::file::format::{read_versioned, FileKind};
+use game_lib::file::repository::DeckLoader;
+use game_lib::world::card_query::{cost_range, parse_card_category, CardQuery, EffectKind};
+use game_lib::world::deck::{CardRc, TargetTaxonomyRepository};
+use game_setup::config::config::Config;
+use game_ui::card_text::card_to_text;
+
+use crate::cli::cli_result::{CliError, CliResult, ErrorKind};
+
+/// Builds the query from the filters of the `cards list` command.
+pub fn query_from_args(matches: &ArgMatches) -> CliResult<CardQuery> {
+    let category = matches
+        .get_one::<String>("type")
+        .map(|name| parse_card_category(name))
+        .transpose()
+        .map_err(invalid_filter)?;
+    let effect_kind = matches
+        .get_one::<String>("effect")
+        .map(|name| name.parse::<EffectKind>())
+        .transpose()
+        .map_err(invalid_filter)?;
+    Ok(CardQuery {
+        category,
+        target: matches.get_one::<String>("target").map(|t| Target::new(t)),
+        effect_kind,
+        min_cost: matches.get_one::<usize>("min-cost").copied(),
+        max_cost: matches.get_one::<usize>("max-cost").copied(),
+        text: matches.get_one::<String>("search").cloned(),
+    })
+}
+
+fn invalid_filter(message: String) -> CliError {
+    CliError::new(ErrorKind::CardError, "Invalid filter", Some(message))
+}
+
+/// Prints id, type, title and costs of every card matching the query.
+pub fn list(cfg: &Config, query: &CardQuery) -> CliResult<()> {
+    let loader = DeckLoader::create(cfg.game_path.as_str());
+    let taxonomy = loader.get_target_taxonomy();
+    let cards: Vec<_> = loader
+        .get_stored_cards()
+        .into_iter()
+        .filter(|stored| query.matches(&stored.card, &taxonomy))
+        .collect();
+
+    for stored in &cards {
+        println!(
+            "{:<14} {:<14} {}{}",
+            stored.id,
+            stored.card.category().to_string(),
+            stored.card.title().value(),
+            costs_to_text(&stored.card)
+        );
+    }
+    println!("{} cards found", cards.len());
+    Ok(())
+}
+
+fn costs_to_text(card: &Card) -> String {
+    match cost_range(card) {
+        Some((min, max)) if min == max => format!(" ({} resources)", min),
+        Some((min, max)) => format!(" ({} to {} resources)", min, max),
+        None => "".to_string(),
+    }
+}
+
+/// Prints a card like the card window of the game. The card is either a card file or the id of a
+/// card of the repository as printed by `cards list`, e.g. `oopsies/0003`.
+pub fn show(cfg: &Config, card: &str) -> CliResult<()> {
+    let card = if Path::new(card).is_file() {
+        read_card_file(card)?
+    } else {
+        DeckLoader::create(cfg.game_path.as_str())
+            .find_stored_card(card)
+            .map(|stored| stored.card)
+            .ok_or_else(|| {
+                CliError::new(
+                    ErrorKind::CardError,
+                    format!("Card '{}' not found", card).as_str(),
+                    None,
+                )
+            })?
+    };
+    println!("{}", card_to_text(card));
+    Ok(())
+}
+
+fn read_card_file(path: &str) -> CliResult<CardRc> {
+    let content = fs::read_to_string(path).map_err(|e| {
+        CliError::new(
+            ErrorKind::FileSystemError,
+            format!("Could not read {}", path).as_str(),
+            Some(e.to_string()),
+        )
+    })?;
+    let directory = Path::new(path)
+        .parent()
+        .and_then(|p| p.file_name())
+        .and_then(|d| d.to_str())
+        .unwrap_or_default();
+    read_versioned::<Card>(FileKind::Card, directory, content.as_str())
+        .map(Rc::new)
+        .map_err(|e| {
+            CliError::new(
+                ErrorKind::CardError,
+                format!("{} is no valid card", path).as_str(),
+                Some(e),
+            )
+        })
+}
@@ -0,0 +1,32 @@
+use std::fs;
+
+use game_lib::file::repository::DeckLoader;
+use game_lib::file::schema::{card_schema, scenario_schema};
+use game_lib::world::deck::TargetTaxonomyRepository;
+use game_setup::config::config::Config;
+
+use crate::cli::cli_result::ErrorKind::FileSystemError;
+use crate::cli::cli_result::{CliError, CliResult};
+
+/// Prints the JSON Schema of card or scenario files or writes it into the file.
+pub fn schema(cfg: &Config, kind: &str, output: Option<&String>) -> CliResult<()> {
+    let taxonomy = DeckLoader::create(cfg.game_path.as_str()).get_target_taxonomy();
+    let schema = match kind {
+        "scenario" => scenario_schema(&taxonomy),
+        _ => card_schema(&taxonomy),
+    };
+    let content = serde_json::to_string_pretty(&schema).unwrap();
+    match output {
+        Some(path) => fs::write(path, content).map_err(|e| {
+            CliError::new(
+                FileSystemError,
+                format!("Could not write {}", path).as_str(),
+                Some(e.to_string()),
+            )
+        }),
+        None => {
+            println!("{}", content);
+            Ok(())
+        }
+    }
+}
@@ -8,6 +8,7 @@ use game_lib::cards::properties::effect::Effect;
 use game_lib::cards::properties::target::Target;
 use game_lib::cards::types::attack::AttackCard;
 use game_lib::cards::types::card_model::Card;
+use game_lib::cards::types::control::ControlCard;
 use game_lib::cards::types::event::EventCard;
 use game_lib::cards::types::lucky::LuckyCard;
 use game_lib::cards::types::oopsie::OopsieCard;
@@ -21,6 +22,7 @@ pub struct CardStats {
     pub attack_cards: u32,
     pub oopsie_cards: u32,
     pub lucky_cards: u32,
+    pub control_cards: u32,
     pub targets: HashMap<String, TargetCounter>,
 }
 
@@ -36,6 +38,7 @@ impl CardStats {
             event_cards: Self::count_event_cards(cfg),
             oopsie_cards: Self::count_oopsie_cards(cfg),
             lucky_cards: Self::count_lucky_cards(cfg),
+            control_cards: Self::count_control_cards(cfg),
             attack_cards: Self::count_attack_cards(cfg),
             targets: Self::read_targets(cfg),
         })
@@ -61,6 +64,11 @@ impl CardStats {
         Self::count_files(&cfg, &card)
     }
 
+    fn count_control_cards(cfg: &Config) -> u32 {
+        let card = ControlCard::empty();
+        Self::count_files(cfg, &card)
+    }
+
     fn count_files(cfg: &Config, event_card: &Card) -> u32 {
         let mut base_path = PathBuf::from(&cfg.game_path);
         let card_dir = get_card_directory(&event_card);
@@ -177,6 +185,7 @@ pub(crate) fn print_stats(cfg: &Config) -> CliResult<()> {
     println!("Lucky:\t\t{}", stats.lucky_cards);
     println!("Oopsie:\t\t{}", stats.oopsie_cards);
     println!("Attacks:\t{}", stats.attack_cards);
+    println!("Controls:\t{}", stats.control_cards);
     println!("=====Targets=====");
     if stats.targets.len() > 0 {
         println!("{:<20}\t\tOopsie\tIncident", "Name");
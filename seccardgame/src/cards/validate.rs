@@ -0,0 +1,32 @@
+use game_lib::file::lint::lint_card_repository;
+use game_lib::file::repository::DeckLoader;
+use game_lib::world::deck::TargetTaxonomyRepository;
+use game_setup::config::config::Config;
+
+use crate::cli::cli_result::{CliError, CliResult, ErrorKind};
+
+/// Lints all cards of the repository and prints every problem as `file:line: message`. The command
+/// fails if any problem was found, so it can be used in the CI of the card repository.
+pub fn validate(cfg: &Config) -> CliResult<()> {
+    let taxonomy = DeckLoader::create(cfg.game_path.as_str()).get_target_taxonomy();
+    let diagnostics = lint_card_repository(cfg.game_path.as_str(), &taxonomy).map_err(|e| {
+        CliError::new(
+            ErrorKind::FileSystemError,
+            "Could not read cards",
+            Some(e.to_string()),
+        )
+    })?;
+
+    for diagnostic in &diagnostics {
+        println!("{}", diagnostic);
+    }
+    if !diagnostics.is_empty() {
+        return Err(CliError::new(
+            ErrorKind::CardError,
+            format!("Found {} problems in the cards", diagnostics.len()).as_str(),
+            None,
+        ));
+    }
+    println!("All cards are valid");
+    Ok(())
+}
@@ -1,5 +1,7 @@
 use std::fmt;
 use std::fmt::Formatter;
+use std::io;
+use std::path::Path;
 use game_setup::results::GameSetupError;
 
 #[derive(Clone, Debug, PartialEq)]
@@ -13,6 +15,7 @@ pub enum ErrorKind {
     UserInterfaceError,
     GUI,
     GameSetupError,
+    ScenarioError,
 }
 
 impl fmt::Display for ErrorKind {
@@ -26,6 +29,7 @@ impl fmt::Display for ErrorKind {
             ErrorKind::UserInterfaceError => write!(f, "UserInterfaceError"),
             ErrorKind::GUI => write!(f, "GUIError"),
             ErrorKind::GameSetupError => write!(f, "GameSetupError"),
+            ErrorKind::ScenarioError => write!(f, "ScenarioError"),
         }
     }
 }
@@ -60,6 +64,15 @@ impl CliError {
             original_message,
         }
     }
+
+    /// A file or directory which could not be written.
+    pub fn write_error(path: impl AsRef<Path>, e: io::Error) -> Self {
+        CliError::new(
+            ErrorKind::FileSystemError,
+            format!("Could not write {}", path.as_ref().display()).as_str(),
+            Some(e.to_string()),
+        )
+    }
 }
 
 pub type CliResult<T> = Result<T, CliError>;
@@ -15,3 +15,14 @@ where
     input.interact_text().unwrap()
 }
 
+pub fn prompt_with_default<T>(prompt_msg: &str, default: T) -> T
+where
+    T: Clone + ToString + std::str::FromStr,
+    <T as std::str::FromStr>::Err: std::fmt::Debug + ToString,
+{
+    Input::<T>::new()
+        .with_prompt(prompt_msg)
+        .default(default)
+        .interact_text()
+        .unwrap()
+}
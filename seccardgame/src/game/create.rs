@@ -4,6 +4,7 @@ use std::path::PathBuf;
 use dialoguer::Input;
 use log::info;
 
+use game_lib::cards::game_variants::card_selection::CardSelection;
 use game_lib::file::cards::write_data_to_file;
 use game_lib::world::deck::{CardRc, Deck, DeckComposition};
 use game_setup::config::config::Config;
@@ -22,12 +23,13 @@ pub fn create_deck(config: &Config) -> Deck {
     let attack_card_count = get_number_of_cards("Enter number of attack types", 5);
     let oopsie_card_count = get_number_of_cards("Enter number of oopsies", 15);
     let lucky_card_count = get_number_of_cards("Enter number of lucky types", 5);
+    let control_card_count = get_number_of_cards("Enter number of control types", 0);
     let grace_period = get_number_of_cards(
         "Enter number of turns after which attacks should be possible?",
         (event_card_count + attack_card_count + oopsie_card_count + lucky_card_count) / 4,
     );
     
-    let curent_card_count = event_card_count + attack_card_count + oopsie_card_count + lucky_card_count;
+    let curent_card_count = event_card_count + attack_card_count + oopsie_card_count + lucky_card_count + control_card_count;
     let eval_prompt = format!("Enter number of evaluation cards (max {}). The deck will be split into n + 1 parts and all parts except the first will contain an evaluation card. 0 disables them.", curent_card_count - 1);
     let evaluation_cards = get_number_of_cards(eval_prompt.as_str(), 0);
 
@@ -37,10 +39,16 @@ pub fn create_deck(config: &Config) -> Deck {
         attacks: attack_card_count as usize,
         oopsies: oopsie_card_count as usize,
         lucky: lucky_card_count as usize,
+        controls: control_card_count as usize,
         evaluation: evaluation_cards as usize,
     };
 
-    game_setup::creation::create::create_deck(&deck_composition, grace_period, config)
+    game_setup::creation::create::create_deck(
+        &deck_composition,
+        grace_period,
+        &CardSelection::default(),
+        config,
+    )
 }
 
 pub fn create_deck_and_write_to_disk(deck_path: String, config: &Config) -> CliResult<()> {
@@ -1,6 +1,6 @@
 use std::process::exit;
 
-use clap::{Arg, Command};
+use clap::{value_parser, Arg, ArgAction, Command};
 use flexi_logger::Logger;
 use log::error;
 use game_setup::config::config::{init, CfgInit, Config};
@@ -14,6 +14,7 @@ mod cards;
 mod cli;
 mod game;
 mod migrations;
+mod scenarios;
 
 fn cli() -> Command {
     Command::new("seccardgame")
@@ -41,10 +42,249 @@ fn cli() -> Command {
                 .arg_required_else_help(true)
                 .subcommand(
                     Command::new("create")
-                        .about("Create a card")
-                        .arg_required_else_help(false),
+                        .about("Create a card, asks for all details unless a file or --type is given")
+                        .arg_required_else_help(false)
+                        .arg(
+                            Arg::new("file")
+                                .long("file")
+                                .short('f')
+                                .conflicts_with("type")
+                                .help("JSON or YAML file with a card or a list of cards, - reads stdin"),
+                        )
+                        .arg(
+                            Arg::new("type")
+                                .long("type")
+                                .help("event, attack, oopsie, lucky or control"),
+                        )
+                        .arg(Arg::new("title").long("title").requires("type"))
+                        .arg(Arg::new("description").long("description").requires("type"))
+                        .arg(
+                            Arg::new("effect")
+                                .long("effect")
+                                .requires("type")
+                                .help("Description of the effect"),
+                        )
+                        .arg(
+                            Arg::new("target")
+                                .long("target")
+                                .requires("type")
+                                .action(ArgAction::Append)
+                                .help("Target of an attack, oopsie or control, can be repeated"),
+                        )
+                        .arg(
+                            Arg::new("duration")
+                                .long("duration")
+                                .requires("type")
+                                .value_parser(value_parser!(usize))
+                                .help("Rounds an attack lasts"),
+                        )
+                        .arg(
+                            Arg::new("revenue-share")
+                                .long("revenue-share")
+                                .requires("type")
+                                .conflicts_with("damage")
+                                .value_parser(value_parser!(u8).range(0..=100))
+                                .help("Percentage of the revenue an attack costs"),
+                        )
+                        .arg(
+                            Arg::new("damage")
+                                .long("damage")
+                                .requires("type")
+                                .value_parser(value_parser!(usize))
+                                .help("Resources an attack costs"),
+                        )
+                        .arg(
+                            Arg::new("min-cost")
+                                .long("min-cost")
+                                .requires("type")
+                                .value_parser(value_parser!(usize)),
+                        )
+                        .arg(
+                            Arg::new("max-cost")
+                                .long("max-cost")
+                                .requires("type")
+                                .value_parser(value_parser!(usize)),
+                        )
+                        .arg(
+                            Arg::new("fix-rounds")
+                                .long("fix-rounds")
+                                .requires("type")
+                                .value_parser(value_parser!(usize)),
+                        )
+                        .arg(
+                            Arg::new("people-hours")
+                                .long("people-hours")
+                                .requires("type")
+                                .value_parser(value_parser!(usize)),
+                        )
+                        .arg(
+                            Arg::new("next-fix")
+                                .long("next-fix")
+                                .requires("type")
+                                .conflicts_with("on-use")
+                                .allow_negative_numbers(true)
+                                .value_parser(value_parser!(isize))
+                                .help("Change of the next fix cost by an event or lucky card"),
+                        )
+                        .arg(
+                            Arg::new("on-use")
+                                .long("on-use")
+                                .requires("type")
+                                .allow_negative_numbers(true)
+                                .value_parser(value_parser!(isize))
+                                .help("Change of a fix cost when a lucky card is used for it"),
+                        )
+                        .arg(
+                            Arg::new("price")
+                                .long("price")
+                                .requires("type")
+                                .value_parser(value_parser!(usize))
+                                .help("Market price of a lucky card"),
+                        )
+                        .arg(
+                            Arg::new("cost")
+                                .long("cost")
+                                .requires("type")
+                                .value_parser(value_parser!(usize))
+                                .help("Costs to buy a control"),
+                        )
+                        .arg(
+                            Arg::new("reduce")
+                                .long("reduce")
+                                .requires("type")
+                                .value_parser(value_parser!(u8).range(0..=100))
+                                .help("Percentage a control reduces incidents by, blocks them if missing"),
+                        ),
+                )
+                .subcommand(
+                    Command::new("edit")
+                        .about("Opens a card in the editor and saves it if it is valid")
+                        .arg(Arg::new("card").help("Card id, e.g. oopsies/0003, asks if missing"))
+                        .arg(
+                            Arg::new("file")
+                                .long("file")
+                                .short('f')
+                                .requires("card")
+                                .help("JSON or YAML file with the new card instead of the editor, - reads stdin"),
+                        ),
+                )
+                .subcommand(
+                    Command::new("delete")
+                        .about("Deletes a card and renumbers the following cards")
+                        .arg(Arg::new("card").help("Card id, e.g. oopsies/0003, asks if missing")),
+                )
+                .subcommand(
+                    Command::new("export")
+                        .about("Exports all cards, e.g. to edit them in a spreadsheet")
+                        .arg(
+                            Arg::new("format")
+                                .long("format")
+                                .value_parser(["csv"])
+                                .default_value("csv"),
+                        )
+                        .arg(
+                            Arg::new("output")
+                                .long("output")
+                                .short('o')
+                                .help("File to write to, defaults to stdout"),
+                        ),
+                )
+                .subcommand(
+                    Command::new("import")
+                        .about("Creates cards from the rows of a CSV file")
+                        .arg(Arg::new("file").required(true))
+                        .arg(
+                            Arg::new("dry-run")
+                                .long("dry-run")
+                                .action(ArgAction::SetTrue)
+                                .help("Only report which cards would be created or rejected"),
+                        ),
+                )
+                .subcommand(Command::new("stats").about("Prints stats"))
+                .subcommand(
+                    Command::new("gallery")
+                        .about("Generates a static HTML site to browse all cards and scenarios")
+                        .arg(Arg::new("out-dir").required(true)),
+                )
+                .subcommand(
+                    with_card_filters(Command::new("list"))
+                        .about("Lists all cards matching the filters"),
+                )
+                .subcommand(
+                    Command::new("schema")
+                        .about("Prints the JSON Schema of card or scenario files for editors")
+                        .arg(
+                            Arg::new("kind")
+                                .long("kind")
+                                .value_parser(["card", "scenario"])
+                                .default_value("card"),
+                        )
+                        .arg(
+                            Arg::new("output")
+                                .short('o')
+                                .long("output")
+                                .help("File to write the schema to instead of stdout"),
+                        ),
+                )
+                .subcommand(
+                    Command::new("show")
+                        .about("Prints a card like the game shows it")
+                        .arg(
+                            Arg::new("card")
+                                .required(true)
+                                .help("Card id as printed by list, e.g. oopsies/0003, or a card file"),
+                        ),
                 )
-                .subcommand(Command::new("stats").about("Prints stats")),
+                .subcommand(
+                    with_card_filters(Command::new("print"))
+                        .about("Renders cards as print-ready sheets with nine cards each")
+                        .arg(
+                            Arg::new("cards")
+                                .num_args(0..)
+                                .help("Card ids as printed by list, without ids all cards matching the filters are printed"),
+                        )
+                        .arg(
+                            Arg::new("deck")
+                                .long("deck")
+                                .conflicts_with("cards")
+                                .help("Directory of a deck prepared by game create"),
+                        )
+                        .arg(
+                            Arg::new("format")
+                                .long("format")
+                                .value_parser(["html", "svg"])
+                                .default_value("html"),
+                        )
+                        .arg(
+                            Arg::new("output")
+                                .short('o')
+                                .long("output")
+                                .required(true)
+                                .help("HTML file or directory for the SVG sheets"),
+                        ),
+                )
+                .subcommand(
+                    Command::new("validate")
+                        .about("Checks all card files and fails if any card is invalid"),
+                ),
+        )
+        .subcommand(
+            Command::new("scenario")
+                .about("Operate on scenarios")
+                .subcommand_required(true)
+                .arg_required_else_help(true)
+                .subcommand(Command::new("create").about("Create a scenario"))
+                .subcommand(Command::new("list").about("Lists all scenarios"))
+                .subcommand(
+                    Command::new("show")
+                        .about("Prints a scenario")
+                        .arg(Arg::new("title").required(true)),
+                )
+                .subcommand(
+                    Command::new("validate")
+                        .about("Validates a scenario file or all scenarios of the repository")
+                        .arg(Arg::new("path")),
+                ),
         )
         .subcommand(
             Command::new("game")
@@ -75,8 +315,56 @@ fn cli() -> Command {
                 .about("Migrates card versions")
                 .subcommand_required(true)
                 .arg_required_else_help(true)
-                .subcommand(Command::new("version1").about("Migrates to version 1"))
-                .subcommand(Command::new("version3").about("Migrates from v1 to v3")),
+                .subcommand(
+                    Command::new("run")
+                        .about("Upgrades card and scenario files to the current format version")
+                        .arg(
+                            Arg::new("dry-run")
+                                .long("dry-run")
+                                .action(ArgAction::SetTrue)
+                                .help("Prints the changes as diff without writing files"),
+                        )
+                        .arg(
+                            Arg::new("backup")
+                                .long("backup")
+                                .help("Directory the original files are copied into"),
+                        ),
+                ),
+        )
+}
+
+/// Filters of the cards matching a query, see `cards list`.
+fn with_card_filters(command: Command) -> Command {
+    command
+        .arg(
+            Arg::new("type")
+                .long("type")
+                .help("event, attack, oopsie, lucky or control"),
+        )
+        .arg(
+            Arg::new("target")
+                .long("target")
+                .help("Target or target group of the card"),
+        )
+        .arg(
+            Arg::new("effect")
+                .long("effect")
+                .help("Effect kind, e.g. incident, attackSurface or onNextFix"),
+        )
+        .arg(
+            Arg::new("min-cost")
+                .long("min-cost")
+                .value_parser(clap::value_parser!(usize)),
+        )
+        .arg(
+            Arg::new("max-cost")
+                .long("max-cost")
+                .value_parser(clap::value_parser!(usize)),
+        )
+        .arg(
+            Arg::new("search")
+                .long("search")
+                .help("Text in title, description or effect"),
         )
 }
 
@@ -113,11 +401,73 @@ fn handle_commands() -> CliResult<()> {
         Some(("cards", sub_matches)) => {
             let config = load_config(cfg);
             match sub_matches.subcommand() {
-                Some(("create", _)) => cards::crud::create(&config),
+                Some(("create", sub_matches)) => {
+                    if let Some(file) = sub_matches.get_one::<String>("file") {
+                        cards::input::create_from_file(&config, file)
+                    } else if sub_matches.contains_id("type") {
+                        cards::input::create_from_flags(&config, sub_matches)
+                    } else {
+                        cards::crud::create(&config)
+                    }
+                }
+                Some(("edit", sub_matches)) => {
+                    let card = sub_matches.get_one::<String>("card");
+                    match (card, sub_matches.get_one::<String>("file")) {
+                        (Some(card), Some(file)) => cards::input::edit_from_file(&config, card, file),
+                        _ => cards::crud::edit(&config, card),
+                    }
+                }
+                Some(("delete", sub_matches)) => {
+                    cards::crud::delete(&config, sub_matches.get_one::<String>("card"))
+                }
+                Some(("export", sub_matches)) => {
+                    cards::import_export::export(&config, sub_matches.get_one::<String>("output"))
+                }
+                Some(("import", sub_matches)) => {
+                    let file = sub_matches.get_one::<String>("file").unwrap();
+                    cards::import_export::import(&config, file, sub_matches.get_flag("dry-run"))
+                }
                 Some(("stats", _)) => cards::stats::print_stats(&config),
+                Some(("gallery", sub_matches)) => {
+                    let out_dir = sub_matches.get_one::<String>("out-dir").unwrap();
+                    cards::gallery::gallery(&config, out_dir)
+                }
+                Some(("list", sub_matches)) => {
+                    let query = cards::query::query_from_args(sub_matches)?;
+                    cards::query::list(&config, &query)
+                }
+                Some(("schema", sub_matches)) => cards::schema::schema(
+                    &config,
+                    sub_matches.get_one::<String>("kind").unwrap(),
+                    sub_matches.get_one::<String>("output"),
+                ),
+                Some(("show", sub_matches)) => {
+                    let card = sub_matches.get_one::<String>("card").unwrap();
+                    cards::query::show(&config, card)
+                }
+                Some(("print", sub_matches)) => cards::print::print(&config, sub_matches),
+                Some(("validate", _)) => cards::validate::validate(&config),
                 _ => exit(-1),
             }
         }
+        Some(("scenario", sub_matches)) => {
+            let config = load_config(cfg);
+            match sub_matches.subcommand() {
+                Some(("create", _)) => scenarios::crud::create(&config),
+                Some(("list", _)) => scenarios::crud::list(&config),
+                Some(("show", sub_matches)) => {
+                    let title = sub_matches.get_one::<String>("title").unwrap();
+                    scenarios::crud::show(&config, title)
+                }
+                Some(("validate", sub_matches)) => {
+                    scenarios::validate::validate(&config, sub_matches.get_one::<String>("path"))
+                }
+                _ => {
+                    println!("Unknown command!");
+                    exit(-1)
+                }
+            }
+        }
         Some(("game", sub_matches)) => {
             let config = load_config(cfg);
             match sub_matches.subcommand() {
@@ -140,8 +490,11 @@ fn handle_commands() -> CliResult<()> {
         Some(("migration", sub_matches)) => {
             let config = load_config(cfg);
             match sub_matches.subcommand() {
-                Some(("version1", _)) => version_one::convert(&config),
-                Some(("version3", _)) => version_three::convert(&config),
+                Some(("run", sub_matches)) => upgrade::run(
+                    &config,
+                    sub_matches.get_flag("dry-run"),
+                    sub_matches.get_one::<String>("backup"),
+                ),
                 _ => {
                     println!("Unknown command!");
                     exit(-1)
@@ -1,2 +1 @@
-pub mod version_one;
-pub mod version_three;
+pub mod upgrade;
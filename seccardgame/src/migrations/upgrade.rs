@@ -0,0 +1,336 @@
+use std::collections::HashMap;
+use std::fs;
+use std::path::{Path, PathBuf};
+
+use log::{error, info, warn};
+use serde_json::Value;
+use similar::TextDiff;
+
+use game_lib::cards::game_variants::scenario::Scenario;
+use game_lib::cards::types::card_model::Card;
+use game_lib::file::cards::{card_number, renumbered_filename, CARD_DIRECTORIES};
+use game_lib::file::format::{to_versioned_json, upgrade, FileKind, VersionedFile, VERSION_FIELD};
+use game_lib::file::general::get_files_in_directory_with_filter;
+use game_lib::file::scenarios::SCENARIO_DIRECTORY;
+use game_setup::config::config::Config;
+
+use crate::cli::cli_result::ErrorKind::FileSystemError;
+use crate::cli::cli_result::{CliError, CliResult, ErrorKind};
+
+/// The directory incidents were stored in before they became attacks.
+const INCIDENT_DIRECTORY: &str = "incidents";
+
+/// An upgraded file, the path changes if a migration moved the file into another directory.
+struct Change {
+    old_path: PathBuf,
+    new_path: PathBuf,
+    old_content: String,
+    new_content: String,
+    from: u64,
+    to: u64,
+    notes: Vec<String>,
+}
+
+/// Upgrades all card and scenario files of the repository to the current version of their format.
+/// A dry run prints the changes as diff, otherwise the files are rewritten and the original files
+/// copied into the backup directory first. Without backup directory the files are rewritten in place.
+pub fn run(cfg: &Config, dry_run: bool, backup: Option<&String>) -> CliResult<()> {
+    let base = Path::new(&cfg.game_path);
+    let directories = CARD_DIRECTORIES
+        .iter()
+        .chain([INCIDENT_DIRECTORY, SCENARIO_DIRECTORY].iter());
+
+    let mut changes = vec![];
+    let mut failures = 0;
+    let mut last_numbers = HashMap::new();
+    for directory in directories {
+        let path = base.join(directory);
+        if !path.is_dir() {
+            continue;
+        }
+        let mut files = get_files_in_directory_with_filter(path.to_str().unwrap(), ".json")
+            .map_err(|e| {
+                CliError::new(
+                    FileSystemError,
+                    format!("Could not read {}", path.display()).as_str(),
+                    Some(e.to_string()),
+                )
+            })?;
+        files.sort();
+        for file in files {
+            let file = PathBuf::from(file);
+            match upgrade_file(base, directory, &file, &mut last_numbers) {
+                Ok(Some(change)) => changes.push(change),
+                Ok(None) => {}
+                Err(e) => {
+                    error!("{} can not be upgraded: {}", file.display(), e);
+                    failures += 1;
+                }
+            }
+        }
+    }
+
+    if !dry_run && backup.is_none() && !changes.is_empty() {
+        warn!("No backup directory given with --backup, the original files are overwritten");
+    }
+    for change in &changes {
+        info!(
+            "{}: version {} -> {}",
+            change.old_path.display(),
+            change.from,
+            change.to
+        );
+        for note in &change.notes {
+            info!("  {}", note);
+        }
+        if dry_run {
+            print_diff(base, change);
+        } else {
+            apply(base, change, backup)?;
+        }
+    }
+
+    let verb = if dry_run { "would be upgraded" } else { "upgraded" };
+    info!("{} files {}, {} failed", changes.len(), verb, failures);
+    if failures > 0 {
+        return Err(CliError::new(
+            ErrorKind::CardError,
+            format!("{} files can not be upgraded", failures).as_str(),
+            None,
+        ));
+    }
+    Ok(())
+}
+
+/// Upgrades the file in memory, files which are already in the current format and have the version
+/// field give `None`. Files in the current format without version field only get the version. A file
+/// moved into another directory gets the next free number there, the last numbers of the directories
+/// are kept in `last_numbers` so files moved in the same run get different numbers.
+fn upgrade_file(
+    base: &Path,
+    directory: &str,
+    path: &Path,
+    last_numbers: &mut HashMap<String, u32>,
+) -> Result<Option<Change>, String> {
+    let kind = FileKind::of_directory(directory).unwrap();
+    let old_content = fs::read_to_string(path).map_err(|e| e.to_string())?;
+    let content: Value = serde_json::from_str(&old_content).map_err(|e| e.to_string())?;
+    let has_version = content.get(VERSION_FIELD).is_some();
+    let mut upgrade = upgrade(
+        kind,
+        VersionedFile {
+            directory: directory.to_string(),
+            content,
+        },
+    )?;
+    if upgrade.from == upgrade.to {
+        if has_version {
+            return Ok(None);
+        }
+        upgrade.notes.push("added the version field".to_string());
+    }
+
+    // the upgraded file is read like the game reads it, so only files the game accepts are written
+    let new_content = match kind {
+        FileKind::Card => serde_json::from_value::<Card>(upgrade.file.content)
+            .and_then(|card| to_versioned_json(kind, &card)),
+        FileKind::Scenario => serde_json::from_value::<Scenario>(upgrade.file.content)
+            .and_then(|scenario| to_versioned_json(kind, &scenario)),
+    }
+    .map_err(|e| e.to_string())?;
+
+    let new_path = if upgrade.file.directory == directory {
+        path.to_path_buf()
+    } else {
+        let target = base.join(&upgrade.file.directory);
+        let last_number = last_numbers
+            .entry(upgrade.file.directory.clone())
+            .or_insert_with(|| highest_number(&target));
+        *last_number += 1;
+        match renumbered_filename(path, *last_number) {
+            Some(file_name) => target.join(file_name),
+            None => target.join(path.file_name().unwrap()),
+        }
+    };
+    if new_path != path && new_path.exists() {
+        return Err(format!("{} already exists", new_path.display()));
+    }
+    Ok(Some(Change {
+        old_path: path.to_path_buf(),
+        new_path,
+        old_content,
+        new_content,
+        from: upgrade.from,
+        to: upgrade.to,
+        notes: upgrade.notes,
+    }))
+}
+
+/// Highest number of the card files in the directory, 0 if there are none.
+fn highest_number(directory: &Path) -> u32 {
+    get_files_in_directory_with_filter(directory.to_str().unwrap(), ".json")
+        .unwrap_or_default()
+        .iter()
+        .filter_map(|file| card_number(Path::new(file)))
+        .max()
+        .unwrap_or(0)
+}
+
+fn print_diff(base: &Path, change: &Change) {
+    let diff = TextDiff::from_lines(&change.old_content, &change.new_content);
+    print!(
+        "{}",
+        diff.unified_diff().header(
+            &relative(base, &change.old_path),
+            &relative(base, &change.new_path)
+        )
+    );
+}
+
+fn apply(base: &Path, change: &Change, backup: Option<&String>) -> CliResult<()> {
+    if let Some(backup) = backup {
+        let backup_path = Path::new(backup).join(relative(base, &change.old_path));
+        fs::create_dir_all(backup_path.parent().unwrap())
+            .and_then(|_| fs::copy(&change.old_path, &backup_path))
+            .map_err(|e| CliError::write_error(&backup_path, e))?;
+    }
+    fs::create_dir_all(change.new_path.parent().unwrap())
+        .and_then(|_| fs::write(&change.new_path, &change.new_content))
+        .map_err(|e| CliError::write_error(&change.new_path, e))?;
+    if change.new_path != change.old_path {
+        fs::remove_file(&change.old_path).map_err(|e| CliError::write_error(&change.old_path, e))?;
+    }
+    Ok(())
+}
+
+fn relative(base: &Path, path: &Path) -> String {
+    path.strip_prefix(base)
+        .unwrap_or(path)
+        .to_string_lossy()
+        .to_string()
+}
+
+#[cfg(test)]
+mod tests {
+    use tempfile::{tempdir, TempDir};
+
+    use super::*;
+
+    const CURRENT_CARD: &str = r#"{
+  "type": "event",
+  "title": "Audit",
+  "description": "An auditor arrives",
+  "effect": {
+    "other": "Nothing happens"
+  }
+}"#;
+
+    const OLD_INCIDENT: &str = r#"{
+  "title": "DDoS",
+  "description": "Too many requests",
+  "action": "The shop is down",
+  "targets": ["frontend"]
+}"#;
+
+    fn repository_with_files(files: &[(&str, &str)]) -> (TempDir, Config) {
+        let base = tempdir().unwrap();
+        for (name, content) in files {
+            let path = base.path().join(name);
+            fs::create_dir_all(path.parent().unwrap()).unwrap();
+            fs::write(path, content).unwrap();
+        }
+        let cfg = Config {
+            game_path: base.path().to_str().unwrap().to_string(),
+        };
+        (base, cfg)
+    }
+
+    fn read(base: &TempDir, name: &str) -> String {
+        fs::read_to_string(base.path().join(name)).unwrap()
+    }
+
+    #[test]
+    fn dry_run_writes_nothing() {
+        let (base, cfg) = repository_with_files(&[
+            ("events/0001-Audit.json", CURRENT_CARD),
+            ("incidents/0001-DDoS.json", OLD_INCIDENT),
+        ]);
+
+        run(&cfg, true, None).unwrap();
+
+        assert_eq!(read(&base, "events/0001-Audit.json"), CURRENT_CARD);
+        assert_eq!(read(&base, "incidents/0001-DDoS.json"), OLD_INCIDENT);
+        assert!(!base.path().join("attacks").exists());
+    }
+
+    #[test]
+    fn files_are_rewritten_in_the_current_format() {
+        let (base, cfg) = repository_with_files(&[
+            ("events/0001-Audit.json", CURRENT_CARD),
+            ("incidents/0001-DDoS.json", OLD_INCIDENT),
+        ]);
+
+        run(&cfg, false, None).unwrap();
+
+        let stamped: Value = serde_json::from_str(&read(&base, "events/0001-Audit.json")).unwrap();
+        let moved: Value = serde_json::from_str(&read(&base, "attacks/0001-DDoS.json")).unwrap();
+        assert_eq!(stamped[VERSION_FIELD], FileKind::Card.current_version());
+        assert_eq!(stamped["title"], "Audit");
+        assert_eq!(moved[VERSION_FIELD], FileKind::Card.current_version());
+        assert_eq!(moved["type"], "attack");
+        assert!(!base.path().join("incidents/0001-DDoS.json").exists());
+    }
+
+    #[test]
+    fn moved_files_get_the_next_free_number() {
+        let attack = r#"{ "type": "attack", "title": "Phishing", "description": "A mail",
+            "effect": { "incident": ["Leak", ["frontend"], { "partOfRevenue": 20 }] }, "duration": { "rounds": 2 } }"#;
+        let (base, cfg) = repository_with_files(&[
+            ("attacks/0001-Phishing.json", attack),
+            ("incidents/0001-DDoS.json", OLD_INCIDENT),
+            ("incidents/0002-Flood.json", &OLD_INCIDENT.replace("DDoS", "Flood")),
+        ]);
+
+        run(&cfg, false, None).unwrap();
+
+        let phishing: Value = serde_json::from_str(&read(&base, "attacks/0001-Phishing.json")).unwrap();
+        let ddos: Value = serde_json::from_str(&read(&base, "attacks/0002-DDoS.json")).unwrap();
+        let flood: Value = serde_json::from_str(&read(&base, "attacks/0003-Flood.json")).unwrap();
+        assert_eq!(phishing["title"], "Phishing");
+        assert_eq!(ddos["title"], "DDoS");
+        assert_eq!(flood["title"], "Flood");
+    }
+
+    #[test]
+    fn files_with_the_current_version_are_not_rewritten() {
+        let (base, cfg) = repository_with_files(&[("events/0001-Audit.json", CURRENT_CARD)]);
+        run(&cfg, false, None).unwrap();
+        let upgraded = read(&base, "events/0001-Audit.json");
+
+        let changes = upgrade_file(
+            base.path(),
+            "events",
+            &base.path().join("events/0001-Audit.json"),
+            &mut HashMap::new(),
+        )
+        .unwrap();
+
+        assert!(changes.is_none());
+        assert_eq!(read(&base, "events/0001-Audit.json"), upgraded);
+    }
+
+    #[test]
+    fn backup_keeps_the_original_files() {
+        let (base, cfg) = repository_with_files(&[("incidents/0001-DDoS.json", OLD_INCIDENT)]);
+        let backup = tempdir().unwrap();
+        let backup_path = backup.path().to_str().unwrap().to_string();
+
+        run(&cfg, false, Some(&backup_path)).unwrap();
+
+        assert_eq!(
+            fs::read_to_string(backup.path().join("incidents/0001-DDoS.json")).unwrap(),
+            OLD_INCIDENT
+        );
+        assert!(base.path().join("attacks/0001-DDoS.json").exists());
+    }
+}
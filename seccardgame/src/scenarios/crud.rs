@@ -0,0 +1,245 @@
+use std::rc::Rc;
+
+use dialoguer::{Confirm, Editor, Select};
+use log::{error, warn};
+
+use game_lib::cards::game_variants::card_selection::CardSelection;
+use game_lib::cards::game_variants::rule_set::RuleSet;
+use game_lib::cards::game_variants::scenario::{Goal, Preset, Scenario};
+use game_lib::cards::properties::description::Description;
+use game_lib::cards::properties::target::Target;
+use game_lib::cards::properties::target_taxonomy::TargetTaxonomy;
+use game_lib::cards::properties::title::Title;
+use game_lib::file::repository::DeckLoader;
+use game_lib::file::scenarios::{get_scenario_files, read_scenario_file, write_scenario_to_file};
+use game_lib::world::deck::{DeckComposition, GameVariantsRepository, TargetTaxonomyRepository};
+use game_lib::world::objective::Objective;
+use game_lib::world::reputation::Reputation;
+use game_lib::world::reputation_income::ReputationIncome;
+use game_lib::world::resource_fix_multiplier::ResourceFixMultiplier;
+use game_lib::world::resources::Resources;
+use game_setup::config::config::Config;
+
+use crate::cli::cli_result::ErrorKind::FileSystemError;
+use crate::cli::cli_result::{CliError, CliResult, ErrorKind};
+use crate::cli::prompts::{prompt, prompt_with_default};
+use crate::scenarios::issues_of;
+
+pub fn create(cfg: &Config) -> CliResult<()> {
+    let loader = DeckLoader::create(cfg.game_path.as_str());
+
+    println!("Create a new Scenario");
+    let title: String = prompt("Scenario title", None);
+    let description: String = prompt("Scenario description", None);
+    let preset = ask_for_preset();
+    let goal = ask_for_goal(&loader.get_target_taxonomy());
+    let rule_set = ask_for_rule_set(&loader.get_rule_sets());
+    let (deck, grace_rounds) = ask_for_deck();
+
+    let scenario = Scenario {
+        title: Title::from(title),
+        description: Description::from(description),
+        preset,
+        goal,
+        reputation_income: ReputationIncome::default(),
+        rule_set,
+        deck,
+        grace_rounds,
+        cards: CardSelection::default(),
+        income_schedule: vec![],
+        scripted_events: vec![],
+    };
+
+    write_scenario(&scenario, cfg, &loader)
+}
+
+pub fn list(cfg: &Config) -> CliResult<()> {
+    let files = get_scenario_files(cfg.game_path.as_str()).map_err(|e| {
+        CliError::new(
+            FileSystemError,
+            "Could not read scenarios",
+            Some(e.to_string()),
+        )
+    })?;
+    if files.is_empty() {
+        println!("No scenarios found");
+    }
+    for file in files {
+        match read_scenario_file(&file) {
+            Ok(scenario) => println!(
+                "{} - {}",
+                scenario.title.value(),
+                scenario.description.value()
+            ),
+            Err(e) => warn!("Skipping invalid scenario: {}", e),
+        }
+    }
+    Ok(())
+}
+
+pub fn show(cfg: &Config, title: &str) -> CliResult<()> {
+    let scenarios = DeckLoader::create(cfg.game_path.as_str()).get_scenarios();
+    match scenarios.iter().find(|s| s.title.value() == title) {
+        Some(scenario) => {
+            println!("{}", serde_json::to_string_pretty(scenario.as_ref()).unwrap());
+            Ok(())
+        }
+        None => Err(CliError::new(
+            ErrorKind::ScenarioError,
+            format!("Scenario '{}' not found", title).as_str(),
+            None,
+        )),
+    }
+}
+
+fn write_scenario(scenario: &Scenario, cfg: &Config, loader: &DeckLoader) -> CliResult<()> {
+    let mut scenario_to_save = scenario.clone();
+    println!("{}", serde_json::to_string_pretty(scenario).unwrap());
+    if Confirm::new()
+        .with_prompt("Do you want to edit this scenario?")
+        .interact()
+        .unwrap()
+    {
+        scenario_to_save =
+            match Editor::new().edit(serde_json::to_string_pretty(scenario).unwrap().as_str()) {
+                Ok(Some(edited)) => serde_json::from_str(edited.as_str()).unwrap_or_else(|e| {
+                    error!("Error deserializing into scenario: {}", e);
+                    scenario_to_save
+                }),
+                Ok(None) => scenario_to_save,
+                Err(e) => {
+                    return Err(CliError::new(
+                        ErrorKind::ScenarioError,
+                        "Could not edit scenario",
+                        Some(e.to_string()),
+                    ))
+                }
+            };
+        println!(
+            "Scenario to save is\n{}",
+            serde_json::to_string_pretty(&scenario_to_save).unwrap()
+        );
+    }
+    for issue in issues_of(&scenario_to_save, loader) {
+        warn!("{}", issue);
+    }
+    if Confirm::new()
+        .with_prompt("Do you confirm these details?")
+        .interact()
+        .unwrap()
+    {
+        let path = write_scenario_to_file(&scenario_to_save, cfg.game_path.as_str()).map_err(|e| {
+            CliError::new(
+                FileSystemError,
+                "Could not write to file!",
+                Some(e.to_string()),
+            )
+        })?;
+        println!("Wrote to file {}", path.display());
+    } else {
+        println!("Cancelled!");
+    }
+    Ok(())
+}
+
+fn ask_for_preset() -> Preset {
+    Preset {
+        resources: Resources::new(prompt_with_default("Start resources", 50)),
+        reputation: Reputation::new(prompt_with_default("Start reputation (0-100)", 50)),
+        resource_gain: Resources::new(prompt_with_default("Resource gain per round", 5)),
+        multiplier: ResourceFixMultiplier::new(prompt_with_default("Fix cost multiplier", 1)),
+        people_hours: Resources::new(prompt_with_default("Start people-hours", 0)),
+        people_hours_gain: Resources::new(prompt_with_default("People-hours gain per round", 0)),
+    }
+}
+
+fn ask_for_goal(taxonomy: &TargetTaxonomy) -> Goal {
+    Goal {
+        minimum_resources: Resources::new(prompt_with_default("Minimum resources to win", 0)),
+        minimum_reputation: Reputation::new(prompt_with_default(
+            "Minimum reputation to win (0-100)",
+            20,
+        )),
+        objectives: ask_for_objectives(taxonomy),
+    }
+}
+
+fn ask_for_objectives(taxonomy: &TargetTaxonomy) -> Vec<Objective> {
+    let available_objectives = [
+        "No more than n incidents",
+        "Fix all oopsies with a target",
+        "Reputation above a value for some rounds",
+        "Fix at least n oopsies",
+        "Finished",
+    ];
+    let mut objectives = Vec::new();
+    loop {
+        let selection = Select::new()
+            .with_prompt("Add objective (use Finished to end)")
+            .items(&available_objectives)
+            .default(available_objectives.len() - 1)
+            .interact()
+            .unwrap();
+        let objective = match selection {
+            0 => Objective::MaxIncidents(prompt("Maximum number of incidents", None)),
+            1 => Objective::FixAllOopsiesWithTarget(ask_for_target(taxonomy)),
+            2 => Objective::ReputationAbove {
+                reputation: Reputation::new(prompt("Reputation (int)", None)),
+                rounds: prompt("Rounds in a row", None),
+            },
+            3 => Objective::FixOopsies(prompt("Number of oopsies to fix", None)),
+            _ => return objectives,
+        };
+        objectives.push(objective);
+    }
+}
+
+fn ask_for_target(taxonomy: &TargetTaxonomy) -> Target {
+    let targets = taxonomy.all_targets();
+    let names: Vec<&str> = targets.iter().map(|t| t.value()).collect();
+    let selection = Select::new()
+        .with_prompt("Select target")
+        .items(&names)
+        .default(0)
+        .interact()
+        .unwrap();
+    targets[selection].clone()
+}
+
+fn ask_for_rule_set(rule_sets: &[Rc<RuleSet>]) -> Option<String> {
+    if rule_sets.is_empty() {
+        return None;
+    }
+    let mut names = vec![RuleSet::DEFAULT_NAME];
+    names.extend(rule_sets.iter().map(|rules| rules.name.as_str()));
+    let selection = Select::new()
+        .with_prompt("Select the rule set of the scenario")
+        .items(&names)
+        .default(0)
+        .interact()
+        .unwrap();
+    match selection {
+        0 => None,
+        _ => Some(names[selection].to_string()),
+    }
+}
+
+fn ask_for_deck() -> (Option<DeckComposition>, Option<u8>) {
+    if !Confirm::new()
+        .with_prompt("Does the scenario come with its own deck composition?")
+        .interact()
+        .unwrap()
+    {
+        return (None, None);
+    }
+    let deck = DeckComposition {
+        events: prompt_with_default("Number of event cards", 10),
+        attacks: prompt_with_default("Number of attack cards", 5),
+        oopsies: prompt_with_default("Number of oopsie cards", 15),
+        lucky: prompt_with_default("Number of lucky cards", 5),
+        controls: prompt_with_default("Number of control cards", 0),
+        evaluation: prompt_with_default("Number of evaluation cards", 0),
+    };
+    let grace_rounds = prompt_with_default("Cards drawn before attacks can show up", 5);
+    (Some(deck), Some(grace_rounds))
+}
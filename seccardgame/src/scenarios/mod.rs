@@ -0,0 +1,16 @@
+use game_lib::cards::game_variants::scenario::Scenario;
+use game_lib::cards::game_variants::scenario_validation::ScenarioIssue;
+use game_lib::file::repository::DeckLoader;
+use game_lib::world::deck::{DeckRepository, GameVariantsRepository, TargetTaxonomyRepository};
+
+pub mod crud;
+pub mod validate;
+
+/// Validates a scenario against the cards, rule sets and targets of the card repository.
+fn issues_of(scenario: &Scenario, loader: &DeckLoader) -> Vec<ScenarioIssue> {
+    scenario.validate(
+        &loader.get_all_cards(),
+        &loader.get_rule_sets(),
+        &loader.get_target_taxonomy(),
+    )
+}
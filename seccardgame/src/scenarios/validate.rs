@@ -0,0 +1,51 @@
+use std::path::PathBuf;
+
+use game_lib::file::repository::DeckLoader;
+use game_lib::file::scenarios::{get_scenario_files, read_scenario_file};
+use game_setup::config::config::Config;
+
+use crate::cli::cli_result::{CliError, CliResult, ErrorKind};
+use crate::scenarios::issues_of;
+
+/// Validates the given scenario file or all scenarios of the card repository. Every problem is printed
+/// with the file it was found in, the command fails if any scenario is invalid.
+pub fn validate(cfg: &Config, path: Option<&String>) -> CliResult<()> {
+    let files = match path {
+        Some(path) => vec![PathBuf::from(path)],
+        None => get_scenario_files(cfg.game_path.as_str()).map_err(|e| {
+            CliError::new(
+                ErrorKind::FileSystemError,
+                "Could not read scenarios",
+                Some(e.to_string()),
+            )
+        })?,
+    };
+    let loader = DeckLoader::create(cfg.game_path.as_str());
+
+    let mut invalid_files = 0;
+    for file in &files {
+        let problems: Vec<String> = match read_scenario_file(file) {
+            Ok(scenario) => issues_of(&scenario, &loader)
+                .iter()
+                .map(|issue| issue.to_string())
+                .collect(),
+            Err(e) => vec![e.to_string()],
+        };
+        for problem in &problems {
+            println!("{}: {}", file.display(), problem);
+        }
+        if !problems.is_empty() {
+            invalid_files += 1;
+        }
+    }
+
+    if invalid_files > 0 {
+        return Err(CliError::new(
+            ErrorKind::ScenarioError,
+            format!("{} of {} scenarios are invalid", invalid_files, files.len()).as_str(),
+            None,
+        ));
+    }
+    println!("All {} scenarios are valid", files.len());
+    Ok(())
+}